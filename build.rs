@@ -0,0 +1,32 @@
+use std::{env, fs, path::Path};
+
+/// Bundle every template in assets/prefabs, so a new prefab needs only its own file
+fn main() {
+    let prefabs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/prefabs");
+    println!("cargo:rerun-if-changed={}", prefabs_dir.display());
+
+    let mut template_paths: Vec<_> = fs::read_dir(&prefabs_dir)
+        .expect("assets/prefabs must exist")
+        .map(|entry| entry.expect("Cannot read prefab entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    template_paths.sort();
+
+    let mut templates = String::new();
+    for path in &template_paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+        templates.push_str(&format!(
+            "    include_str!({:?}),\n",
+            path.display().to_string()
+        ));
+    }
+
+    let generated = format!(
+        "const PREFAB_TEMPLATES: [&str; {}] = [\n{}];\n",
+        template_paths.len(),
+        templates
+    );
+    let out_path = Path::new(&env::var("OUT_DIR").expect("OUT_DIR must be set by cargo"))
+        .join("prefab_templates.rs");
+    fs::write(out_path, generated).expect("Cannot write the prefab template list");
+}
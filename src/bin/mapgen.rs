@@ -0,0 +1,233 @@
+//! Preview the zones made by the builders, without a window or assets.
+//!
+//! > cargo run --bin mapgen -- <builder> [depth] [seed]
+//!
+//! Prints the tiles as ASCII, with spawn points marked, and some statistics about the zone
+
+use std::{collections::VecDeque, env, process};
+
+use hecs::World;
+use macroquad::rand;
+
+use worldsviscera::{
+    maps::{
+        ZoneBuilder,
+        arena_zone_builder::ArenaZoneBuilder,
+        cavern_zone_builder::CavernZoneBuilder,
+        connectivity_checker::ConnectivityChecker,
+        crystal_cave_builder::CrystalCaveBuilder,
+        dungeon_zone_builder::DungeonZoneBuilder,
+        flooded_mine_builder::FloodedMineBuilder,
+        fungal_grotto_builder::FungalGrottoBuilder,
+        main_zone_builder::MainZoneBuilder,
+        stonedust_monastery_builder::StonedustMonasteryBuilder,
+        test_zone_builder::TestZoneBuilder,
+        zone::{TileType, Zone},
+        zone_builder_table::BuildZoneFn,
+    },
+    utils::pathfinding::Pathfinding,
+};
+
+/// Name given on the command line and builder
+const BUILDERS: [(&str, BuildZoneFn); 9] = [
+    ("main", MainZoneBuilder::build),
+    ("arena", ArenaZoneBuilder::build),
+    ("dungeon", DungeonZoneBuilder::build),
+    ("crystal_cave", CrystalCaveBuilder::build),
+    ("test", TestZoneBuilder::build),
+    ("cavern", CavernZoneBuilder::build),
+    ("flooded_mine", FloodedMineBuilder::build),
+    ("fungal_grotto", FungalGrottoBuilder::build),
+    ("stonedust_monastery", StonedustMonasteryBuilder::build),
+];
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let Some(builder_name) = args.get(1) else {
+        print_usage();
+    };
+    let Some(&(_, builder)) = BUILDERS
+        .iter()
+        .find(|(name, _)| *name == builder_name.as_str())
+    else {
+        print_usage();
+    };
+    let depth: u32 = args
+        .get(2)
+        .map(|value| value.parse().unwrap_or_else(|_| print_usage()))
+        .unwrap_or(1);
+    let seed: u64 = args
+        .get(3)
+        .map(|value| value.parse().unwrap_or_else(|_| print_usage()))
+        .unwrap_or_else(|| macroquad::miniquad::date::now() as u64);
+
+    rand::srand(seed);
+    let mut ecs_world = World::new();
+    let mut zone = ConnectivityChecker::build_connected(builder, depth, &mut ecs_world);
+    zone.populate_blocked();
+    zone.populate_water();
+
+    println!("{} zone, depth {}, seed {}", builder_name, depth, seed);
+    println!();
+    print_tiles(&zone);
+    println!();
+    print_statistics(&zone);
+}
+
+fn print_usage() -> ! {
+    let names: Vec<&str> = BUILDERS.iter().map(|(name, _)| *name).collect();
+    eprintln!("Usage: mapgen <builder> [depth] [seed]");
+    eprintln!("Builders: {}", names.join(", "));
+    process::exit(1);
+}
+
+/// Print the zone as ASCII. Spawn points are drawn over the tiles
+fn print_tiles(zone: &Zone) {
+    for y in 0..zone.height {
+        let row: String = (0..zone.width)
+            .map(|x| {
+                let index = zone.get_index_from_xy(&x, &y);
+                if index == zone.player_spawn_point {
+                    '@'
+                } else if zone.monster_spawn_points.contains(&index) {
+                    'M'
+                } else if zone.item_spawn_points.contains(&index) {
+                    'i'
+                } else if zone.fauna_spawn_points.contains(&index) {
+                    'f'
+                } else {
+                    get_tile_char(&zone.tiles[index])
+                }
+            })
+            .collect();
+        println!("{}", row);
+    }
+}
+
+/// Same characters of the prefab templates, where there is one
+fn get_tile_char(tile: &TileType) -> char {
+    match tile {
+        TileType::Floor => '.',
+        TileType::Wall => '#',
+        TileType::DownPassage => '>',
+        TileType::BranchPassage => '/',
+        TileType::Brazier => '*',
+        TileType::Water => '~',
+        TileType::DeepWater => '=',
+        TileType::CrackedWall => 'c',
+        TileType::MushroomField => '"',
+        TileType::FieldFence => '|',
+        TileType::GoldMine => 'G',
+        TileType::BrickWall => 'B',
+        TileType::StoneFloor => '_',
+        TileType::MiniCrystal | TileType::LittleCrystal | TileType::MediumCrystal => ':',
+        TileType::BigCrystal => 'X',
+        TileType::TripleGoldLock(_) => 'T',
+        TileType::CarvedStone => 'C',
+        TileType::DisembodiedEntity => 'E',
+        TileType::ClosedDoor => '+',
+        TileType::OpenDoor | TileType::BrokenDoor => '\'',
+        TileType::LockedDoor => 'L',
+        TileType::Chasm => ' ',
+        TileType::Pit => 'o',
+        TileType::Lava => '&',
+        TileType::LavaCrust => ',',
+        TileType::HotRock => ';',
+    }
+}
+
+fn print_statistics(zone: &Zone) {
+    let zone_indexes: Vec<usize> = (0..zone.height)
+        .flat_map(|y| (0..zone.width).map(move |x| zone.get_index_from_xy(&x, &y)))
+        .collect();
+    let open_tiles = zone_indexes
+        .iter()
+        .filter(|&&index| ConnectivityChecker::is_passable(zone, index))
+        .count();
+
+    let reachable = ConnectivityChecker::flood_fill(zone, zone.player_spawn_point);
+    let reachable_tiles = zone_indexes
+        .iter()
+        .filter(|&&index| reachable[index])
+        .count();
+
+    println!(
+        "Open tiles: {}/{} ({:.1}%)",
+        open_tiles,
+        zone_indexes.len(),
+        open_tiles as f32 * 100.0 / zone_indexes.len() as f32
+    );
+    println!(
+        "Reachable from spawn: {}/{} ({:.1}%)",
+        reachable_tiles,
+        open_tiles,
+        reachable_tiles as f32 * 100.0 / open_tiles.max(1) as f32
+    );
+    println!(
+        "Rivers and lakes: {}",
+        count_bodies(zone, |tile| {
+            matches!(tile, TileType::Water | TileType::DeepWater)
+        })
+    );
+    println!(
+        "Magma rivers: {}",
+        count_bodies(zone, |tile| {
+            matches!(tile, TileType::Lava | TileType::LavaCrust)
+        })
+    );
+
+    // Walking distance, the exit is reached when standing next to it
+    let (spawn_x, spawn_y) = zone.get_xy_from_index(zone.player_spawn_point);
+    match zone
+        .tiles
+        .iter()
+        .position(|tile| *tile == TileType::DownPassage)
+    {
+        Some(exit_index) => {
+            let (exit_x, exit_y) = zone.get_xy_from_index(exit_index);
+            match Pathfinding::dijkstra_wrapper(
+                spawn_x, spawn_y, exit_x, exit_y, zone, false, false,
+            ) {
+                Some((path, _)) => println!("Distance from spawn to exit: {} steps", path.len()),
+                None => println!("Distance from spawn to exit: unreachable"),
+            }
+        }
+        None => println!("Distance from spawn to exit: no exit"),
+    }
+}
+
+/// Count the separate bodies made of some kind of tile, like the water of rivers and lakes
+fn count_bodies(zone: &Zone, is_body: fn(&TileType) -> bool) -> usize {
+    let mut visited = vec![false; zone.tiles.len()];
+    let mut bodies = 0;
+
+    for start in 0..zone.tiles.len() {
+        let (start_x, start_y) = zone.get_xy_from_index(start);
+        if visited[start]
+            || start_x >= zone.width
+            || start_y >= zone.height
+            || !is_body(&zone.tiles[start])
+        {
+            continue;
+        }
+
+        bodies += 1;
+        visited[start] = true;
+        let mut frontier = VecDeque::from([start]);
+        while let Some(current) = frontier.pop_front() {
+            let (x, y) = zone.get_xy_from_index(current);
+            for (next_x, next_y) in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                if !(0..zone.width).contains(&next_x) || !(0..zone.height).contains(&next_y) {
+                    continue;
+                }
+                let next = zone.get_index_from_xy(&next_x, &next_y);
+                if !visited[next] && is_body(&zone.tiles[next]) {
+                    visited[next] = true;
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+
+    bodies
+}
@@ -50,3 +50,20 @@ pub struct WantsToDig {
     pub target: Entity,
     pub tool: Entity,
 }
+
+pub struct WantsToThrow {
+    pub item: Entity,
+}
+
+pub struct WantsToOpenDoor {
+    pub target: (i32, i32),
+}
+
+pub struct WantsToCloseDoor {
+    pub target: (i32, i32),
+}
+
+pub struct WantsToDisarm {
+    pub target: Entity,
+    pub tool: Entity,
+}
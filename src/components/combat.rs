@@ -41,6 +41,8 @@ pub struct IsHidden {
     pub hidden_counter: i32,
 }
 
+pub struct Sneaking {}
+
 pub struct WantsToShoot {
     pub weapon: Entity,
 }
@@ -3,14 +3,15 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-use hecs::Entity;
+use hecs::{Entity, World};
 use macroquad::math::Rect;
 
 use crate::{
-    components::health::DiseaseType,
+    components::{health::DiseaseType, quest::QuestId},
     constants::{
         BURNING_PARTICLE_TYPE, DAZE_PARTICLE_TYPE, INITIAL_LOG_CAPACITY, STONE_FELL_PARTICLE_TYPE,
     },
+    maps::zone::GasType,
     utils::assets::TextureName,
 };
 
@@ -75,6 +76,17 @@ pub struct CanSmell {
     pub radius: f32,
 }
 
+impl CanSmell {
+    /// Faint odors can be smelled from half the normal distance, unless the smeller has a keen nose
+    pub fn can_smell(&self, smellable: &Smellable, distance: f32) -> bool {
+        self.intensity != SmellIntensity::None
+            && ((distance < self.radius / 2.0 && smellable.intensity == SmellIntensity::Faint)
+                || (distance < self.radius
+                    && (smellable.intensity == SmellIntensity::Strong
+                        || self.intensity == SmellIntensity::Strong)))
+    }
+}
+
 pub struct Wet {
     pub tick_countdown: i32,
 }
@@ -96,6 +108,29 @@ pub struct Hates {
     pub list: HashSet<u32>,
 }
 
+/// Group of creatures that shares the same feelings about the player
+pub struct Faction {
+    pub value: FactionEnum,
+}
+
+#[derive(PartialEq, Debug, Hash, Eq, Clone, Copy)]
+pub enum FactionEnum {
+    StonedustCult,
+    Molemen,
+    Refugees,
+    DeepSpawn,
+}
+
+/// How much each faction likes the player
+pub struct Reputation {
+    pub values: HashMap<FactionEnum, i32>,
+}
+
+/// Gold coins carried outside of the backpack
+pub struct Purse {
+    pub gold: u32,
+}
+
 pub struct Experience {
     pub value: u32,
     pub auto_advance_counter: u32,
@@ -103,7 +138,7 @@ pub struct Experience {
 
 pub struct Immobile {}
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum SpeciesEnum {
     Human,
     Undergrounder,
@@ -126,6 +161,7 @@ pub struct Diggable {
 pub enum DigProductEnum {
     Gold,
     Stone,
+    Nothing,
 }
 
 // HashSet should handle ImmuntyType enum variants safely like they are actual different immunity
@@ -142,6 +178,7 @@ pub enum ImmunityTypeEnum {
     DamagingFloor,
     Slipping,
     StoneFellSpell,
+    Heat,
 }
 
 pub struct Spell {
@@ -178,11 +215,80 @@ pub struct Key {
     pub lock: Entity,
 }
 
+/// Creature that can be chatted with. Those with a dialogue tree will hold a
+/// conversation, the others will just say one of their lines
 pub struct WillChat {
     pub dialogues: Vec<&'static str>,
+    pub tree: Option<&'static [DialogueNode]>,
+}
+
+/// Step of a conversation: what the creature says and how the player can answer
+pub struct DialogueNode {
+    pub text: &'static str,
+    pub options: &'static [DialogueOption],
+}
+
+/// Answer of the player. Shown only if all its conditions are met,
+/// the conversation goes on with the next node or ends if there is none
+pub struct DialogueOption {
+    pub text: &'static str,
+    pub conditions: &'static [DialogueCondition],
+    pub effects: &'static [DialogueEffect],
+    pub next: Option<usize>,
+}
+
+pub enum DialogueCondition {
+    HasItem(&'static str),
+    PlayerSpecies(SpeciesEnum),
+    MinDepth(u32),
+    HatesPlayer,
+    QuestActive(QuestId),
+    QuestReady(QuestId),
+    KnowsSpell(&'static str),
+    Not(&'static DialogueCondition),
+}
+
+pub enum DialogueEffect {
+    GiveItem(&'static str),
+    TakeItem(&'static str),
+    RevealMapArea(i32),
+    StartTrade,
+    SetHostility(bool),
+    TeachSpell(fn(&mut World) -> Entity),
+    StartQuest(QuestId),
+    CompleteQuest(QuestId),
 }
 
 pub struct Inspectable {
     pub description: &'static str,
     pub despawn_on_inspect: bool,
 }
+
+pub struct Flammable {}
+
+pub struct Burning {
+    pub tick_counter: i32,
+    pub gives_light: bool,
+}
+
+pub struct Fire {}
+
+pub struct EmitsGas {
+    pub of: GasType,
+    pub density: u8,
+    pub chance: i32,
+}
+
+pub struct Trap {
+    pub trap_type: TrapType,
+    pub armed: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TrapType {
+    Pit,
+    FallingRocks,
+    SpikePlate,
+    AlarmTripwire,
+    GasVent,
+}
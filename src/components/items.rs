@@ -9,6 +9,8 @@ use crate::{
 
 pub struct Item {
     pub item_tile: (i32, i32),
+    /// Base price in gold coins, zero if no one would buy it
+    pub value: u32,
 }
 
 pub struct Edible {
@@ -130,9 +132,13 @@ pub struct Eroded {
 
 pub struct DiggingTool {}
 
+pub struct DisarmingTool {}
+
 pub struct ShopOwner {
     pub shop_tiles: Vec<usize>,
     pub wanted_items: Vec<Tradable>,
+    /// How many times the shop owner was robbed or attacked, raises its prices
+    pub times_wronged: u32,
 }
 
 pub struct Corpse {}
@@ -4,4 +4,6 @@ pub mod monster;
 pub mod combat;
 pub mod items;
 pub mod health;
-pub mod actions;
\ No newline at end of file
+pub mod actions;
+pub mod quest;
+pub mod overview;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::collections::LinkedList;
+use std::collections::{HashMap, LinkedList};
 
 use hecs::Entity;
 
@@ -35,6 +35,61 @@ pub struct WantsToApproach {
     pub counter: u32,
 }
 
+/// Where the enemies that went out of sight were last seen, smelled or heard.
+/// The search counter tells for how long the monster will look around there before giving up
+pub struct Memory {
+    pub last_seen: HashMap<u32, (i32, i32)>,
+    pub search_counter: u32,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum AwarenessState {
+    Asleep,
+    Unaware,
+    Alert,
+    Hunting,
+}
+
+/// How much a monster is paying attention to its surroundings.
+/// Asleep and Unaware monsters ignore the player until they notice him
+pub struct Awareness {
+    pub state: AwarenessState,
+}
+
+/// Courage of a monster, tested when badly wounded or when allies die in sight
+pub struct Morale {
+    pub allies_lost: i32,
+    pub has_tested_wounds: bool,
+}
+
+pub struct Fleeing {
+    pub counter: i32,
+}
+
+pub struct Surrendered {}
+
+/// Member of a group of monsters, following its leader
+pub struct PackMember {
+    pub leader: Entity,
+}
+
+/// NPC willing to work for the player, paid with gold or food
+pub struct Hireable {}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum HirelingCommand {
+    Follow,
+    Stay,
+    Attack(Entity),
+}
+
+/// NPC working for someone. Loyalty goes down over time, when it runs out the hireling leaves
+pub struct Hireling {
+    pub owner: Entity,
+    pub loyalty: i32,
+    pub command: HirelingCommand,
+}
+
 pub struct SnakeHead {
     pub body: LinkedList<Entity>,
 }
@@ -0,0 +1,13 @@
+use crate::maps::dungeon_graph::Branch;
+
+/// What the player discovered in a zone, remembered after leaving it
+pub struct ZoneSummary {
+    pub depth: u32,
+    pub branch: Branch,
+    pub features: Vec<&'static str>,
+}
+
+/// Summaries of all the zones visited by the player, in the order they were left
+pub struct DungeonOverview {
+    pub zones: Vec<ZoneSummary>,
+}
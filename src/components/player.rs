@@ -1,16 +1,32 @@
 use std::borrow::Cow;
 
-use crate::components::actions::{WantsToDig, WantsToTrade};
-use crate::components::combat::{Grappled, SufferingDamage, WantsToShoot};
+use crate::components::actions::{
+    WantsToCloseDoor, WantsToDig, WantsToDisarm, WantsToOpenDoor, WantsToThrow, WantsToTrade,
+};
+use crate::components::combat::{
+    Grappled, IsHidden, Sneaking, SufferingDamage, WantsToCast, WantsToShoot,
+};
 use crate::components::common::{
-    Diggable, Hates, Immunity, ImmunityTypeEnum, Inspectable, Named, WillChat,
+    Diggable, Hates, Immunity, ImmunityTypeEnum, Inspectable, Named, Purse, Spell, SpellList, Trap,
+    WillChat,
+};
+use crate::components::items::{Armor, Equipped, InBackback, Metallic, RangedWeapon, ShopOwner};
+use crate::components::monster::{Hireable, Hireling, HirelingCommand};
+use crate::constants::{
+    ACID_DECAL_DAMAGE_DICE, LONG_ACTION_MULTIPLIER, MAX_ITEMS_IN_BACKPACK, NEXT_TO_DISTANCE,
+    STANDARD_ACTION_MULTIPLIER,
 };
-use crate::components::items::{RangedWeapon, ShopOwner};
-use crate::constants::{ACID_DECAL_DAMAGE_DICE, NEXT_TO_DISTANCE, STANDARD_ACTION_MULTIPLIER};
 use crate::engine::state::GameState;
-use crate::utils::common::{EquippedDiggingTool, ItemsInBackpack};
+use crate::systems::dialogue_manager::DialogueManager;
+use crate::systems::faction_manager::FactionManager;
+use crate::systems::hireling_manager::HirelingManager;
+use crate::systems::trade_system::TradeSystem;
+use crate::utils::common::{EquippedDiggingTool, EquippedDisarmingTool, ItemsInBackpack};
 use crate::utils::roll::Roll;
-use crate::{components::actions::WantsToInvoke, maps::zone::DecalType};
+use crate::{
+    components::actions::WantsToInvoke,
+    maps::zone::{DecalType, NoiseType},
+};
 use hecs::{Component, Entity, World};
 use macroquad::input::{
     KeyCode, MouseButton, clear_input_queue, get_char_pressed, get_key_pressed, is_key_down,
@@ -24,8 +40,7 @@ use crate::{
         common::{MyTurn, Position, Viewshed},
         items::{Edible, Item, Quaffable},
     },
-    constants::{MAP_HEIGHT, MAP_WIDTH, TILE_SIZE_F32, UI_BORDER_F32},
-    engine::state::RunState,
+    engine::{camera::Camera, state::RunState},
     inventory::InventoryAction,
     maps::zone::{TileType, Zone},
     spawning::spawner::Spawn,
@@ -39,6 +54,8 @@ pub enum SpecialViewMode {
     RangedTargeting,
     Smell,
     Inspecting,
+    ThrowTargeting,
+    HirelingTargeting,
 }
 
 /// Player struct
@@ -52,8 +69,12 @@ impl Player {
         game_state.run_state = RunState::WaitingPlayerInput;
         let mut attacker_target: Option<(Entity, Entity)> = None;
         let mut digger_target: Option<(Entity, Entity, Entity)> = None;
+        let mut tunnel_target: Option<(Entity, Entity, (i32, i32))> = None;
+        let mut door_target: Option<(Entity, (i32, i32))> = None;
         let mut waiter_speed_list: Vec<(Entity, i32)> = Vec::new();
         let mut remove_grappled: bool = false;
+        let mut swap_target: Option<Entity> = None;
+        let mut hireling_swap: Option<(Entity, i32, i32)> = None;
 
         // Scope for keeping borrow checker quiet
         {
@@ -82,7 +103,7 @@ impl Player {
                 // Check if player is on slime before moving away and is not immune to slip
                 if let Some(special_tile) = zone
                     .decals_tiles
-                    .get(&Zone::get_index_from_xy(&position.x, &position.y))
+                    .get(&zone.get_index_from_xy(&position.x, &position.y))
                     && let DecalType::Slime = special_tile
                     && !immunity.to.contains_key(&ImmunityTypeEnum::Slipping)
                 {
@@ -99,7 +120,7 @@ impl Player {
                 }
 
                 let destination_index =
-                    Zone::get_index_from_xy(&(position.x + delta_x), &(position.y + delta_y));
+                    zone.get_index_from_xy(&(position.x + delta_x), &(position.y + delta_y));
 
                 let player_dig_tool = ecs_world.query::<EquippedDiggingTool>().iter().find_map(
                     |(item, (in_backpack, ..))| {
@@ -111,13 +132,27 @@ impl Player {
                     },
                 );
 
+                // Metallic armor clanks at every step
+                let wears_metallic_armor = ecs_world
+                    .query::<&Equipped>()
+                    .with::<(&Armor, &Metallic)>()
+                    .iter()
+                    .any(|(_, equipped)| equipped.owner.id() == player_entity.id());
+
                 //Search for potential targets (must have CombatStats component)
                 for &potential_target in zone.tile_content[destination_index].iter() {
                     let has_combat_stats = ecs_world
                         .satisfies::<&CombatStats>(potential_target)
                         .unwrap_or(false);
 
-                    if has_combat_stats {
+                    // Walking into an own hireling makes the player swap places with it
+                    let is_own_hireling = ecs_world
+                        .get::<&Hireling>(potential_target)
+                        .is_ok_and(|hireling| hireling.owner.id() == player_entity.id());
+
+                    if is_own_hireling {
+                        swap_target = Some(potential_target);
+                    } else if has_combat_stats {
                         attacker_target = Some((player_entity, potential_target));
                     }
                     let is_diggable = ecs_world
@@ -126,6 +161,11 @@ impl Player {
 
                     if is_diggable && let Some(dig_tool) = player_dig_tool {
                         digger_target = Some((player_entity, dig_tool, potential_target));
+                    } else if is_diggable && zone.tiles[destination_index] == TileType::LockedDoor {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("The door is locked"));
                     } else if is_diggable {
                         game_state.game_log.add_entry(Cow::Borrowed(
                             "You have no digging tool to use on this wall",
@@ -133,10 +173,40 @@ impl Player {
                     }
                 }
 
-                // Move if not attacking or destination is not blocked
+                // With a digging tool, even ordinary walls can be tunnelled through
+                if attacker_target.is_none()
+                    && digger_target.is_none()
+                    && let Some(dig_tool) = player_dig_tool
+                    && matches!(
+                        zone.tiles[destination_index],
+                        TileType::Wall | TileType::BrickWall | TileType::BigCrystal
+                    )
+                {
+                    tunnel_target = Some((
+                        player_entity,
+                        dig_tool,
+                        (position.x + delta_x, position.y + delta_y),
+                    ));
+                }
+
+                // Walking into a closed door opens it
+                if attacker_target.is_none()
+                    && zone.tiles[destination_index] == TileType::ClosedDoor
+                {
+                    door_target =
+                        Some((player_entity, (position.x + delta_x, position.y + delta_y)));
+                }
+
+                // Move if not attacking or destination is not blocked.
+                // Nothing stops from jumping into a chasm or walking into lava
                 if attacker_target.is_none()
                     && digger_target.is_none()
-                    && !zone.blocked_tiles[destination_index]
+                    && tunnel_target.is_none()
+                    && door_target.is_none()
+                    && (!zone.blocked_tiles[destination_index]
+                        || zone.is_chasm(destination_index)
+                        || zone.is_lava(destination_index)
+                        || swap_target.is_some())
                 {
                     // Check if player is grappled and try to escape.
                     // Placed if here so Player can still attack grappler
@@ -166,18 +236,21 @@ impl Player {
                             break;
                         }
                     } else {
-                        zone.blocked_tiles[Zone::get_index_from_xy(&position.x, &position.y)] =
-                            false;
-                        position.x = (position.x + delta_x).clamp(0, MAP_WIDTH - 1);
-                        position.y = (position.y + delta_y).clamp(0, MAP_HEIGHT - 1);
+                        if let Some(hireling) = swap_target {
+                            hireling_swap = Some((hireling, position.x, position.y));
+                        }
+                        let old_index = zone.get_index_from_xy(&position.x, &position.y);
+                        zone.blocked_tiles[old_index] = false;
+                        position.x = (position.x + delta_x).clamp(0, zone.width - 1);
+                        position.y = (position.y + delta_y).clamp(0, zone.height - 1);
                         viewshed.must_recalculate = true;
-                        zone.blocked_tiles[Zone::get_index_from_xy(&position.x, &position.y)] =
-                            true;
+                        let new_index = zone.get_index_from_xy(&position.x, &position.y);
+                        zone.blocked_tiles[new_index] = true;
 
                         // Check if player has stepped on a acid
                         if let Some(special_tile) = zone
                             .decals_tiles
-                            .get(&Zone::get_index_from_xy(&position.x, &position.y))
+                            .get(&zone.get_index_from_xy(&position.x, &position.y))
                             && let DecalType::Acid = special_tile
                             && !immunity.to.contains_key(&ImmunityTypeEnum::DamagingFloor)
                         {
@@ -191,6 +264,14 @@ impl Player {
                                     Roll::dice(1, ACID_DECAL_DAMAGE_DICE);
                             }
                         }
+
+                        // Monsters nearby could hear the player moving
+                        if zone.water_tiles[zone.get_index_from_xy(&position.x, &position.y)] {
+                            zone.add_noise(position.x, position.y, NoiseType::Splashing);
+                        }
+                        if wears_metallic_armor {
+                            zone.add_noise(position.x, position.y, NoiseType::ClankingArmor);
+                        }
                     }
 
                     game_state.run_state = RunState::DoTick;
@@ -199,8 +280,21 @@ impl Player {
         }
 
         // if return_state == RunState::DoTick here, than is moving, needs to wait!
+        // Sneaking around takes longer
         if game_state.run_state == RunState::DoTick {
-            Player::wait_after_action(game_state, STANDARD_ACTION_MULTIPLIER);
+            let is_sneaking = game_state
+                .ecs_world
+                .satisfies::<&Sneaking>(
+                    game_state
+                        .current_player_entity
+                        .expect("Player should be set"),
+                )
+                .unwrap_or(false);
+            if is_sneaking {
+                Player::wait_after_action(game_state, LONG_ACTION_MULTIPLIER);
+            } else {
+                Player::wait_after_action(game_state, STANDARD_ACTION_MULTIPLIER);
+            }
         }
 
         // Attack if needed
@@ -219,6 +313,48 @@ impl Player {
             game_state.run_state = RunState::DoTick;
         }
 
+        // Start tunnelling if needed
+        if let Some((digger, tool, (x, y))) = tunnel_target {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+            if let Some(target) = Spawn::tunnel_entity(&mut game_state.ecs_world, zone, x, y) {
+                let _ = game_state
+                    .ecs_world
+                    .insert_one(digger, WantsToDig { target, tool });
+                game_state.run_state = RunState::DoTick;
+            } else {
+                game_state
+                    .game_log
+                    .entries
+                    .push(Cow::Borrowed("This wall is too solid to dig"));
+            }
+        }
+
+        // Open door if needed
+        if let Some((opener, target)) = door_target {
+            let _ = game_state
+                .ecs_world
+                .insert_one(opener, WantsToOpenDoor { target });
+            game_state.run_state = RunState::DoTick;
+        }
+
+        // Hireling takes the place left by the player
+        if let Some((hireling, x, y)) = hireling_swap {
+            if let Ok(mut position) = game_state.ecs_world.get::<&mut Position>(hireling) {
+                position.x = x;
+                position.y = y;
+            }
+            if let Ok(mut viewshed) = game_state.ecs_world.get::<&mut Viewshed>(hireling) {
+                viewshed.must_recalculate = true;
+            }
+            if let Some(zone) = game_state.current_zone.as_mut() {
+                let tile_index = zone.get_index_from_xy(&x, &y);
+                zone.blocked_tiles[tile_index] = true;
+            }
+        }
+
         // Remove grapple if player was grappled
         if remove_grappled {
             let _ = game_state.ecs_world.remove_one::<Grappled>(
@@ -296,6 +432,12 @@ impl Player {
                             game_state.run_state = RunState::ShowInventory(InventoryAction::Apply);
                         }
 
+                        //Throw item
+                        'h' => {
+                            clear_input_queue();
+                            game_state.run_state = RunState::ShowInventory(InventoryAction::Throw);
+                        }
+
                         //Kill himself in debug mode only
                         'k' if game_state.debug_mode => {
                             game_state.run_state = RunState::GameOver;
@@ -353,6 +495,66 @@ impl Player {
                         'i' => {
                             Player::try_inspect(game_state);
                         }
+
+                        //Open or force a door
+                        'o' => {
+                            Player::try_open_door(game_state);
+                        }
+
+                        //Close a door
+                        'C' => {
+                            Player::try_close_door(game_state);
+                        }
+
+                        //Disarm a trap
+                        'x' => {
+                            Player::try_disarm(game_state);
+                        }
+
+                        //Cast a learned spell
+                        'm' => {
+                            clear_input_queue();
+                            Player::try_cast(game_state);
+                        }
+
+                        //Toggle sneak mode
+                        'z' => {
+                            Player::toggle_sneak(game_state);
+                        }
+
+                        //Order hirelings to follow
+                        'F' => {
+                            HirelingManager::give_command(game_state, HirelingCommand::Follow);
+                        }
+
+                        //Order hirelings to stay
+                        'S' => {
+                            HirelingManager::give_command(game_state, HirelingCommand::Stay);
+                        }
+
+                        //Order hirelings to attack a target
+                        'A' => {
+                            clear_input_queue();
+                            game_state.run_state =
+                                RunState::MouseTargeting(SpecialViewMode::HirelingTargeting);
+                        }
+
+                        //Get back items carried by hirelings
+                        'G' => {
+                            HirelingManager::take_back_items(game_state);
+                        }
+
+                        //Show quest journal
+                        'j' => {
+                            clear_input_queue();
+                            game_state.run_state = RunState::ShowJournal;
+                        }
+
+                        //Show overview of visited depths
+                        'O' => {
+                            clear_input_queue();
+                            game_state.run_state = RunState::ShowOverview;
+                        }
                         _ => {}
                     }
                 }
@@ -367,6 +569,7 @@ impl Player {
     ) {
         let player_entity = game_state.current_player_entity.expect("must be Some");
         let mut to_despawn: Option<(Entity, &str)> = None;
+        let mut hireling_target: Option<Entity> = None;
         // Keep RunState to MouseTargeting running while player is targeting
         game_state.run_state = RunState::MouseTargeting(special_view_mode);
         // ESC for escaping targeting without using Invokable
@@ -377,37 +580,45 @@ impl Player {
                     let _ = game_state
                         .ecs_world
                         .remove_one::<WantsToInvoke>(player_entity);
+                    let _ = game_state
+                        .ecs_world
+                        .remove_one::<WantsToCast>(player_entity);
                 }
                 SpecialViewMode::RangedTargeting => {
                     let _ = game_state
                         .ecs_world
                         .remove_one::<WantsToShoot>(player_entity);
                 }
+                SpecialViewMode::ThrowTargeting => {
+                    let _ = game_state
+                        .ecs_world
+                        .remove_one::<WantsToThrow>(player_entity);
+                }
                 _ => {}
             }
             game_state.run_state = RunState::WaitingPlayerInput;
         } else if is_mouse_button_down(MouseButton::Left) {
             let (mouse_x, mouse_y) = mouse_position();
-
-            let rounded_x = (((mouse_x - UI_BORDER_F32) / TILE_SIZE_F32).ceil() - 1.0) as i32;
-            let rounded_y = (((mouse_y - UI_BORDER_F32) / TILE_SIZE_F32).ceil() - 1.0) as i32;
+            let camera = Camera::from_game_state(game_state);
+            let (rounded_x, rounded_y) = camera.to_zone(mouse_x, mouse_y);
 
             let zone = game_state
                 .current_zone
                 .as_mut()
                 .expect("must have Some Zone");
 
+            // Clicks outside the zone or the viewport point at nothing
+            if !zone.is_inside(&rounded_x, &rounded_y) || !camera.is_visible(rounded_x, rounded_y) {
+                return;
+            }
+            let index = zone.get_index_from_xy(&rounded_x, &rounded_y);
+
             match special_view_mode {
-                SpecialViewMode::ZapTargeting | SpecialViewMode::RangedTargeting => {
-                    let mut is_valid_tile = false;
-                    // Scope for keeping borrow checker quiet
-                    {
-                        // Make sure that we are targeting a valid tile
-                        let index = Zone::get_index_from_xy(&rounded_x, &rounded_y);
-                        if index < zone.visible_tiles.len() {
-                            is_valid_tile = zone.visible_tiles[index];
-                        }
-                    }
+                SpecialViewMode::ZapTargeting
+                | SpecialViewMode::RangedTargeting
+                | SpecialViewMode::ThrowTargeting => {
+                    // Make sure that we are targeting a valid tile
+                    let is_valid_tile = zone.visible_tiles[index];
 
                     if is_valid_tile {
                         let _ = game_state.ecs_world.insert_one(
@@ -421,20 +632,12 @@ impl Player {
                     }
                 }
                 SpecialViewMode::Inspecting => {
-                    let mut is_valid_tile = false;
-                    // Scope for keeping borrow checker quiet
-                    {
-                        // Make sure that we are targeting a valid tile
-                        let index = Zone::get_index_from_xy(&rounded_x, &rounded_y);
-                        if index < zone.visible_tiles.len() {
-                            is_valid_tile = zone.visible_tiles[index];
-                        }
-                    }
+                    // Make sure that we are targeting a valid tile
+                    let is_valid_tile = zone.visible_tiles[index];
 
                     // Inspect the first entity found in tile and
                     // query Inspectable and Named components
-                    let first_ent_fount =
-                        zone.tile_content[Zone::get_index_from_xy(&rounded_x, &rounded_y)].first();
+                    let first_ent_fount = zone.tile_content[index].first();
                     if is_valid_tile
                         && let Some(&ent) = first_ent_fount
                         && let Ok(mut q) = game_state
@@ -473,18 +676,45 @@ impl Player {
                     );
                     game_state.run_state = RunState::WaitingPlayerInput;
                 }
+                SpecialViewMode::HirelingTargeting => {
+                    let target_opt = if zone.visible_tiles[index] {
+                        zone.tile_content[index].iter().copied().find(|&ent| {
+                            ent.id() != player_entity.id()
+                                && game_state
+                                    .ecs_world
+                                    .satisfies::<&CombatStats>(ent)
+                                    .unwrap_or(false)
+                        })
+                    } else {
+                        None
+                    };
+
+                    if target_opt.is_some() {
+                        hireling_target = target_opt;
+                    } else {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("There is no one to attack there"));
+                    }
+                    game_state.run_state = RunState::WaitingPlayerInput;
+                }
             }
 
             // Despawn any entity that should be despawned
             // and reset the tile to floor and log
             if let Some((e, name)) = to_despawn {
                 let _ = game_state.ecs_world.despawn(e);
-                zone.tiles[Zone::get_index_from_xy(&rounded_x, &rounded_y)] = TileType::Floor;
+                zone.tiles[index] = TileType::Floor;
                 game_state
                     .game_log
                     .entries
                     .push(Cow::Owned(format!("The {} vanishes in the darkness", name)));
             }
+
+            if let Some(target) = hireling_target {
+                HirelingManager::give_command(game_state, HirelingCommand::Attack(target));
+            }
         }
     }
 
@@ -493,11 +723,62 @@ impl Player {
         let ecs_world = &mut game_state.ecs_world;
         let player_entity = game_state.current_player_entity.expect("Must be Some");
 
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+
         if let Some(item) = Player::take_from_map::<Item>(ecs_world, player_entity) {
-            // Check if the item is being stolen from a shop
-            if Utils::get_item_owner(ecs_world, item).is_some() {
+            // Check if the item is being bought or stolen from a shop
+            if let Some(owner) = Utils::get_item_owner(ecs_world, zone, item) {
+                let depth = zone.depth;
+                let price = TradeSystem::get_price(ecs_world, item, owner, depth, false);
+                let gold = ecs_world
+                    .get::<&Purse>(player_entity)
+                    .map_or(0, |purse| purse.gold);
+
+                // Angry shop owners won't sell anything, so the item can only be stolen
+                let refuses_to_sell =
+                    FactionManager::is_unfriendly(ecs_world, player_entity, owner)
+                        || ecs_world
+                            .get::<&Hates>(owner)
+                            .is_ok_and(|hates| hates.list.contains(&player_entity.id()));
+
+                // No deal if the item cannot be carried away, or the gold would be lost
+                let is_backpack_full = ecs_world
+                    .query::<&InBackback>()
+                    .iter()
+                    .filter(|(_, in_backpack)| in_backpack.owner.id() == player_entity.id())
+                    .count()
+                    >= MAX_ITEMS_IN_BACKPACK;
+
                 //Show Dialog
-                game_state.run_state = RunState::ShowDialog(DialogAction::StealPick(item));
+                if is_backpack_full {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("You cannot carry anymore!"));
+                    game_state.run_state = RunState::WaitingPlayerInput;
+                } else if !refuses_to_sell && price > 0 && gold >= price {
+                    game_state.run_state = RunState::ShowDialog(DialogAction::Buy((
+                        player_entity,
+                        item,
+                        owner,
+                        price,
+                    )));
+                } else {
+                    if !refuses_to_sell && price > 0 {
+                        let item_name = ecs_world
+                            .get::<&Named>(item)
+                            .expect("Item must be Named")
+                            .name;
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "You can't afford the {}, it costs {} gold",
+                            item_name, price
+                        )));
+                    }
+                    game_state.run_state = RunState::ShowDialog(DialogAction::StealPick(item));
+                }
             } else {
                 // Reset heal counter if the player did pick up something
                 let _ = ecs_world.insert_one(
@@ -555,7 +836,11 @@ impl Player {
             && ecs_world.satisfies::<&Edible>(item).unwrap_or(false)
         {
             // Check if the item is being stolen from a shop
-            if Utils::get_item_owner(ecs_world, item).is_some() {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+            if Utils::get_item_owner(ecs_world, zone, item).is_some() {
                 //Show Theft Dialog
 
                 game_state.run_state = RunState::ShowDialog(DialogAction::StealEat(item));
@@ -592,7 +877,7 @@ impl Player {
 
             // Get Water from river
             there_is_river_here =
-                zone.water_tiles[Zone::get_index_from_xy(&player_position.x, &player_position.y)];
+                zone.water_tiles[zone.get_index_from_xy(&player_position.x, &player_position.y)];
         }
 
         if there_is_river_here {
@@ -631,7 +916,7 @@ impl Player {
                 .current_zone
                 .as_ref()
                 .expect("must have Some Zone");
-            standing_on_tile = &zone.tiles[Zone::get_index_from_xy(&position.x, &position.y)];
+            standing_on_tile = &zone.tiles[zone.get_index_from_xy(&position.x, &position.y)];
 
             game_state
                 .game_log
@@ -639,7 +924,9 @@ impl Player {
                 .push(Cow::Borrowed("There is nothing here to pick up"));
 
             //TODO skill check
-            if standing_on_tile == &TileType::DownPassage {
+            if standing_on_tile == &TileType::DownPassage
+                || standing_on_tile == &TileType::BranchPassage
+            {
                 game_state
                     .game_log
                     .entries
@@ -776,18 +1063,30 @@ impl Player {
             // Search for visibile shop owners in the visibile tiles
             for &index in &viewshed.visible_tiles {
                 for &entity in &zone.tile_content[index] {
-                    // If entity is a non-angered shop owner, try to trade
-                    if let Ok(mut res) =
-                        ecs_world.query_one::<(&ShopOwner, &Hates, &Position)>(entity)
-                        && let Some((_, hates, pos)) = res.get()
+                    // If entity is a non-angered shop owner, try to trade.
+                    // NPCs for hire or already working for the player can be paid too
+                    if let Ok(mut res) = ecs_world.query_one::<(
+                        &Hates,
+                        &Position,
+                        Option<&ShopOwner>,
+                        Option<&Hireable>,
+                        Option<&Hireling>,
+                    )>(entity)
+                        && let Some((hates, pos, shop_owner_opt, hireable_opt, hireling_opt)) =
+                            res.get()
                         && !hates.list.contains(&player_entity.id())
                     {
-                        if Utils::distance(&player_pos.x, &pos.x, &player_pos.y, &pos.y)
-                            <= NEXT_TO_DISTANCE
-                        {
+                        let is_next_to =
+                            Utils::distance(&player_pos.x, &pos.x, &player_pos.y, &pos.y)
+                                <= NEXT_TO_DISTANCE;
+                        let can_be_paid = hireable_opt.is_some()
+                            || hireling_opt
+                                .is_some_and(|hireling| hireling.owner.id() == player_entity.id());
+
+                        if is_next_to && (shop_owner_opt.is_some() || can_be_paid) {
                             owner_entity = Some(entity);
                             break;
-                        } else {
+                        } else if shop_owner_opt.is_some() {
                             game_state.game_log.add_entry(Cow::Borrowed(
                                 "You see someone who may trade, but it's too far away",
                             ));
@@ -823,6 +1122,8 @@ impl Player {
     pub fn try_chat(game_state: &mut GameState) {
         game_state.run_state = RunState::WaitingPlayerInput;
         let mut chatter_entity: Option<&'static str> = None;
+        let mut tree_chatter: Option<Entity> = None;
+        let mut simple_chatter: Option<Entity> = None;
         let ecs_world = &mut game_state.ecs_world;
         let player_entity = game_state
             .current_player_entity
@@ -841,16 +1142,22 @@ impl Player {
             // Search for visibile shop owners in the visibile tiles
             for &index in &viewshed.visible_tiles {
                 for &entity in &zone.tile_content[index] {
-                    // If is a non-angered chattable, try to chat with it
+                    // If is a non-angered chattable, try to chat with it.
+                    // Angered ones will talk only if they have a dialogue tree
                     if let Ok(mut res) =
                         ecs_world.query_one::<(&WillChat, &Hates, &Position)>(entity)
                         && let Some((will_chat, hates, pos)) = res.get()
-                        && !hates.list.contains(&player_entity.id())
+                        && (will_chat.tree.is_some() || !hates.list.contains(&player_entity.id()))
                     {
                         // must be adjacent to player
-                        if Utils::distance(&player_pos.x, &pos.x, &player_pos.y, &pos.y)
-                            <= NEXT_TO_DISTANCE
-                        {
+                        let is_adjacent =
+                            Utils::distance(&player_pos.x, &pos.x, &player_pos.y, &pos.y)
+                                <= NEXT_TO_DISTANCE;
+                        if is_adjacent && will_chat.tree.is_some() {
+                            tree_chatter = Some(entity);
+                            break;
+                        } else if is_adjacent {
+                            simple_chatter = Some(entity);
                             let diag_len = will_chat.dialogues.len();
                             chatter_entity = Some(
                                 will_chat.dialogues[Roll::dice(1, diag_len as i32) as usize - 1],
@@ -868,12 +1175,12 @@ impl Player {
                 }
 
                 // Avoid unnecessary iterations
-                if chatter_entity.is_some() {
+                if chatter_entity.is_some() || tree_chatter.is_some() {
                     break;
                 }
             }
 
-            if chatter_entity.is_none() {
+            if chatter_entity.is_none() && tree_chatter.is_none() {
                 game_state
                     .game_log
                     .entries
@@ -881,8 +1188,24 @@ impl Player {
             }
         }
 
-        // If we found a shop owner, offer them an item
-        if let Some(message) = chatter_entity {
+        // Factions that don't like the player will not talk to it
+        if let Some(chatter) = tree_chatter.or(simple_chatter)
+            && FactionManager::is_unfriendly(&game_state.ecs_world, player_entity, chatter)
+        {
+            let named = game_state
+                .ecs_world
+                .get::<&Named>(chatter)
+                .expect("Chatter must be Named");
+            game_state
+                .game_log
+                .add_entry(Cow::Owned(format!("The {} ignores you", named.name)));
+            return;
+        }
+
+        // Hold a conversation or just listen to what the chatter has to say
+        if let Some(chatter) = tree_chatter {
+            DialogueManager::start_chat(game_state, chatter);
+        } else if let Some(message) = chatter_entity {
             Player::wait_after_action(game_state, STANDARD_ACTION_MULTIPLIER);
             game_state.run_state = RunState::ShowDialog(DialogAction::ShowMessage(message));
         }
@@ -891,4 +1214,198 @@ impl Player {
     pub fn try_inspect(game_state: &mut GameState) {
         game_state.run_state = RunState::MouseTargeting(SpecialViewMode::Inspecting);
     }
+
+    /// Try to open (or force, if locked) a door adjacent to the player
+    pub fn try_open_door(game_state: &mut GameState) {
+        let player_entity = game_state
+            .current_player_entity
+            .expect("must be some entity");
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+        let door_opt = {
+            let position = game_state
+                .ecs_world
+                .get::<&Position>(player_entity)
+                .expect("Player must have Position");
+            zone.get_adjacent_closed_doors(&position.x, &position.y, false)
+                .first()
+                .copied()
+        };
+
+        if let Some(target) = door_opt {
+            let _ = game_state
+                .ecs_world
+                .insert_one(player_entity, WantsToOpenDoor { target });
+            game_state.run_state = RunState::DoTick;
+        } else {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("There is no closed door nearby"));
+        }
+    }
+
+    /// Try to close a door adjacent to the player
+    pub fn try_close_door(game_state: &mut GameState) {
+        let player_entity = game_state
+            .current_player_entity
+            .expect("must be some entity");
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+        let mut door_opt: Option<(i32, i32)> = None;
+
+        // Scope for keeping borrow checker quiet
+        {
+            let position = game_state
+                .ecs_world
+                .get::<&Position>(player_entity)
+                .expect("Player must have Position");
+            for x in position.x - 1..=position.x + 1 {
+                for y in position.y - 1..=position.y + 1 {
+                    let index = zone.get_index_from_xy(&x, &y);
+                    if zone.tiles.len() > index && zone.tiles[index] == TileType::OpenDoor {
+                        door_opt = Some((x, y));
+                    }
+                }
+            }
+        }
+
+        if let Some(target) = door_opt {
+            let _ = game_state
+                .ecs_world
+                .insert_one(player_entity, WantsToCloseDoor { target });
+            game_state.run_state = RunState::DoTick;
+        } else {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("There is no open door nearby"));
+        }
+    }
+
+    /// Try to disarm a known trap adjacent to the player, using a wielded tool
+    pub fn try_disarm(game_state: &mut GameState) {
+        let player_entity = game_state
+            .current_player_entity
+            .expect("must be some entity");
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+        let ecs_world = &mut game_state.ecs_world;
+
+        let tool_opt = ecs_world.query::<EquippedDisarmingTool>().iter().find_map(
+            |(item, (in_backpack, ..))| {
+                if in_backpack.owner.id() == player_entity.id() {
+                    Some(item)
+                } else {
+                    None
+                }
+            },
+        );
+
+        let Some(tool) = tool_opt else {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("You must wield a tool to disarm traps"));
+            return;
+        };
+
+        let mut trap_opt: Option<Entity> = None;
+        // Scope for keeping borrow checker quiet
+        {
+            let position = ecs_world
+                .get::<&Position>(player_entity)
+                .expect("Player must have Position");
+            for x in position.x - 1..=position.x + 1 {
+                for y in position.y - 1..=position.y + 1 {
+                    let index = zone.get_index_from_xy(&x, &y);
+                    if zone.tile_content.len() > index
+                        && let Some(&trap) = zone.tile_content[index].iter().find(|&&entity| {
+                            ecs_world.satisfies::<&Trap>(entity).unwrap_or(false)
+                                && !ecs_world.satisfies::<&IsHidden>(entity).unwrap_or(false)
+                        })
+                    {
+                        trap_opt = Some(trap);
+                    }
+                }
+            }
+        }
+
+        if let Some(target) = trap_opt {
+            let _ = ecs_world.insert_one(player_entity, WantsToDisarm { target, tool });
+            game_state.run_state = RunState::DoTick;
+        } else {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("There is no known trap nearby"));
+        }
+    }
+
+    /// Cast the first ready spell among the ones learned by the player
+    fn try_cast(game_state: &mut GameState) {
+        let player = game_state
+            .current_player_entity
+            .expect("Player should be set");
+
+        let ready_spell_opt = {
+            let Ok(spell_list) = game_state.ecs_world.get::<&SpellList>(player) else {
+                game_state
+                    .game_log
+                    .entries
+                    .push(Cow::Borrowed("You don't know any spell"));
+                return;
+            };
+            spell_list.spells.iter().copied().find(|&spell| {
+                game_state
+                    .ecs_world
+                    .get::<&Spell>(spell)
+                    .is_ok_and(|s| s.spell_cooldown == 0)
+            })
+        };
+
+        if let Some(spell) = ready_spell_opt {
+            let _ = game_state
+                .ecs_world
+                .insert_one(player, WantsToCast { spell });
+            game_state.run_state = RunState::MouseTargeting(SpecialViewMode::ZapTargeting);
+        } else {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("You are too tired to cast a spell again"));
+        }
+    }
+
+    /// Toggle sneak mode: slower movement, but harder to notice and able to hide
+    pub fn toggle_sneak(game_state: &mut GameState) {
+        let player_entity = game_state
+            .current_player_entity
+            .expect("must be some entity");
+        let ecs_world = &mut game_state.ecs_world;
+
+        if ecs_world
+            .satisfies::<&Sneaking>(player_entity)
+            .unwrap_or(false)
+        {
+            let _ = ecs_world.remove_one::<Sneaking>(player_entity);
+            let _ = ecs_world.remove_one::<IsHidden>(player_entity);
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("You stop sneaking"));
+        } else {
+            let _ = ecs_world.insert_one(player_entity, Sneaking {});
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("You start sneaking"));
+        }
+    }
 }
@@ -0,0 +1,57 @@
+use hecs::{Entity, World};
+
+use crate::components::common::{FactionEnum, ImmunityTypeEnum};
+
+/// Quests that can be handed out by NPCs
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum QuestId {
+    AbbotGold,
+    MolemanHunt,
+    RefugeeEscort,
+    FarmerCorpse,
+}
+
+/// What must be done to complete a quest
+pub enum QuestKind {
+    BringItems {
+        item_name: &'static str,
+        amount: usize,
+    },
+    Kill {
+        target: Entity,
+    },
+    Escort {
+        escorted: Entity,
+    },
+    DeliverCorpse,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum QuestState {
+    Active,
+    Completed,
+    Failed,
+}
+
+#[derive(Clone, Copy)]
+pub enum QuestReward {
+    Item(fn(&mut World, i32, i32) -> Entity),
+    Experience(u32),
+    Immunity(ImmunityTypeEnum),
+    RawGold(u32),
+}
+
+pub struct Quest {
+    pub id: QuestId,
+    pub description: String,
+    pub giver_name: &'static str,
+    pub giver_faction: Option<FactionEnum>,
+    pub kind: QuestKind,
+    pub state: QuestState,
+    pub reward: QuestReward,
+}
+
+/// All the quests accepted by the player, in the order they were given
+pub struct QuestJournal {
+    pub quests: Vec<Quest>,
+}
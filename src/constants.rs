@@ -1,18 +1,22 @@
-use crate::components::common::SpeciesEnum;
+use crate::components::common::{FactionEnum, SpeciesEnum};
 
 /// UI related constants
 pub const UI_BORDER: i32 = 8;
 pub const UI_BORDER_F32: f32 = UI_BORDER as f32;
-pub const WINDOW_WIDTH: i32 = (UI_BORDER * 2) + (MAP_WIDTH * TILE_SIZE);
-pub const WINDOW_HEIGHT: i32 = (UI_BORDER * 2) + (MAP_HEIGHT * TILE_SIZE) + HUD_HEIGHT;
+pub const WINDOW_WIDTH: i32 = (UI_BORDER * 2) + (VIEWPORT_WIDTH * TILE_SIZE);
+pub const WINDOW_HEIGHT: i32 = (UI_BORDER * 2) + (VIEWPORT_HEIGHT * TILE_SIZE) + HUD_HEIGHT;
 pub const FONT_SIZE: f32 = 30.0;
 pub const LETTER_SIZE: f32 = 14.0;
 
-/// Zone related constats
-pub const MAP_WIDTH: i32 = 45;
-pub const MAP_HEIGHT: i32 = 24;
-pub const MAP_WIDTH_F32: f32 = MAP_WIDTH as f32;
-pub const MAP_HEIGHT_F32: f32 = MAP_HEIGHT as f32;
+/// Tiles of the zone shown on screen at once
+pub const VIEWPORT_WIDTH: i32 = 45;
+pub const VIEWPORT_HEIGHT: i32 = 24;
+pub const VIEWPORT_WIDTH_F32: f32 = VIEWPORT_WIDTH as f32;
+pub const VIEWPORT_HEIGHT_F32: f32 = VIEWPORT_HEIGHT as f32;
+
+/// Zone related constats. Size of the zones made by most builders, any size can be used
+pub const MAP_WIDTH: i32 = 90;
+pub const MAP_HEIGHT: i32 = 48;
 pub const TILE_SIZE: i32 = 24;
 pub const TILE_SIZE_F32: f32 = TILE_SIZE as f32;
 pub const BRAZIER_RADIUS: i32 = 20;
@@ -25,9 +29,12 @@ pub const MAX_GOLD_IN_ZONE: i32 = 10;
 pub const CRYSTAL_LIGHT_RADIUS: i32 = 1;
 pub const CRYSTAL_GROWTH_COUNTER_START: u8 = 2 * NORMAL as u8;
 pub const CRYSTAL_CAVE_DEPTH: u32 = 9;
+pub const FLOODED_MINE_DEPTH: u32 = 3;
+pub const FUNGAL_GROTTO_DEPTH: u32 = 6;
+pub const STONEDUST_MONASTERY_DEPTH: u32 = 11;
 
 /// Hud related constants
-pub const HUD_WIDTH: i32 = MAP_WIDTH * TILE_SIZE;
+pub const HUD_WIDTH: i32 = VIEWPORT_WIDTH * TILE_SIZE;
 pub const HUD_HEIGHT: i32 = 192 + UI_BORDER;
 pub const HUD_BORDER: i32 = 4;
 
@@ -69,7 +76,7 @@ pub const SLOW: i32 = 4;
 pub const NORMAL: i32 = 2;
 pub const FAST: i32 = 1;
 pub const STANDARD_ACTION_MULTIPLIER: i32 = 1;
-// pub const LONG_ACTION_MULTIPLIER: i32 = 2;
+pub const LONG_ACTION_MULTIPLIER: i32 = 2;
 pub const VERY_LONG_ACTION_MULTIPLIER: i32 = 3;
 
 /// Spawning related constants
@@ -94,6 +101,25 @@ pub const DRUNKEN_WALK_LIFE_MAX: i32 = 50;
 pub const DRUNKEN_WALK_MAX_ITERATIONS: i32 = 50;
 pub const DRUNKEN_WALK_MIN_ITERATIONS: i32 = 10;
 
+/// Cellular automata related constants
+pub const CAVERN_FILL_PERCENT: i32 = 45;
+pub const CAVERN_MAX_FILL_PERCENT: i32 = 55;
+pub const CAVERN_SMOOTHING_ITERATIONS: i32 = 5;
+pub const CAVERN_PILLAR_ITERATIONS: i32 = 3;
+pub const CAVERN_WALL_THRESHOLD: usize = 5;
+pub const CAVERN_PILLAR_THRESHOLD: usize = 2;
+pub const CAVERN_WIDTH: i32 = 135;
+pub const CAVERN_HEIGHT: i32 = 72;
+
+/// Prefab related constants
+pub const PREFAB_PLACEMENT_TENTATIVES: i32 = 150;
+pub const PREFAB_MIN_FLOOR_PERCENT: i32 = 25;
+
+/// Connectivity related constants
+pub const MAX_CONNECTIVITY_REPAIRS: i32 = 100;
+pub const MAX_ZONE_REBUILDS: i32 = 10;
+pub const TUNNEL_PATH_COST: u32 = 3;
+
 /// Item related constants
 pub const STARTING_ROT_COUNTER: i32 = 100;
 pub const LANTERN_RADIUS: i32 = 6;
@@ -129,6 +155,93 @@ pub const MUSHROOM_SPAWN_MAP: [i32; 15] = [
 pub const RUST_MAX_VALUE: u32 = 3;
 pub const RUST_CHANCE: i32 = 1;
 
+/// Fire related constants
+pub const FIRE_LIGHT_RADIUS: i32 = 3;
+pub const FIRE_DAMAGE_DICE: i32 = 4;
+pub const FIRE_SPREAD_CHANCE: i32 = 2;
+pub const STARTING_BURNING_COUNTER: i32 = 8;
+pub const OIL_BURNING_COUNTER: i32 = 5;
+pub const MUSHROOM_FIELD_BURNING_COUNTER: i32 = 12;
+pub const MAX_THROW_DISTANCE: f32 = 6.0;
+
+/// Gas related constants
+pub const MAX_GAS_DENSITY: u8 = 12;
+pub const GAS_OPAQUE_DENSITY: u8 = 6;
+pub const GAS_DECAY: u8 = 1;
+pub const FIRE_SMOKE_DENSITY: u8 = 4;
+pub const MOLD_SPORES_DENSITY: u8 = 8;
+pub const MOLD_SPORES_CHANCE: i32 = 1;
+pub const SULFUR_FUMES_DENSITY: u8 = 3;
+pub const SULFUR_FUMES_CHANCE: i32 = 20;
+pub const BOMBARDIER_SPRAY_DENSITY: u8 = 6;
+pub const GAS_POISON_DAMAGE_DICE: i32 = 4;
+pub const GAS_STUN_TICKS: i32 = 2;
+
+/// Door related constants
+pub const CLOSED_DOOR_PATH_COST: u32 = 3;
+pub const FORCE_DOOR_PENALTY: i32 = 5;
+pub const DOOR_CHANCE: i32 = 3;
+pub const LOCKED_DOOR_CHANCE: i32 = 2;
+
+/// Trap related constants
+pub const MAX_TRAPS_IN_ZONE: i32 = 4;
+pub const TRAP_SPOT_DISTANCE: f32 = 2.5;
+pub const TRAP_SPOT_PENALTY: i32 = 8;
+pub const PIT_DAMAGE_DICE: i32 = 6;
+pub const FALLING_ROCKS_DAMAGE_DICE: i32 = 6;
+pub const SPIKE_PLATE_DAMAGE_DICE: i32 = 8;
+pub const ALARM_RADIUS: f32 = 15.0;
+pub const ALARM_APPROACH_COUNTER: u32 = 10;
+pub const GAS_VENT_DENSITY: u8 = 10;
+
+/// Chasm related constants
+pub const CHASM_CHANCE: i32 = 1;
+pub const MAX_PITS_IN_CHASM: i32 = 3;
+pub const FALL_DAMAGE_DICE: i32 = 6;
+
+/// Heat related constants
+pub const MAX_MAGMA_RIVERS_IN_ZONE: i32 = 2;
+pub const MAGMA_RIVER_TENTATIVES: i32 = 10;
+pub const LAVA_CRUST_CHANCE: i32 = 1;
+pub const LAVA_CRUST_STEPS: u8 = 3;
+pub const LAVA_LIGHT_RADIUS: i32 = 2;
+pub const LAVA_STEAM_DENSITY: u8 = 5;
+pub const HOT_ROCK_DAMAGE_DICE: i32 = 3;
+pub const STEAM_DAMAGE_DICE: i32 = 3;
+
+/// Swimming related constants
+pub const LAKE_CHANCE: i32 = 2;
+pub const LAKE_MIN_RADIUS: i32 = 2;
+pub const LAKE_MAX_RADIUS: i32 = 5;
+pub const BULKY_SWIM_PENALTY: i32 = 4;
+pub const METALLIC_SWIM_PENALTY: i32 = 2;
+pub const DROWNING_DAMAGE_DICE: i32 = 4;
+pub const LOSE_ITEM_WHILE_SWIMMING_CHANCE: i32 = 1;
+
+/// Tunnelling related constants
+pub const TUNNEL_DIG_DICE_SIZE: i32 = 10;
+pub const WALL_DIG_DICE_NUMBER: i32 = 8;
+pub const BRICK_WALL_DIG_DICE_NUMBER: i32 = 12;
+pub const CRYSTAL_DIG_DICE_NUMBER: i32 = 16;
+pub const CAVE_IN_CHANCE: i32 = 2;
+pub const CAVE_IN_DAMAGE_DICE: i32 = 8;
+pub const STONE_EATER_TUNNEL_CHANCE: i32 = 2;
+
+/// Noise related constants
+pub const DIGGING_NOISE_RADIUS: f32 = 12.0;
+pub const DIGGING_NOISE_LOUDNESS: i32 = 16;
+pub const FIGHTING_NOISE_RADIUS: f32 = 8.0;
+pub const FIGHTING_NOISE_LOUDNESS: i32 = 12;
+pub const SHOOTING_NOISE_RADIUS: f32 = 6.0;
+pub const SHOOTING_NOISE_LOUDNESS: i32 = 8;
+pub const BREAKING_DOOR_NOISE_RADIUS: f32 = 14.0;
+pub const BREAKING_DOOR_NOISE_LOUDNESS: i32 = 18;
+pub const CLANKING_ARMOR_NOISE_RADIUS: f32 = 5.0;
+pub const CLANKING_ARMOR_NOISE_LOUDNESS: i32 = 10;
+pub const SPLASHING_NOISE_RADIUS: f32 = 4.0;
+pub const SPLASHING_NOISE_LOUDNESS: i32 = 12;
+pub const NOISE_APPROACH_COUNTER: u32 = 10;
+
 /// Monsters related constats
 pub const BASE_MONSTER_VIEW_RADIUS: i32 = 8;
 pub const MAX_HIDDEN_TURNS: i32 = 9;
@@ -136,6 +249,12 @@ pub const SLUG_TRAIL_LIFETIME: u32 = 50;
 pub const FILTH_TRAIL_LIFETIME: u32 = 20;
 pub const ACID_DECAL_DAMAGE_DICE: i32 = 6;
 pub const MAX_PRIORITIES_NUMBER: usize = 5;
+pub const MONSTER_SMELL_RADIUS: f32 = 10.0;
+pub const MONSTER_LISTEN_RADIUS: f32 = 12.0;
+pub const MONSTER_SEARCH_RADIUS: f32 = 3.0;
+pub const MONSTER_SEARCH_TURNS: u32 = 8;
+pub const WAKE_UP_DISTANCE: f32 = 4.0;
+pub const SNEAK_BONUS: i32 = 4;
 pub const HUMAN_SPECIES_HATES: [SpeciesEnum; 4] = [
     SpeciesEnum::Fish,
     SpeciesEnum::Gastropod,
@@ -197,6 +316,65 @@ pub const UNDEAD_SPECIES_HATES: [SpeciesEnum; 4] = [
     SpeciesEnum::Gremlin,
 ];
 
+/// Morale related constants
+pub const MORALE_BASE: i32 = 10;
+pub const WOUNDED_MORALE_PENALTY: i32 = 4;
+pub const ALLY_DEATH_MORALE_PENALTY: i32 = 3;
+pub const FLEE_TURNS: i32 = 10;
+pub const CALL_FOR_HELP_RADIUS: f32 = 10.0;
+pub const CALL_FOR_HELP_APPROACH_COUNTER: u32 = 10;
+pub const FEARLESS_SPECIES: [SpeciesEnum; 3] = [
+    SpeciesEnum::Slime,
+    SpeciesEnum::Myconid,
+    SpeciesEnum::Undead,
+];
+
+/// Hireling related constants
+pub const HIRE_GOLD_LOYALTY: i32 = 300;
+pub const HIRE_FOOD_LOYALTY: i32 = 150;
+pub const MAX_LOYALTY: i32 = 900;
+pub const HUNGRY_LOYALTY_PENALTY: i32 = 2;
+pub const STARVED_LOYALTY_PENALTY: i32 = 5;
+pub const HIRELING_FOLLOW_DISTANCE: f32 = 2.0;
+
+/// Dialogue related constants
+pub const DIALOGUE_REVEAL_RADIUS: i32 = 6;
+
+/// Quest related constants
+pub const QUEST_GOLD_AMOUNT: usize = 3;
+pub const QUEST_HUNT_EXPERIENCE: u32 = 25;
+pub const QUEST_ESCORT_GOLD: u32 = 2;
+
+/// Faction related constants
+pub const STARTING_REPUTATIONS: [(FactionEnum, i32); 4] = [
+    (FactionEnum::StonedustCult, 20),
+    (FactionEnum::Molemen, 0),
+    (FactionEnum::Refugees, 0),
+    (FactionEnum::DeepSpawn, -60),
+];
+pub const MAX_REPUTATION: i32 = 100;
+pub const MIN_REPUTATION: i32 = -100;
+pub const FRIENDLY_REPUTATION: i32 = 30;
+pub const UNFRIENDLY_REPUTATION: i32 = -20;
+pub const HOSTILE_REPUTATION: i32 = -50;
+pub const REPUTATION_ATTACK_PENALTY: i32 = 5;
+pub const REPUTATION_KILL_PENALTY: i32 = 25;
+pub const REPUTATION_HELP_BONUS: i32 = 5;
+pub const REPUTATION_TRADE_BONUS: i32 = 2;
+pub const REPUTATION_QUEST_BONUS: i32 = 20;
+
+/// Price related constants, percents of the base value of an item
+pub const STARTING_GOLD: u32 = 5;
+pub const PRICE_DEPTH_PERCENT: u32 = 10;
+pub const UNWANTED_SELL_PERCENT: u32 = 50;
+pub const WRONGED_PRICE_PERCENT: u32 = 50;
+pub const WET_PRICE_PERCENT: u32 = 75;
+pub const ROTTEN_PRICE_PERCENT: u32 = 10;
+pub const ERODED_PRICE_PERCENT: u32 = 25;
+
+/// Overview related constants
+pub const OVERVIEW_MAX_LINE_LENGTH: usize = 36;
+
 /// Saving Throw related constants
 pub const AUTOFAIL_SAVING_THROW: i32 = 999;
 
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use hecs::World;
 use macroquad::{
-    color::{BLACK, Color, DARKGRAY, GRAY, GREEN, ORANGE, RED, WHITE, YELLOW},
+    color::{BEIGE, BLACK, BROWN, Color, DARKGRAY, GRAY, GREEN, ORANGE, RED, WHITE, YELLOW},
     input::mouse_position,
     math::Rect,
     shapes::{draw_circle, draw_rectangle, draw_rectangle_lines},
@@ -13,14 +13,19 @@ use macroquad::{
 use crate::{
     components::{
         combat::{CombatStats, IsHidden},
-        common::{CanSmell, Experience, Position, Renderable, SmellIntensity, Smellable},
+        common::{CanSmell, Experience, Position, Purse, Renderable, SmellIntensity, Smellable},
         health::{Hunger, Thirst},
         player::{Player, SpecialViewMode},
     },
     constants::*,
-    engine::state::{GameState, RunState},
+    engine::{
+        camera::Camera,
+        state::{GameState, RunState},
+    },
     inventory::Inventory,
-    maps::zone::{DecalType, TileType, Zone},
+    journal::Journal,
+    maps::zone::{DecalType, GasType, TileType, Zone},
+    overview::Overview,
     systems::{hunger_check::HungerStatus, thirst_check::ThirstStatus},
     utils::{
         assets::TextureName,
@@ -32,6 +37,16 @@ use crate::{
     },
 };
 
+type RenderableWithPosition<'a> = (
+    hecs::Entity,
+    (
+        &'a Renderable,
+        &'a Position,
+        Option<&'a IsHidden>,
+        Option<&'a Player>,
+    ),
+);
+
 pub struct Draw {}
 
 impl Draw {
@@ -43,27 +58,31 @@ impl Draw {
             }
             RunState::TitleScreen => Draw::title_screen(assets),
             _ => {
-                // Zone and renderables
+                // Zone and renderables, seen from a camera following the player
+                let camera = Camera::from_game_state(game_state);
                 let zone = game_state
                     .current_zone
                     .as_ref()
                     .expect("must have Some Zone");
-                Draw::zone(zone, assets);
-                Draw::renderables(&game_state.ecs_world, assets, zone);
-                Draw::smells(&game_state.ecs_world, assets, zone);
+                Draw::zone(zone, assets, &camera);
+                Draw::renderables(&game_state.ecs_world, assets, zone, &camera);
+                Draw::gas_clouds(zone, &camera);
+                Draw::smells(&game_state.ecs_world, assets, zone, &camera);
 
                 #[cfg(not(target_arch = "wasm32"))]
                 if game_state.debug_mode {
-                    Draw::debug_exit(zone);
-                    Draw::debug_tiles_type(zone);
+                    Draw::debug_exit(zone, &camera);
+                    Draw::debug_tiles_type(zone, &camera);
                     if game_state.debug_monster_vision {
-                        Draw::debug_monster_viewshed(game_state);
+                        Draw::debug_monster_viewshed(game_state, &camera);
                     }
                 }
 
                 //Overlay (clone is needed to avoid borrow checker errors)
                 match &game_state.run_state.clone() {
                     RunState::ShowInventory(mode) => Inventory::draw(assets, game_state, mode),
+                    RunState::ShowJournal => Journal::draw(game_state),
+                    RunState::ShowOverview => Overview::draw(game_state),
                     RunState::ShowDialog(mode) => match mode {
                         DialogAction::ShowMessage(_) => {
                             SimpleDialog::draw(assets, &game_state.ecs_world, mode)
@@ -71,7 +90,7 @@ impl Draw {
                         _ => ChoiceDialog::draw(assets, &game_state.ecs_world, mode),
                     },
                     RunState::MouseTargeting(special_view_mode) => {
-                        Draw::targeting(game_state, special_view_mode);
+                        Draw::targeting(game_state, special_view_mode, &camera);
                     }
                     RunState::DrawParticles => {
                         let mut animations = game_state.ecs_world.query::<&mut ParticleAnimation>();
@@ -80,7 +99,7 @@ impl Draw {
                             .as_ref()
                             .expect("must have Some Zone");
                         for a in &mut animations {
-                            Draw::particles(a.1, assets, zone);
+                            Draw::particles(a.1, assets, zone, &camera);
                         }
                     }
                     _ => {}
@@ -96,14 +115,14 @@ impl Draw {
         // ------- Background Rectangle -----------
         draw_rectangle(
             UI_BORDER_F32,
-            (MAP_HEIGHT * TILE_SIZE) as f32 + 2.0 * UI_BORDER as f32,
+            (VIEWPORT_HEIGHT * TILE_SIZE) as f32 + 2.0 * UI_BORDER as f32,
             HUD_WIDTH as f32,
             HUD_HEIGHT as f32,
             WHITE,
         );
         draw_rectangle(
             (HUD_BORDER + UI_BORDER) as f32,
-            (HUD_BORDER + MAP_HEIGHT * TILE_SIZE) as f32 + 2.0 * UI_BORDER as f32,
+            (HUD_BORDER + VIEWPORT_HEIGHT * TILE_SIZE) as f32 + 2.0 * UI_BORDER as f32,
             (HUD_WIDTH - UI_BORDER) as f32,
             (HUD_HEIGHT - UI_BORDER) as f32,
             BLACK,
@@ -124,7 +143,7 @@ impl Draw {
                 (HUD_BORDER
                     + 32
                     + (UI_BORDER * 4)
-                    + (MAP_HEIGHT * TILE_SIZE)
+                    + (VIEWPORT_HEIGHT * TILE_SIZE)
                     + ((MAX_MESSAGES_IN_LOG - index) as i32 * 32)) as f32,
                 FONT_SIZE,
                 WHITE,
@@ -148,9 +167,9 @@ impl Draw {
             .expect("must have Some Player");
 
         let mut player_query = ecs_world
-            .query_one::<(&Experience, &CombatStats, &Hunger, &Thirst)>(player_entity)
+            .query_one::<(&Experience, &CombatStats, &Hunger, &Thirst, &Purse)>(player_entity)
             .expect("Player is not in hecs::World");
-        let (experience, player_stats, hunger, thirst) =
+        let (experience, player_stats, hunger, thirst, purse) =
             player_query.get().expect("Player is not in hecs::World");
 
         let level_text = format!("LVL:{}", player_stats.level);
@@ -186,11 +205,13 @@ impl Draw {
         let dex_text_len = dex_text.len();
         let depth_text = format!("Depth:{}", zone.depth);
         let depth_text_len = depth_text.len();
+        let gold_text = format!("Gold:{}", purse.gold);
+        let gold_text_len = gold_text.len();
 
         draw_rectangle(
             (HEADER_LEFT_SPAN + HUD_BORDER) as f32,
-            (MAP_HEIGHT * TILE_SIZE) as f32 + UI_BORDER as f32,
-            8.0 * LETTER_SIZE - HUD_BORDER as f32 * 2.0
+            (VIEWPORT_HEIGHT * TILE_SIZE) as f32 + UI_BORDER as f32,
+            9.0 * LETTER_SIZE - HUD_BORDER as f32 * 2.0
                 + (level_text_len as f32 * LETTER_SIZE)
                 + (exp_text_len as f32 * LETTER_SIZE)
                 + (sta_text_len as f32 * LETTER_SIZE)
@@ -198,7 +219,8 @@ impl Draw {
                 + (dex_text_len as f32 * LETTER_SIZE)
                 + (hunger_text_len as f32 * LETTER_SIZE)
                 + (thirst_text_len as f32 * LETTER_SIZE)
-                + (depth_text_len as f32 * LETTER_SIZE),
+                + (depth_text_len as f32 * LETTER_SIZE)
+                + (gold_text_len as f32 * LETTER_SIZE),
             HEADER_HEIGHT as f32,
             BLACK,
         );
@@ -316,41 +338,73 @@ impl Draw {
                 + (thirst_text_len as f32 * LETTER_SIZE),
             text_color,
         );
+
+        // Draw Gold
+        Draw::stat_text(
+            &gold_text,
+            8.0 * LETTER_SIZE
+                + (level_text_len as f32 * LETTER_SIZE)
+                + (exp_text_len as f32 * LETTER_SIZE)
+                + (sta_text_len as f32 * LETTER_SIZE)
+                + (tou_text_len as f32 * LETTER_SIZE)
+                + (dex_text_len as f32 * LETTER_SIZE)
+                + (hunger_text_len as f32 * LETTER_SIZE)
+                + (thirst_text_len as f32 * LETTER_SIZE)
+                + (depth_text_len as f32 * LETTER_SIZE),
+            text_color,
+        );
     }
 
     fn stat_text(text: &str, left_pad: f32, text_color: Color) {
         draw_text(
             text,
             (HUD_BORDER + HEADER_LEFT_SPAN + UI_BORDER) as f32 + left_pad,
-            (HUD_BORDER + UI_BORDER * 3 + MAP_HEIGHT * TILE_SIZE) as f32,
+            (HUD_BORDER + UI_BORDER * 3 + VIEWPORT_HEIGHT * TILE_SIZE) as f32,
             FONT_SIZE,
             text_color,
         );
     }
 
     /// Draw all Renderable entities in World
-    fn renderables(ecs_world: &World, assets: &HashMap<TextureName, Texture2D>, zone: &Zone) {
+    fn renderables(
+        ecs_world: &World,
+        assets: &HashMap<TextureName, Texture2D>,
+        zone: &Zone,
+        camera: &Camera,
+    ) {
         //Get all entities in readonly
-        let mut renderables_with_position = ecs_world
-            .query::<(&Renderable, &Position)>()
-            .without::<&IsHidden>();
+        let mut renderables_with_position =
+            ecs_world.query::<(&Renderable, &Position, Option<&IsHidden>, Option<&Player>)>();
 
-        let mut renderables_vec: Vec<(hecs::Entity, (&Renderable, &Position))> =
-            renderables_with_position.iter().collect();
-        renderables_vec.sort_by_key(|(_, (renderable, _))| renderable.z_index);
+        // Hidden entities are not drawn, unless it's the sneaking player
+        let mut renderables_vec: Vec<RenderableWithPosition<'_>> = renderables_with_position
+            .iter()
+            .filter(|(_, (_, _, hidden_opt, player_opt))| {
+                hidden_opt.is_none() || player_opt.is_some()
+            })
+            .collect();
+        renderables_vec.sort_by_key(|(_, (renderable, ..))| renderable.z_index);
 
-        for (_, (renderable, position)) in renderables_vec {
+        for (_, (renderable, position, hidden_opt, _)) in renderables_vec {
             let texture_to_render = assets
                 .get(&renderable.texture_name)
                 .expect("Texture not found");
 
-            if zone.visible_tiles[Zone::get_index_from_xy(&position.x, &position.y)] {
+            if camera.is_visible(position.x, position.y)
+                && zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)]
+            {
+                let (screen_x, screen_y) = camera.to_screen(position.x, position.y);
                 // Take the texture and draw only the wanted tile ( DrawTextureParams.source )
                 draw_texture_ex(
                     texture_to_render,
-                    (UI_BORDER + (position.x * TILE_SIZE)) as f32,
-                    (UI_BORDER + (position.y * TILE_SIZE)) as f32,
-                    WHITE, // Seems like White color is needed to normal render
+                    screen_x,
+                    screen_y,
+                    // Seems like White color is needed to normal render
+                    if hidden_opt.is_some() {
+                        Color::new(1.0, 1.0, 1.0, 0.5)
+                    } else {
+                        WHITE
+                    },
                     DrawTextureParams {
                         source: Some(renderable.texture_region),
                         ..Default::default()
@@ -400,7 +454,7 @@ impl Draw {
     }
 
     /// Draw target on tile where mouse is poiting
-    fn targeting(game_state: &mut GameState, special_view_mode: &SpecialViewMode) {
+    fn targeting(game_state: &mut GameState, special_view_mode: &SpecialViewMode, camera: &Camera) {
         draw_text(
             "Use mouse to select, ESC to cancel",
             24.0,
@@ -414,28 +468,26 @@ impl Draw {
             .as_ref()
             .expect("must have Some Zone");
         let (mouse_x, mouse_y) = mouse_position();
-
-        let rounded_x = (((mouse_x - UI_BORDER_F32) / TILE_SIZE_F32).ceil() - 1.0) as i32;
-        let rounded_y = (((mouse_y - UI_BORDER_F32) / TILE_SIZE_F32).ceil() - 1.0) as i32;
+        let (rounded_x, rounded_y) = camera.to_zone(mouse_x, mouse_y);
 
         // Draw target if tile is visible
-        let index = Zone::get_index_from_xy(&rounded_x, &rounded_y);
-        if special_view_mode == &SpecialViewMode::Smell
-            || (zone.visible_tiles.len() > index && zone.visible_tiles[index])
+        if zone.is_inside(&rounded_x, &rounded_y)
+            && camera.is_visible(rounded_x, rounded_y)
+            && (special_view_mode == &SpecialViewMode::Smell
+                || zone.visible_tiles[zone.get_index_from_xy(&rounded_x, &rounded_y)])
         {
-            draw_rectangle_lines(
-                (UI_BORDER + (rounded_x * TILE_SIZE)) as f32,
-                (UI_BORDER + (rounded_y * TILE_SIZE)) as f32,
-                TILE_SIZE_F32,
-                TILE_SIZE_F32,
-                3.0,
-                RED,
-            );
+            let (screen_x, screen_y) = camera.to_screen(rounded_x, rounded_y);
+            draw_rectangle_lines(screen_x, screen_y, TILE_SIZE_F32, TILE_SIZE_F32, 3.0, RED);
         }
     }
 
     /// Draws smells
-    fn smells(ecs_world: &World, assets: &HashMap<TextureName, Texture2D>, zone: &Zone) {
+    fn smells(
+        ecs_world: &World,
+        assets: &HashMap<TextureName, Texture2D>,
+        zone: &Zone,
+        camera: &Camera,
+    ) {
         let mut player_query_smell = ecs_world
             .query::<(&Position, &CanSmell)>()
             .with::<&Player>();
@@ -444,7 +496,7 @@ impl Draw {
             //Show smellable on not visibile tiles
             let mut smells_with_position = ecs_world.query::<(&Position, &Smellable)>();
             for (_, (smell_position, smell)) in &mut smells_with_position {
-                let index = Zone::get_index_from_xy(&smell_position.x, &smell_position.y);
+                let index = zone.get_index_from_xy(&smell_position.x, &smell_position.y);
 
                 let distance = Utils::distance(
                     &smell_position.x,
@@ -462,7 +514,7 @@ impl Draw {
                                     || player_smell_ability.intensity == SmellIntensity::Strong))); // Player have improved smell (can smell faint odors from far away)
 
                 //draw not visible smellables within smell radius
-                if can_smell {
+                if can_smell && camera.is_visible(smell_position.x, smell_position.y) {
                     let texture_to_render = assets
                         .get(&TextureName::Particles)
                         .expect("Texture not found");
@@ -472,10 +524,11 @@ impl Draw {
                         index += 1.0;
                     }
 
+                    let (screen_x, screen_y) = camera.to_screen(smell_position.x, smell_position.y);
                     draw_texture_ex(
                         texture_to_render,
-                        (UI_BORDER + (smell_position.x * TILE_SIZE)) as f32,
-                        (UI_BORDER + (smell_position.y * TILE_SIZE)) as f32,
+                        screen_x,
+                        screen_y,
                         WHITE, // Seems like White color is needed to normal render
                         DrawTextureParams {
                             source: Some(Rect {
@@ -493,12 +546,13 @@ impl Draw {
     }
 
     /// Draws zone
-    pub fn zone(zone: &Zone, assets: &HashMap<TextureName, Texture2D>) {
+    pub fn zone(zone: &Zone, assets: &HashMap<TextureName, Texture2D>, camera: &Camera) {
         let texture_to_render = assets.get(&TextureName::Tiles).expect("Texture not found");
 
-        for x in 0..MAP_WIDTH {
-            for y in 0..MAP_HEIGHT {
-                let tile_to_draw = Zone::get_index_from_xy(&x, &y);
+        // Only the part of the zone inside the viewport
+        for x in camera.x..(camera.x + VIEWPORT_WIDTH).min(zone.width) {
+            for y in camera.y..(camera.y + VIEWPORT_HEIGHT).min(zone.height) {
+                let tile_to_draw = zone.get_index_from_xy(&x, &y);
                 let tile_index = Zone::get_tile_sprite_sheet_index(&zone.tiles[tile_to_draw]);
 
                 if zone.revealed_tiles[tile_to_draw] {
@@ -509,10 +563,11 @@ impl Draw {
                     }
 
                     // Take the texture and draw only the wanted tile ( DrawTextureParams.source )
+                    let (screen_x, screen_y) = camera.to_screen(x, y);
                     draw_texture_ex(
                         texture_to_render,
-                        (UI_BORDER + (x * TILE_SIZE)) as f32,
-                        (UI_BORDER + (y * TILE_SIZE)) as f32,
+                        screen_x,
+                        screen_y,
                         alpha,
                         DrawTextureParams {
                             source: Some(Rect {
@@ -531,8 +586,8 @@ impl Draw {
                         && zone.decals_tiles.contains_key(&tile_to_draw)
                     {
                         Draw::draw_decals(
-                            &x,
-                            &y,
+                            screen_x,
+                            screen_y,
                             zone.decals_tiles
                                 .get(&tile_to_draw)
                                 .expect("decals_tiles not available"),
@@ -543,47 +598,60 @@ impl Draw {
         }
     }
 
-    /// Utility for drawing blood blots
-    pub fn draw_decals(x: &i32, y: &i32, particle_type: &DecalType) {
+    /// Draw translucent gas clouds over visible tiles
+    fn gas_clouds(zone: &Zone, camera: &Camera) {
+        for (&index, gas) in zone.gas_tiles.iter() {
+            if !zone.visible_tiles[index] {
+                continue;
+            }
+
+            let base_color = match gas.gas_type {
+                GasType::Poison => GREEN,
+                GasType::Spores => BEIGE,
+                GasType::Smoke => GRAY,
+                GasType::Sulfur => YELLOW,
+                GasType::Steam => WHITE,
+            };
+            // Denser gas is less transparent
+            let alpha = (gas.density as f32 / MAX_GAS_DENSITY as f32).min(0.7);
+
+            let (x, y) = zone.get_xy_from_index(index);
+            if !camera.is_visible(x, y) {
+                continue;
+            }
+
+            let (screen_x, screen_y) = camera.to_screen(x, y);
+            draw_rectangle(
+                screen_x,
+                screen_y,
+                TILE_SIZE_F32,
+                TILE_SIZE_F32,
+                Color::new(base_color.r, base_color.g, base_color.b, alpha),
+            );
+        }
+    }
+
+    /// Utility for drawing blood blots, on the tile drawn at the given screen position
+    pub fn draw_decals(screen_x: f32, screen_y: f32, particle_type: &DecalType) {
         let color = match particle_type {
             DecalType::Blood => RED,
             DecalType::Vomit => ORANGE,
             DecalType::Slime => WHITE,
             DecalType::Acid => GREEN,
             DecalType::Filth => GRAY,
+            DecalType::Oil => BROWN,
+            DecalType::Ash => DARKGRAY,
         };
 
-        draw_circle(
-            (UI_BORDER + (x * TILE_SIZE) + TILE_SIZE / 2) as f32 - 6.0,
-            (UI_BORDER + (y * TILE_SIZE) + TILE_SIZE / 2) as f32 - 7.0,
-            2.0,
-            color,
-        );
-        draw_circle(
-            (UI_BORDER + (x * TILE_SIZE) + TILE_SIZE / 2) as f32 + 4.0,
-            (UI_BORDER + (y * TILE_SIZE) + TILE_SIZE / 2) as f32 - 4.0,
-            2.0,
-            color,
-        );
-        draw_circle(
-            (UI_BORDER + (x * TILE_SIZE) + TILE_SIZE / 2) as f32 - 5.0,
-            (UI_BORDER + (y * TILE_SIZE) + TILE_SIZE / 2) as f32 + 4.0,
-            1.0,
-            color,
-        );
-        draw_circle(
-            (UI_BORDER + (x * TILE_SIZE) + TILE_SIZE / 2) as f32 + 3.0,
-            (UI_BORDER + (y * TILE_SIZE) + TILE_SIZE / 2) as f32 + 5.0,
-            2.0,
-            color,
-        );
+        let center_x = screen_x + TILE_SIZE_F32 / 2.0;
+        let center_y = screen_y + TILE_SIZE_F32 / 2.0;
 
-        draw_circle(
-            (UI_BORDER + (x * TILE_SIZE) + TILE_SIZE / 2) as f32,
-            (UI_BORDER + (y * TILE_SIZE) + TILE_SIZE / 2) as f32,
-            4.0,
-            color,
-        );
+        draw_circle(center_x - 6.0, center_y - 7.0, 2.0, color);
+        draw_circle(center_x + 4.0, center_y - 4.0, 2.0, color);
+        draw_circle(center_x - 5.0, center_y + 4.0, 1.0, color);
+        draw_circle(center_x + 3.0, center_y + 5.0, 2.0, color);
+
+        draw_circle(center_x, center_y, 4.0, color);
     }
 
     /// Draw particles
@@ -591,6 +659,7 @@ impl Draw {
         animation: &mut ParticleAnimation,
         assets: &HashMap<TextureName, Texture2D>,
         zone: &Zone,
+        camera: &Camera,
     ) {
         if animation.current_frame < animation.frames.len() {
             let texture_to_render = assets
@@ -629,14 +698,16 @@ impl Draw {
                 }
                 // If the previous position is the starting one and the animation should exclude the first subframe, skip drawing
                 // This is important for the rays animations, avoiding overlap of the first subframe with the origin
-                if zone.visible_tiles[Zone::get_index_from_xy(x, y)]
+                if camera.is_visible(*x, *y)
+                    && zone.visible_tiles[zone.get_index_from_xy(x, y)]
                     && (animation.animation_type != ParticleAnimationType::Ray || subframe_idx > 0)
                 {
                     // Take the texture and draw only the wanted tile ( DrawTextureParams.source )
+                    let (screen_x, screen_y) = camera.to_screen(*x, *y);
                     draw_texture_ex(
                         texture_to_render,
-                        (UI_BORDER + (x * TILE_SIZE)) as f32,
-                        (UI_BORDER + (y * TILE_SIZE)) as f32,
+                        screen_x,
+                        screen_y,
                         WHITE,
                         DrawTextureParams {
                             source: Some(Rect {
@@ -720,7 +791,7 @@ impl Draw {
     }
 
     #[cfg(not(target_arch = "wasm32"))]
-    fn debug_exit(zone: &Zone) {
+    fn debug_exit(zone: &Zone, camera: &Camera) {
         let start_index = zone
             .tiles
             .iter()
@@ -729,20 +800,18 @@ impl Draw {
 
         // Draw a red rectangle on the DownPassage tile index, if present
         if let Some(start_index) = start_index {
-            let (rounded_x, rounded_y) = Zone::get_xy_from_index(start_index);
+            let (rounded_x, rounded_y) = zone.get_xy_from_index(start_index);
+            if !camera.is_visible(rounded_x, rounded_y) {
+                return;
+            }
 
-            draw_rectangle(
-                (UI_BORDER + (rounded_x * TILE_SIZE)) as f32,
-                (UI_BORDER + (rounded_y * TILE_SIZE)) as f32,
-                TILE_SIZE_F32,
-                TILE_SIZE_F32,
-                RED,
-            );
+            let (screen_x, screen_y) = camera.to_screen(rounded_x, rounded_y);
+            draw_rectangle(screen_x, screen_y, TILE_SIZE_F32, TILE_SIZE_F32, RED);
         }
     }
 
     #[cfg(not(target_arch = "wasm32"))]
-    fn debug_tiles_type(zone: &Zone) {
+    fn debug_tiles_type(zone: &Zone, camera: &Camera) {
         for (index, &tile) in zone.tiles.iter().enumerate() {
             use macroquad::color::BLUE;
             let color = match tile {
@@ -755,33 +824,38 @@ impl Draw {
                 _ => BLACK,
             };
 
-            let (rounded_x, rounded_y) = Zone::get_xy_from_index(index);
+            let (rounded_x, rounded_y) = zone.get_xy_from_index(index);
+            if !camera.is_visible(rounded_x, rounded_y) {
+                continue;
+            }
 
-            draw_rectangle_lines(
-                (UI_BORDER + (rounded_x * TILE_SIZE)) as f32,
-                (UI_BORDER + (rounded_y * TILE_SIZE)) as f32,
-                TILE_SIZE_F32,
-                TILE_SIZE_F32,
-                2.0,
-                color,
-            );
+            let (screen_x, screen_y) = camera.to_screen(rounded_x, rounded_y);
+            draw_rectangle_lines(screen_x, screen_y, TILE_SIZE_F32, TILE_SIZE_F32, 2.0, color);
         }
     }
 
     #[cfg(not(target_arch = "wasm32"))]
-    fn debug_monster_viewshed(game_state: &mut GameState) {
+    fn debug_monster_viewshed(game_state: &mut GameState, camera: &Camera) {
         //Deconstruct data into tuple
 
         use crate::components::{common::Viewshed, monster::Monster};
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
         let mut viewsheds = game_state.ecs_world.query::<&Viewshed>().with::<&Monster>();
         //For each Entity with Components Viewshed and Position
         for (_, viewshed) in &mut viewsheds {
             for &index in viewshed.visible_tiles.iter() {
-                let (rounded_x, rounded_y) = Zone::get_xy_from_index(index);
+                let (rounded_x, rounded_y) = zone.get_xy_from_index(index);
+                if !camera.is_visible(rounded_x, rounded_y) {
+                    continue;
+                }
 
+                let (screen_x, screen_y) = camera.to_screen(rounded_x, rounded_y);
                 draw_rectangle_lines(
-                    (UI_BORDER + (rounded_x * TILE_SIZE)) as f32,
-                    (UI_BORDER + (rounded_y * TILE_SIZE)) as f32,
+                    screen_x,
+                    screen_y,
                     TILE_SIZE_F32,
                     TILE_SIZE_F32,
                     2.0,
@@ -0,0 +1,65 @@
+use crate::{
+    components::common::Position,
+    constants::{
+        TILE_SIZE, TILE_SIZE_F32, UI_BORDER, UI_BORDER_F32, VIEWPORT_HEIGHT, VIEWPORT_WIDTH,
+    },
+    engine::state::GameState,
+    maps::zone::Zone,
+};
+
+/// Top left tile of the zone shown on screen. Zones can be bigger than the viewport,
+/// so everything drawn on the map must be shifted by the camera
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Camera {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Camera {
+    /// Center the view on a tile, without going past the borders of the zone
+    pub fn follow(zone: &Zone, target_x: i32, target_y: i32) -> Camera {
+        Camera {
+            x: (target_x - VIEWPORT_WIDTH / 2).clamp(0, (zone.width - VIEWPORT_WIDTH).max(0)),
+            y: (target_y - VIEWPORT_HEIGHT / 2).clamp(0, (zone.height - VIEWPORT_HEIGHT).max(0)),
+        }
+    }
+
+    /// Camera following the player in the current zone
+    pub fn from_game_state(game_state: &GameState) -> Camera {
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+
+        // Without a player (e.g. just dead) look at where the zone starts
+        let (target_x, target_y) = game_state
+            .current_player_entity
+            .and_then(|player| game_state.ecs_world.get::<&Position>(player).ok())
+            .map(|position| (position.x, position.y))
+            .unwrap_or_else(|| zone.get_xy_from_index(zone.player_spawn_point));
+
+        Camera::follow(zone, target_x, target_y)
+    }
+
+    /// Screen coordinates of the top left corner of a zone tile
+    pub fn to_screen(&self, x: i32, y: i32) -> (f32, f32) {
+        (
+            (UI_BORDER + ((x - self.x) * TILE_SIZE)) as f32,
+            (UI_BORDER + ((y - self.y) * TILE_SIZE)) as f32,
+        )
+    }
+
+    /// Zone tile under a screen position, like the one of the mouse pointer
+    pub fn to_zone(&self, screen_x: f32, screen_y: f32) -> (i32, i32) {
+        (
+            (((screen_x - UI_BORDER_F32) / TILE_SIZE_F32).ceil() - 1.0) as i32 + self.x,
+            (((screen_y - UI_BORDER_F32) / TILE_SIZE_F32).ceil() - 1.0) as i32 + self.y,
+        )
+    }
+
+    /// Return true if a zone tile is inside the viewport
+    pub fn is_visible(&self, x: i32, y: i32) -> bool {
+        (self.x..self.x + VIEWPORT_WIDTH).contains(&x)
+            && (self.y..self.y + VIEWPORT_HEIGHT).contains(&y)
+    }
+}
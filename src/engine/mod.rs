@@ -1,2 +1,3 @@
+pub mod camera;
+pub mod gameengine;
 pub mod state;
-pub mod gameengine;
\ No newline at end of file
@@ -2,8 +2,9 @@ use hecs::{Entity, World};
 
 use crate::{
     components::{
-        common::{CanListen, GameLog},
+        common::{CanListen, GameLog, SpellList},
         items::InBackback,
+        monster::{Hireling, HirelingCommand},
         player::SpecialViewMode,
     },
     inventory::InventoryAction,
@@ -19,10 +20,13 @@ pub enum RunState {
     DoTick,
     GameOver,
     ShowInventory(InventoryAction),
+    ShowJournal,
+    ShowOverview,
     ShowDialog(DialogAction),
     MouseTargeting(SpecialViewMode),
     DrawParticles,
     GoToNextZone,
+    FallToNextZone,
 }
 
 // Game state struct
@@ -39,8 +43,9 @@ pub struct GameState {
 
 // State implementations
 impl GameState {
-    /// Retain the player, gamelog and backpack items when changing Zone
-    pub fn get_entities_to_delete_on_zone_change(&mut self) -> Vec<Entity> {
+    /// Retain the player, gamelog and backpack items when changing Zone.
+    /// If requested, hirelings following the player are retained along with their items
+    pub fn get_entities_to_delete_on_zone_change(&mut self, keep_followers: bool) -> Vec<Entity> {
         let mut entities_to_delete: Vec<Entity> = Vec::new();
         let player_id = self
             .current_player_entity
@@ -49,6 +54,24 @@ impl GameState {
 
         let mut must_delete;
 
+        // Spells learned by the player are kept too
+        let player_spells: Vec<u32> = self
+            .ecs_world
+            .get::<&SpellList>(self.current_player_entity.expect("Player id should be set"))
+            .map(|spell_list| spell_list.spells.iter().map(|spell| spell.id()).collect())
+            .unwrap_or_default();
+
+        let is_follower = |entity: Entity| {
+            keep_followers
+                && self
+                    .ecs_world
+                    .get::<&Hireling>(entity)
+                    .is_ok_and(|hireling| {
+                        hireling.owner.id() == player_id
+                            && hireling.command != HirelingCommand::Stay
+                    })
+        };
+
         for entity_ref in self.ecs_world.iter() {
             let entity = entity_ref.entity();
 
@@ -57,7 +80,7 @@ impl GameState {
             // Do not despawn objects in player's backpack
             // All the others must be deleted or else could be casually reassigned to NPCs
             if let Ok(in_backpack) = self.ecs_world.get::<&InBackback>(entity) {
-                if in_backpack.owner.id() == player_id {
+                if in_backpack.owner.id() == player_id || is_follower(in_backpack.owner) {
                     must_delete = false;
                 }
             } else if entity.id() == player_id {
@@ -66,6 +89,8 @@ impl GameState {
                 if let Ok(mut can_listen) = self.ecs_world.get::<&mut CanListen>(entity) {
                     can_listen.listen_cache.clear();
                 }
+            } else if is_follower(entity) || player_spells.contains(&entity.id()) {
+                must_delete = false;
             }
             if must_delete {
                 entities_to_delete.push(entity);
@@ -14,7 +14,7 @@ use crate::{
     components::{
         actions::{
             WantsToApply, WantsToDrink, WantsToDrop, WantsToEat, WantsToEquip, WantsToFuel,
-            WantsToInvoke, WantsToTrade,
+            WantsToInvoke, WantsToThrow, WantsToTrade,
         },
         common::{Named, Wet},
         items::{
@@ -37,6 +37,7 @@ pub enum InventoryAction {
     Equip,
     Apply,
     Trade,
+    Throw,
 }
 
 /// Inventory Item Data trasfer type: used for rendering and general inventory usage
@@ -101,6 +102,9 @@ impl Inventory {
                         InventoryAction::Apply => {
                             Inventory::get_all_in_backpack_filtered_by::<Appliable>(game_state)
                         }
+                        InventoryAction::Throw => {
+                            Inventory::get_all_in_backpack_filtered_by::<Refiller>(game_state)
+                        }
                         InventoryAction::Trade | InventoryAction::Drop => {
                             Inventory::get_all_in_backpack(game_state)
                         }
@@ -203,6 +207,12 @@ impl Inventory {
                             println!("Player wants to apply {:?}", item.id());
                         }
                     }
+                    InventoryAction::Throw => {
+                        let _ = game_state.ecs_world.insert_one(user, WantsToThrow { item });
+
+                        game_state.run_state =
+                            RunState::MouseTargeting(SpecialViewMode::ThrowTargeting);
+                    }
                 };
 
                 //Avoid strange behaviors
@@ -254,6 +264,10 @@ impl Inventory {
                 header_text = "Apply what?";
                 inventory = Inventory::get_all_in_backpack_filtered_by::<Appliable>(game_state);
             }
+            InventoryAction::Throw => {
+                header_text = "Throw what?";
+                inventory = Inventory::get_all_in_backpack_filtered_by::<Refiller>(game_state);
+            }
         }
 
         // ------- Background Rectangle -----------
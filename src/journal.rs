@@ -0,0 +1,111 @@
+use macroquad::{
+    color::{BLACK, DARKGRAY, GREEN, WHITE},
+    input::{KeyCode, clear_input_queue, is_key_pressed},
+    shapes::draw_rectangle,
+    text::draw_text,
+};
+
+use crate::{
+    components::quest::{QuestJournal, QuestState},
+    constants::*,
+    engine::state::{GameState, RunState},
+};
+
+/// Screen listing the quests given to the player
+pub struct Journal {}
+
+impl Journal {
+    pub fn handle_input(game_state: &mut GameState) {
+        if is_key_pressed(KeyCode::Escape) {
+            // Exit journal, clear queue to avoid to reopen on cancel
+            // caused by char input queue
+            clear_input_queue();
+
+            game_state.run_state = RunState::WaitingPlayerInput;
+        }
+    }
+
+    pub fn draw(game_state: &GameState) {
+        let header_text = "Quest journal";
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        // ------- Background Rectangle -----------
+        draw_rectangle(
+            INVENTORY_X as f32,
+            INVENTORY_Y as f32,
+            INVENTORY_WIDTH as f32,
+            INVENTORY_HEIGHT as f32,
+            WHITE,
+        );
+        draw_rectangle(
+            (INVENTORY_X + HUD_BORDER) as f32,
+            (INVENTORY_Y + HUD_BORDER) as f32,
+            (INVENTORY_WIDTH - UI_BORDER) as f32,
+            (INVENTORY_HEIGHT - UI_BORDER) as f32,
+            BLACK,
+        );
+
+        // ------- Header -----------
+        draw_rectangle(
+            (INVENTORY_X + INVENTORY_LEFT_SPAN) as f32,
+            (INVENTORY_Y - UI_BORDER) as f32,
+            header_text.len() as f32 * LETTER_SIZE,
+            HEADER_HEIGHT as f32,
+            BLACK,
+        );
+
+        draw_text(
+            header_text,
+            (INVENTORY_X + INVENTORY_LEFT_SPAN + HUD_BORDER) as f32,
+            (INVENTORY_Y + UI_BORDER) as f32,
+            FONT_SIZE,
+            WHITE,
+        );
+
+        // ------- Quest List -----------
+        // Active quests are white, completed ones green and failed ones gray
+        let x = (INVENTORY_X + UI_BORDER * 2) as f32;
+        match game_state.ecs_world.get::<&QuestJournal>(player) {
+            Ok(journal) if !journal.quests.is_empty() => {
+                for (index, quest) in journal.quests.iter().enumerate() {
+                    let y = (INVENTORY_Y + INVENTORY_TOP_SPAN) as f32
+                        + ((FONT_SIZE + LETTER_SIZE) * index as f32);
+                    let color = match quest.state {
+                        QuestState::Active => WHITE,
+                        QuestState::Completed => GREEN,
+                        QuestState::Failed => DARKGRAY,
+                    };
+
+                    draw_text(&quest.description, x, y, FONT_SIZE, color);
+                }
+            }
+            _ => {
+                draw_text(
+                    "No quests yet",
+                    x,
+                    (INVENTORY_Y + INVENTORY_TOP_SPAN) as f32,
+                    FONT_SIZE,
+                    WHITE,
+                );
+            }
+        }
+
+        // ------- Footer -----------
+        draw_rectangle(
+            (INVENTORY_X + INVENTORY_LEFT_SPAN) as f32,
+            (INVENTORY_Y + INVENTORY_HEIGHT - UI_BORDER) as f32,
+            INVENTORY_FOOTER_WIDTH as f32,
+            HEADER_HEIGHT as f32,
+            BLACK,
+        );
+        draw_text(
+            "ESC to close",
+            (INVENTORY_X + INVENTORY_LEFT_SPAN + HUD_BORDER) as f32,
+            (INVENTORY_Y + INVENTORY_HEIGHT + HUD_BORDER) as f32,
+            FONT_SIZE,
+            WHITE,
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Game logic shared by the game and the map generator preview
+
+pub mod components;
+pub mod constants;
+pub mod engine;
+pub mod inventory;
+pub mod maps;
+pub mod spawning;
+pub mod systems;
+pub mod utils;
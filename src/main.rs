@@ -1,18 +1,28 @@
 use std::borrow::Cow;
 
+use worldsviscera::{components, constants, engine, inventory, maps, spawning, systems, utils};
+
 use crate::{
-    components::{combat::Grappled, common::Experience},
-    maps::{arena_zone_builder::ArenaZoneBuilder, crystal_cave_builder::CrystalCaveBuilder},
+    components::{
+        combat::{CombatStats, Grappled, SufferingDamage},
+        common::Experience,
+    },
+    maps::{
+        arena_zone_builder::ArenaZoneBuilder, connectivity_checker::ConnectivityChecker,
+        dungeon_graph::DungeonGraph,
+    },
     systems::{
-        advancement_system::AdvancementSystem, dig_manager::DigManager,
-        gaze_attacks_manager::GazeAttacksManager, health_manager::HealthManager,
+        advancement_system::AdvancementSystem, dig_manager::DigManager, door_manager::DoorManager,
+        fall_manager::FallManager, gaze_attacks_manager::GazeAttacksManager,
+        health_manager::HealthManager, heat_manager::HeatManager,
         leave_trail_system::LeaveTrailSystem, ranged_manager::RangedManager,
         special_tiles_system::SpecialTilesSystem, spell_manager::SpellManager,
-        trade_system::TradeSystem,
+        swim_manager::SwimManager, trade_system::TradeSystem, trap_manager::TrapManager,
     },
     utils::{
         choice_dialog::ChoiceDialog,
         dialog::{Dialog, DialogAction},
+        roll::Roll,
         simple_dialog::SimpleDialog,
     },
 };
@@ -25,38 +35,40 @@ use engine::{
 };
 use hecs::World;
 use inventory::Inventory;
+use journal::Journal;
 use macroquad::prelude::*;
+use overview::Overview;
 use spawning::spawner::Spawn;
 use systems::{
-    damage_manager::DamageManager, eating_edibles::EatingEdibles, fov_manager::FieldOfViewManager,
+    awareness_manager::AwarenessManager, damage_manager::DamageManager,
+    eating_edibles::EatingEdibles, fire_manager::FireManager, fov_manager::FieldOfViewManager,
     item_collection::ItemCollection, item_dropping::ItemDropping, melee_manager::MeleeManager,
-    monster_think::MonsterThink,
+    monster_think::MonsterThink, morale_manager::MoraleManager, noise_manager::NoiseManager,
 };
 
 use crate::{
     components::common::{Position, Viewshed},
-    maps::{ZoneBuilder, main_zone_builder::MainZoneBuilder, zone::Zone},
+    maps::{
+        ZoneBuilder,
+        zone::{TileType, Zone},
+    },
     systems::{
         apply_system::ApplySystem, automatic_healing::AutomaticHealing,
         decay_manager::DecayManager, drinking_quaffables::DrinkingQuaffables,
-        fuel_manager::FuelManager, hidden_manager::HiddenManager, hunger_check::HungerCheck,
-        invoke_manager::InvokeManager, item_equipping::ItemEquipping, map_indexing::MapIndexing,
-        monster_approach::MonsterApproach, particle_manager::ParticleManager,
+        faction_manager::FactionManager, fuel_manager::FuelManager, gas_manager::GasManager,
+        hidden_manager::HiddenManager, hireling_manager::HirelingManager,
+        hunger_check::HungerCheck, invoke_manager::InvokeManager, item_equipping::ItemEquipping,
+        map_indexing::MapIndexing, monster_approach::MonsterApproach,
+        particle_manager::ParticleManager, quest_manager::QuestManager,
         smell_manager::SmellManager, sound_system::SoundSystem, thirst_check::ThirstCheck,
         turn_checker::TurnCheck, wet_manager::WetManager,
     },
     utils::assets::Load,
 };
 
-mod components;
-mod constants;
 mod draw;
-mod engine;
-mod inventory;
-mod maps;
-mod spawning;
-mod systems;
-mod utils;
+mod journal;
+mod overview;
 
 //Game configuration
 fn get_game_configuration() -> Conf {
@@ -147,7 +159,10 @@ async fn main() {
                     do_in_tick_game_logic(&mut game_engine, &mut game_state);
 
                     match game_state.run_state {
-                        RunState::GameOver | RunState::ShowDialog(_) | RunState::DrawParticles => {}
+                        RunState::GameOver
+                        | RunState::ShowDialog(_)
+                        | RunState::DrawParticles
+                        | RunState::FallToNextZone => {}
                         _ => {
                             if Player::can_act(
                                 &game_state.ecs_world,
@@ -179,6 +194,12 @@ async fn main() {
                 RunState::ShowInventory(mode) => {
                     Inventory::handle_input(&mut game_state, mode);
                 }
+                RunState::ShowJournal => {
+                    Journal::handle_input(&mut game_state);
+                }
+                RunState::ShowOverview => {
+                    Overview::handle_input(&mut game_state);
+                }
                 // Handle both types of dialog
                 RunState::ShowDialog(mode) => {
                     match mode {
@@ -193,7 +214,12 @@ async fn main() {
                 }
                 RunState::GoToNextZone => {
                     Player::wait_after_action(&mut game_state, STANDARD_ACTION_MULTIPLIER);
-                    change_zone(&mut game_state);
+                    change_zone(&mut game_state, false);
+                    clear_input_queue();
+                    game_state.run_state = RunState::BeforeTick;
+                }
+                RunState::FallToNextZone => {
+                    change_zone(&mut game_state, true);
                     clear_input_queue();
                     game_state.run_state = RunState::BeforeTick;
                 }
@@ -213,7 +239,8 @@ fn populate_world(game_state: &mut GameState) {
     // Generate new seed, or else it will always generate the same things
     rand::srand(macroquad::miniquad::date::now() as _);
 
-    let zone = ArenaZoneBuilder::build(1, &mut game_state.ecs_world);
+    let zone =
+        ConnectivityChecker::build_connected(ArenaZoneBuilder::build, 1, &mut game_state.ecs_world);
 
     game_state.current_player_entity = Some(Spawn::player(&mut game_state.ecs_world, &zone));
     Spawn::everyhing_in_map(&mut game_state.ecs_world, &zone);
@@ -222,22 +249,39 @@ fn populate_world(game_state: &mut GameState) {
     game_state.current_zone = Some(zone);
 }
 
-fn change_zone(game_state: &mut GameState) {
+fn change_zone(game_state: &mut GameState, is_falling: bool) {
     // Generate new seed, or else it will always generate the same things
     rand::srand(macroquad::miniquad::date::now() as _);
 
-    let current_depth = game_state
-        .current_zone
-        .as_ref()
-        .expect("must have Some Zone")
-        .depth;
-
-    let entities_to_delete = game_state.get_entities_to_delete_on_zone_change();
+    Overview::record_zone(game_state);
 
     let player = game_state
         .current_player_entity
         .expect("Player id should be set");
 
+    // The dungeon graph tells where the passage leads. Falling always ends on the level below
+    let (current_branch, next_branch, next_depth) = {
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+        let took_side_passage = !is_falling
+            && game_state
+                .ecs_world
+                .get::<&Position>(player)
+                .is_ok_and(|position| {
+                    zone.tiles[zone.get_index_from_xy(&position.x, &position.y)]
+                        == TileType::BranchPassage
+                });
+        let (next_branch, next_depth) =
+            DungeonGraph::next_zone(zone.branch, zone.depth, took_side_passage);
+        (zone.branch, next_branch, next_depth)
+    };
+
+    // Hirelings can't follow the player down a chasm
+    let entities_to_delete = game_state.get_entities_to_delete_on_zone_change(!is_falling);
+    QuestManager::fail_lost_quests(game_state, &entities_to_delete);
+
     // Remove any existing Grappled component from the player
     let _ = game_state.ecs_world.remove_one::<&Grappled>(player);
 
@@ -245,14 +289,14 @@ fn change_zone(game_state: &mut GameState) {
         let _ = game_state.ecs_world.despawn(e);
     }
 
-    // Build new zone based on depth.
-    // -1 because current depth is then incremented to get the next Zone
-    let zone = match current_depth + 1 {
-        CRYSTAL_CAVE_DEPTH => {
-            CrystalCaveBuilder::build(current_depth + 1, &mut game_state.ecs_world)
-        }
-        _ => MainZoneBuilder::build(current_depth + 1, &mut game_state.ecs_world),
-    };
+    // Build new zone based on branch and depth
+    let mut zone = DungeonGraph::build(next_branch, next_depth, &mut game_state.ecs_world);
+    if next_branch != current_branch {
+        game_state.game_log.add_entry(Cow::Owned(format!(
+            "You enter {}",
+            DungeonGraph::get_name(next_branch)
+        )));
+    }
 
     // Scope for keeping borrow checker quiet
     {
@@ -266,10 +310,33 @@ fn change_zone(game_state: &mut GameState) {
             .get()
             .expect("Must have Position, Viewshed,Experience components");
 
-        let (x, y) = Zone::get_xy_from_index(zone.player_spawn_point);
+        // Falling down a chasm lands the player anywhere in the new zone
+        if is_falling {
+            zone.populate_blocked();
+            zone.populate_water();
+            let mut landing_index = zone.player_spawn_point;
+            for _ in 0..MAX_SPAWN_TENTATIVES {
+                let try_x = Roll::dice(1, zone.width - 2);
+                let try_y = Roll::dice(1, zone.height - 2);
+                let index = zone.get_index_from_xy(&try_x, &try_y);
+                if !zone.blocked_tiles[index]
+                    && !zone.water_tiles[index]
+                    && !zone.monster_spawn_points.contains(&index)
+                    && !zone.fauna_spawn_points.contains(&index)
+                {
+                    landing_index = index;
+                    break;
+                }
+            }
+            zone.player_spawn_point = landing_index;
+        }
+
+        let (x, y) = zone.get_xy_from_index(zone.player_spawn_point);
         player_position.x = x;
         player_position.y = y;
 
+        // Indexes of the old zone mean nothing in the new one, zones can have different sizes
+        player_viewshed.visible_tiles.clear();
         player_viewshed.must_recalculate = true;
 
         // Award experience based on depth reached
@@ -277,7 +344,45 @@ fn change_zone(game_state: &mut GameState) {
         player_experience.auto_advance_counter = AUTO_ADVANCE_EXP_COUNTER_START;
     }
 
+    if is_falling {
+        let mut player_query_damage = game_state
+            .ecs_world
+            .query_one::<(&CombatStats, &mut SufferingDamage)>(player)
+            .expect("Player not in hecs::World");
+
+        let (stats, suffering_damage) = player_query_damage
+            .get()
+            .expect("Must have CombatStats, SufferingDamage components");
+
+        // Dexterity save to land without breaking too many bones
+        let mut damage = Roll::dice(2, FALL_DAMAGE_DICE);
+        if Roll::d20() <= stats.current_dexterity {
+            damage /= 2;
+            game_state.game_log.add_entry(Cow::Owned(format!(
+                "You roll as you land, taking {} damage",
+                damage
+            )));
+        } else {
+            game_state.game_log.add_entry(Cow::Owned(format!(
+                "You land hard, taking {} damage!",
+                damage
+            )));
+        }
+        suffering_damage.damage_received += damage;
+    }
+
+    // Place the hirelings that followed the player
+    zone.populate_blocked();
+    zone.populate_water();
+    HirelingManager::place_followers(
+        &mut game_state.ecs_world,
+        &zone,
+        player,
+        &mut game_state.game_log,
+    );
+
     Spawn::everyhing_in_map(&mut game_state.ecs_world, &zone);
+    FactionManager::apply_to_zone(&game_state.ecs_world, player);
 
     // Add zone (previous shuold be removed)
     game_state.current_zone = Some(zone);
@@ -293,7 +398,14 @@ fn do_before_tick_logic(game_state: &mut GameState) {
     HealthManager::run(game_state);
     FuelManager::check_fuel(game_state);
     WetManager::run(game_state);
+    SwimManager::run(game_state);
+    FireManager::run(game_state);
+    GasManager::run(game_state);
     HiddenManager::run(game_state);
+    AwarenessManager::run(game_state);
+    MoraleManager::run(game_state);
+    HirelingManager::run(game_state);
+    QuestManager::run(game_state);
     MonsterThink::run(game_state);
     LeaveTrailSystem::handle_spawned_trail(game_state);
     AdvancementSystem::run(game_state);
@@ -311,6 +423,7 @@ fn do_in_tick_game_logic(game_engine: &mut GameEngine, game_state: &mut GameStat
     SpellManager::run(game_state);
     RangedManager::run(game_state);
     FuelManager::do_refills(game_state);
+    FuelManager::do_throws(game_state);
     InvokeManager::run(game_state);
     //If there are particles, skip everything and draw
     if !ParticleManager::check_if_animations_are_present(game_engine, game_state) {
@@ -330,10 +443,19 @@ fn do_in_tick_game_logic(game_engine: &mut GameEngine, game_state: &mut GameStat
             SoundSystem::run(game_state);
             LeaveTrailSystem::run(game_state);
             MonsterApproach::run(game_state);
+            // DoorManager must run after MonsterApproach because monsters open doors on their way
+            DoorManager::run(game_state);
+            TrapManager::do_disarms(game_state);
             TradeSystem::run(game_state);
+            // NoiseManager must run after every System that could make noise
+            NoiseManager::run(game_state);
             // These Systems must always be run last
             MapIndexing::run(game_state);
             SpecialTilesSystem::grow_on_step_tiles(game_state);
+            HeatManager::run(game_state);
+            TrapManager::run(game_state);
+            TrapManager::spot_traps(game_state);
+            FallManager::run(game_state);
             FieldOfViewManager::calculate(game_state);
             TurnCheck::check_for_turn_reset(game_state);
         }
@@ -362,7 +484,7 @@ fn do_debug_logic(game_state: &mut GameState) {
             } else if is_key_pressed(KeyCode::F10) {
                 Spawn::wand(&mut game_state.ecs_world, MAP_WIDTH / 2, MAP_HEIGHT / 2);
             } else if is_key_pressed(KeyCode::F9) {
-                Spawn::refugee(&mut game_state.ecs_world, MAP_WIDTH / 2, MAP_HEIGHT / 2);
+                let _ = Spawn::refugee(&mut game_state.ecs_world, MAP_WIDTH / 2, MAP_HEIGHT / 2);
             } else if is_key_pressed(KeyCode::F8) {
                 use crate::components::combat::CombatStats;
 
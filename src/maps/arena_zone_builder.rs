@@ -16,28 +16,28 @@ pub struct ArenaZoneBuilder {}
 impl ZoneBuilder for ArenaZoneBuilder {
     /// Create new dungeon zone (needed?)
     fn build(depth: u32, ecs_world: &mut World) -> Zone {
-        let mut zone = Zone::new(depth, TileType::Wall);
+        let mut zone = Zone::new(depth, TileType::Wall, VIEWPORT_WIDTH, VIEWPORT_HEIGHT);
 
         // Create boundaries
-        for x in 0..MAP_WIDTH {
-            for y in 0..MAP_HEIGHT {
-                if x != 0 && y != 0 && x != MAP_WIDTH - 1 && y != MAP_HEIGHT - 1 {
-                    let index = Zone::get_index_from_xy(&x, &y);
+        for x in 0..VIEWPORT_WIDTH {
+            for y in 0..VIEWPORT_HEIGHT {
+                if x != 0 && y != 0 && x != VIEWPORT_WIDTH - 1 && y != VIEWPORT_HEIGHT - 1 {
+                    let index = zone.get_index_from_xy(&x, &y);
                     zone.tiles[index] = TileType::Floor
                 }
             }
         }
 
-        zone.player_spawn_point = Zone::get_index_from_xy(&(MAP_WIDTH / 2), &(MAP_HEIGHT / 2));
+        zone.player_spawn_point =
+            zone.get_index_from_xy(&(VIEWPORT_WIDTH / 2), &(VIEWPORT_HEIGHT / 2));
 
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.25, MAP_HEIGHT_F32 * 0.25)] =
-            TileType::Brazier;
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.75, MAP_HEIGHT_F32 * 0.25)] =
-            TileType::Brazier;
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.25, MAP_HEIGHT_F32 * 0.75)] =
-            TileType::Brazier;
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.75, MAP_HEIGHT_F32 * 0.75)] =
-            TileType::Brazier;
+        for (brazier_x, brazier_y) in [(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)] {
+            let brazier_index = zone.get_index_from_xy_f32(
+                VIEWPORT_WIDTH_F32 * brazier_x,
+                VIEWPORT_HEIGHT_F32 * brazier_y,
+            );
+            zone.tiles[brazier_index] = TileType::Brazier;
+        }
 
         // Populate blocked tiles here, needed for correct spawning
         zone.populate_blocked();
@@ -47,12 +47,12 @@ impl ZoneBuilder for ArenaZoneBuilder {
 
         for _ in 0..items_number {
             for _ in 0..MAX_SPAWN_TENTATIVES {
-                let x = Roll::dice(1, MAP_WIDTH - 3) as f32 + 1.0;
-                let y = Roll::dice(1, MAP_HEIGHT - 3) as f32 + 1.0;
-                let index = Zone::get_index_from_xy_f32(x, y);
+                let x = Roll::dice(1, VIEWPORT_WIDTH - 3) as f32 + 1.0;
+                let y = Roll::dice(1, VIEWPORT_HEIGHT - 3) as f32 + 1.0;
+                let index = zone.get_index_from_xy_f32(x, y);
 
                 // avoid duplicate spawnpoints
-                if zone.tiles[Zone::get_index_from_xy_f32(x, y)] != TileType::Floor {
+                if zone.tiles[zone.get_index_from_xy_f32(x, y)] != TileType::Floor {
                     continue;
                 } else if zone.item_spawn_points.insert(index) {
                     break;
@@ -61,18 +61,20 @@ impl ZoneBuilder for ArenaZoneBuilder {
         }
 
         // Random starting point for DownPassage
-        let passage_index = Zone::get_index_from_xy(&(MAP_WIDTH / 2), &(MAP_HEIGHT / 2));
+        let passage_index = zone.get_index_from_xy(&(VIEWPORT_WIDTH / 2), &(VIEWPORT_HEIGHT / 2));
         zone.tiles[passage_index] = TileType::DownPassage;
 
-        let disembodied_index = Zone::get_index_from_xy(
-            &((MAP_WIDTH / 2) - 5),
-            &((MAP_HEIGHT / 2) + (Roll::d6() - 3)),
+        let disembodied_index = zone.get_index_from_xy(
+            &((VIEWPORT_WIDTH / 2) - 5),
+            &((VIEWPORT_HEIGHT / 2) + (Roll::d6() - 3)),
         );
         zone.tiles[disembodied_index] = TileType::DisembodiedEntity;
 
         // Guaranteed diggable to be picked up
-        let (guaranteed_diggable_x, guaranteed_diggable_y) =
-            ((MAP_WIDTH / 2) + 3, (MAP_HEIGHT / 2) + (Roll::d6() - 3));
+        let (guaranteed_diggable_x, guaranteed_diggable_y) = (
+            (VIEWPORT_WIDTH / 2) + 3,
+            (VIEWPORT_HEIGHT / 2) + (Roll::d6() - 3),
+        );
         if Roll::d6() < 4 {
             Spawn::rockpick(ecs_world, guaranteed_diggable_x, guaranteed_diggable_y);
         } else {
@@ -0,0 +1,88 @@
+use std::cmp::min;
+
+use hecs::World;
+
+use crate::{
+    constants::*,
+    maps::{
+        ZoneBuilder,
+        main_zone_builder::MainZoneBuilder,
+        zone::{TileType, Zone},
+    },
+    utils::roll::Roll,
+};
+
+/// Builds a wide open cavern with pillars using cellular automata
+pub struct CavernZoneBuilder {}
+
+impl ZoneBuilder for CavernZoneBuilder {
+    fn build(depth: u32, ecs_world: &mut World) -> Zone {
+        let mut zone = Zone::new(depth, TileType::Wall, CAVERN_WIDTH, CAVERN_HEIGHT);
+
+        CavernZoneBuilder::carve(&mut zone);
+
+        MainZoneBuilder::add_features(&mut zone, ecs_world);
+
+        zone
+    }
+}
+
+impl CavernZoneBuilder {
+    /// Carve caverns and pillars in a zone filled with rock
+    pub fn carve(zone: &mut Zone) {
+        // Random noise, the more deep we are the more rock we have
+        let fill_percent = min(
+            CAVERN_MAX_FILL_PERCENT,
+            CAVERN_FILL_PERCENT + zone.depth as i32 / 2,
+        );
+        for x in 1..zone.width - 1 {
+            for y in 1..zone.height - 1 {
+                if Roll::dice(1, 100) > fill_percent {
+                    let tile_index = zone.get_index_from_xy(&x, &y);
+                    zone.tiles[tile_index] = TileType::Floor;
+                }
+            }
+        }
+
+        // Smooth the noise into caverns. The first iterations also raise pillars in the open spaces
+        for iteration in 0..CAVERN_SMOOTHING_ITERATIONS {
+            let mut new_tiles = zone.tiles.clone();
+            for x in 1..zone.width - 1 {
+                for y in 1..zone.height - 1 {
+                    let near_walls = CavernZoneBuilder::count_walls(zone, x, y, 1);
+                    let far_walls = CavernZoneBuilder::count_walls(zone, x, y, 2);
+
+                    new_tiles[zone.get_index_from_xy(&x, &y)] = if near_walls
+                        >= CAVERN_WALL_THRESHOLD
+                        || (iteration < CAVERN_PILLAR_ITERATIONS
+                            && far_walls <= CAVERN_PILLAR_THRESHOLD)
+                    {
+                        TileType::Wall
+                    } else {
+                        TileType::Floor
+                    };
+                }
+            }
+            zone.tiles = new_tiles;
+        }
+    }
+
+    /// Count the walls in the square of given radius around a tile, the tile included.
+    /// Anything outside the zone counts as wall
+    fn count_walls(zone: &Zone, x: i32, y: i32, radius: i32) -> usize {
+        let mut walls = 0;
+        for near_x in x - radius..=x + radius {
+            for near_y in y - radius..=y + radius {
+                if near_x < 0
+                    || near_y < 0
+                    || near_x >= zone.width
+                    || near_y >= zone.height
+                    || zone.tiles[zone.get_index_from_xy(&near_x, &near_y)] == TileType::Wall
+                {
+                    walls += 1;
+                }
+            }
+        }
+        walls
+    }
+}
@@ -0,0 +1,83 @@
+use hecs::World;
+
+use crate::{
+    constants::MAX_PITS_IN_CHASM,
+    maps::{
+        ZoneFeatureBuilder,
+        cracks_builder::CracksBuilder,
+        zone::{TileType, Zone},
+    },
+    utils::roll::Roll,
+};
+
+///Chasms Builder, where the floor along a crack collapsed into the depths
+pub struct ChasmBuilder {}
+
+impl ZoneFeatureBuilder for ChasmBuilder {
+    fn build(zone: &mut Zone, ecs_world: &mut World) -> Vec<usize> {
+        let mut chasm_tiles = Vec::new();
+        let crack_tiles = CracksBuilder::build(zone, ecs_world);
+
+        if crack_tiles.is_empty() {
+            return chasm_tiles;
+        }
+
+        // The floor collapses around a random point of the crack.
+        // The inner ring always falls down, the outer ring only sometimes
+        let collapse_roll = Roll::dice(1, crack_tiles.len() as i32) as usize - 1;
+        let (collapse_x, collapse_y) = zone.get_xy_from_index(crack_tiles[collapse_roll]);
+        for x in collapse_x - 2..=collapse_x + 2 {
+            for y in collapse_y - 2..=collapse_y + 2 {
+                let is_inner_ring = (x - collapse_x).abs() <= 1 && (y - collapse_y).abs() <= 1;
+                if (is_inner_ring || Roll::d6() <= 3) && ChasmBuilder::can_collapse(zone, x, y) {
+                    let index = zone.get_index_from_xy(&x, &y);
+                    zone.tiles[index] = TileType::Chasm;
+                    chasm_tiles.push(index);
+                }
+            }
+        }
+
+        // Some smaller pits open where the crack crosses the floor
+        for _ in 0..Roll::dice(1, MAX_PITS_IN_CHASM) {
+            let pit_roll = Roll::dice(1, crack_tiles.len() as i32) as usize - 1;
+            let index = crack_tiles[pit_roll];
+            let (x, y) = zone.get_xy_from_index(index);
+            if zone.tiles[index] == TileType::Floor && ChasmBuilder::can_collapse(zone, x, y) {
+                zone.tiles[index] = TileType::Pit;
+                chasm_tiles.push(index);
+            }
+        }
+
+        chasm_tiles
+    }
+}
+
+impl ChasmBuilder {
+    /// Only bare rock and floor can collapse, never the zone borders or where something should spawn.
+    /// The ground around the player, the items and the passages holds, so they are never cut off
+    fn can_collapse(zone: &Zone, x: i32, y: i32) -> bool {
+        if x <= 0 || x >= zone.width - 1 || y <= 0 || y >= zone.height - 1 {
+            return false;
+        }
+
+        let index = zone.get_index_from_xy(&x, &y);
+        let is_near_something_important = (x - 1..=x + 1).any(|near_x| {
+            (y - 1..=y + 1).any(|near_y| {
+                let near_index = zone.get_index_from_xy(&near_x, &near_y);
+                near_index == zone.player_spawn_point
+                    || zone.item_spawn_points.contains(&near_index)
+                    || matches!(
+                        zone.tiles[near_index],
+                        TileType::DownPassage | TileType::BranchPassage
+                    )
+            })
+        });
+
+        matches!(
+            zone.tiles[index],
+            TileType::Floor | TileType::Wall | TileType::CrackedWall
+        ) && !is_near_something_important
+            && !zone.monster_spawn_points.contains(&index)
+            && !zone.fauna_spawn_points.contains(&index)
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::{HashSet, VecDeque};
+
+use hecs::{Entity, World};
+use pathfinding::prelude::dijkstra;
+
+use crate::{
+    components::items::ShopOwner,
+    constants::{MAX_CONNECTIVITY_REPAIRS, MAX_ZONE_REBUILDS, TUNNEL_PATH_COST},
+    maps::zone::{TileType, Zone},
+};
+
+/// Post generation pass that guarantees the player can reach the exit,
+/// the item spawn points and the shops of a zone
+pub struct ConnectivityChecker {}
+
+impl ConnectivityChecker {
+    /// Build zones until everything important in one of them can be reached.
+    /// Whatever a discarded zone spawned in the world goes away with it
+    pub fn build_connected(
+        builder: fn(u32, &mut World) -> Zone,
+        depth: u32,
+        ecs_world: &mut World,
+    ) -> Zone {
+        for _ in 0..MAX_ZONE_REBUILDS {
+            let existing_entities: HashSet<Entity> = ecs_world
+                .iter()
+                .map(|entity_ref| entity_ref.entity())
+                .collect();
+
+            let mut zone = builder(depth, ecs_world);
+            if ConnectivityChecker::ensure_connected(&mut zone, ecs_world) {
+                return zone;
+            }
+
+            println!("ConnectivityChecker - Try again");
+            let spawned_entities: Vec<Entity> = ecs_world
+                .iter()
+                .map(|entity_ref| entity_ref.entity())
+                .filter(|entity| !existing_entities.contains(entity))
+                .collect();
+            for entity in spawned_entities {
+                let _ = ecs_world.despawn(entity);
+            }
+        }
+
+        // Out of luck, keep the last zone as it comes
+        let mut zone = builder(depth, ecs_world);
+        ConnectivityChecker::ensure_connected(&mut zone, ecs_world);
+        zone
+    }
+
+    /// Flood fill from the player spawn point and dig a tunnel toward every unreachable
+    /// target. Returns false if some target cannot be connected
+    fn ensure_connected(zone: &mut Zone, ecs_world: &World) -> bool {
+        zone.populate_blocked();
+        let targets = ConnectivityChecker::get_targets(zone, ecs_world);
+
+        for _ in 0..MAX_CONNECTIVITY_REPAIRS {
+            let reachable = ConnectivityChecker::flood_fill(zone, zone.player_spawn_point);
+            let unreachable_targets: Vec<usize> = targets
+                .iter()
+                .copied()
+                .filter(|&index| !reachable[index])
+                .collect();
+            if unreachable_targets.is_empty() {
+                return true;
+            }
+
+            // Some target could be walled in by tiles that must not be dug, try the next one
+            if !unreachable_targets
+                .iter()
+                .any(|&target| ConnectivityChecker::dig_tunnel(zone, target, &reachable))
+            {
+                return false;
+            }
+            zone.populate_blocked();
+        }
+
+        false
+    }
+
+    /// Tiles that must be reachable. Walls of a shop are not meant to be walked on
+    fn get_targets(zone: &Zone, ecs_world: &World) -> Vec<usize> {
+        let mut targets: Vec<usize> = zone
+            .tiles
+            .iter()
+            .enumerate()
+            .filter_map(|(index, tile)| {
+                if *tile == TileType::DownPassage || *tile == TileType::BranchPassage {
+                    Some(index)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        targets.extend(zone.item_spawn_points.iter().copied());
+
+        for (_, shop_owner) in &mut ecs_world.query::<&ShopOwner>() {
+            targets.extend(
+                shop_owner
+                    .shop_tiles
+                    .iter()
+                    .copied()
+                    .filter(|&index| ConnectivityChecker::is_passable(zone, index)),
+            );
+        }
+
+        targets
+    }
+
+    /// Doors block the way only until someone opens or breaks them.
+    /// Deep water and hot ground are not blocked, but no one should be forced to swim or burn
+    pub fn is_passable(zone: &Zone, index: usize) -> bool {
+        (!zone.blocked_tiles[index] || zone.is_door_closed(index))
+            && !zone.is_deep_water(index)
+            && !matches!(zone.tiles[index], TileType::LavaCrust | TileType::HotRock)
+    }
+
+    /// Only bare rock can be dug, everything else built in the zone must stay as it is
+    fn is_diggable(zone: &Zone, index: usize) -> bool {
+        matches!(zone.tiles[index], TileType::Wall | TileType::CrackedWall)
+    }
+
+    /// Marks every tile that can be reached walking from the start, diagonals included
+    pub fn flood_fill(zone: &Zone, start: usize) -> Vec<bool> {
+        ConnectivityChecker::flood_fill_through(zone, start, |index| {
+            ConnectivityChecker::is_passable(zone, index)
+        })
+    }
+
+    /// Same as flood_fill, but stopping at locked doors as if they were walls
+    pub fn flood_fill_without_keys(zone: &Zone, start: usize) -> Vec<bool> {
+        ConnectivityChecker::flood_fill_through(zone, start, |index| {
+            ConnectivityChecker::is_passable(zone, index)
+                && zone.tiles[index] != TileType::LockedDoor
+        })
+    }
+
+    fn flood_fill_through(
+        zone: &Zone,
+        start: usize,
+        can_pass: impl Fn(usize) -> bool,
+    ) -> Vec<bool> {
+        let mut reachable = vec![false; zone.tiles.len()];
+        if !can_pass(start) {
+            return reachable;
+        }
+
+        let mut frontier = VecDeque::from([start]);
+        reachable[start] = true;
+
+        while let Some(current) = frontier.pop_front() {
+            let (x, y) = zone.get_xy_from_index(current);
+            for (next_x, next_y) in ConnectivityChecker::get_neighbours(zone, x, y, true) {
+                let next = zone.get_index_from_xy(&next_x, &next_y);
+                if !reachable[next] && can_pass(next) {
+                    reachable[next] = true;
+                    frontier.push_back(next);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Dig the cheapest straight-stepped tunnel from an unreachable tile to the reachable area.
+    /// Walking is cheaper than digging, so existing passages are used whenever possible.
+    /// Returns false if there was nothing to dig
+    fn dig_tunnel(zone: &mut Zone, from: usize, reachable: &[bool]) -> bool {
+        let Some((path, _)) = dijkstra(
+            &from,
+            |&current| {
+                let (x, y) = zone.get_xy_from_index(current);
+                ConnectivityChecker::get_neighbours(zone, x, y, false)
+                    .into_iter()
+                    .filter_map(|(next_x, next_y)| {
+                        let next = zone.get_index_from_xy(&next_x, &next_y);
+                        if ConnectivityChecker::is_passable(zone, next) {
+                            Some((next, 1))
+                        } else if ConnectivityChecker::is_diggable(zone, next) {
+                            Some((next, TUNNEL_PATH_COST))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<(usize, u32)>>()
+            },
+            |&current| reachable[current],
+        ) else {
+            return false;
+        };
+
+        let mut has_dug = false;
+        for index in path {
+            if ConnectivityChecker::is_diggable(zone, index) {
+                zone.tiles[index] = TileType::Floor;
+                has_dug = true;
+            }
+        }
+
+        has_dug
+    }
+
+    /// Adjacent tiles inside the zone boundaries, which are never walkable
+    fn get_neighbours(zone: &Zone, x: i32, y: i32, with_diagonals: bool) -> Vec<(i32, i32)> {
+        let mut neighbours = Vec::new();
+        for next_x in x - 1..=x + 1 {
+            for next_y in y - 1..=y + 1 {
+                if (next_x == x && next_y == y)
+                    || (!with_diagonals && next_x != x && next_y != y)
+                    || next_x < 1
+                    || next_y < 1
+                    || next_x > zone.width - 2
+                    || next_y > zone.height - 2
+                {
+                    continue;
+                }
+                neighbours.push((next_x, next_y));
+            }
+        }
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hecs::World;
+    use macroquad::rand;
+
+    use crate::maps::{
+        ZoneBuilder, arena_zone_builder::ArenaZoneBuilder, cavern_zone_builder::CavernZoneBuilder,
+        crystal_cave_builder::CrystalCaveBuilder, dungeon_zone_builder::DungeonZoneBuilder,
+        flooded_mine_builder::FloodedMineBuilder, fungal_grotto_builder::FungalGrottoBuilder,
+        main_zone_builder::MainZoneBuilder, stonedust_monastery_builder::StonedustMonasteryBuilder,
+        test_zone_builder::TestZoneBuilder, zone::Zone,
+    };
+
+    use super::ConnectivityChecker;
+
+    const SEEDS: u64 = 2000;
+    const DEPTHS: [u32; 4] = [1, 5, 10, 20];
+
+    /// Builds many zones and checks that everything important can be reached from the player spawn point.
+    /// Reaching all of them from the same tile means they can all be reached from each other
+    fn sweep_seeds(builder_name: &str, builder: fn(u32, &mut World) -> Zone) {
+        for seed in 0..SEEDS {
+            for depth in DEPTHS {
+                rand::srand(seed);
+                let mut ecs_world = World::new();
+                let mut zone = ConnectivityChecker::build_connected(builder, depth, &mut ecs_world);
+                zone.populate_blocked();
+
+                assert!(
+                    ConnectivityChecker::is_passable(&zone, zone.player_spawn_point),
+                    "{builder_name} seed {seed} depth {depth}: player spawn point is not passable"
+                );
+
+                let reachable = ConnectivityChecker::flood_fill(&zone, zone.player_spawn_point);
+                for target in ConnectivityChecker::get_targets(&zone, &ecs_world) {
+                    let (x, y) = zone.get_xy_from_index(target);
+                    assert!(
+                        reachable[target],
+                        "{builder_name} seed {seed} depth {depth}: {:?} at {x},{y} is not reachable",
+                        zone.tiles[target]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn main_zones_are_connected() {
+        sweep_seeds("main", MainZoneBuilder::build);
+    }
+
+    #[test]
+    fn arena_zones_are_connected() {
+        sweep_seeds("arena", ArenaZoneBuilder::build);
+    }
+
+    #[test]
+    fn cavern_zones_are_connected() {
+        sweep_seeds("cavern", CavernZoneBuilder::build);
+    }
+
+    #[test]
+    fn crystal_cave_zones_are_connected() {
+        sweep_seeds("crystal cave", CrystalCaveBuilder::build);
+    }
+
+    #[test]
+    fn dungeon_zones_are_connected() {
+        sweep_seeds("dungeon", DungeonZoneBuilder::build);
+    }
+
+    #[test]
+    fn flooded_mine_zones_are_connected() {
+        sweep_seeds("flooded mine", FloodedMineBuilder::build);
+    }
+
+    #[test]
+    fn fungal_grotto_zones_are_connected() {
+        sweep_seeds("fungal grotto", FungalGrottoBuilder::build);
+    }
+
+    #[test]
+    fn stonedust_monastery_zones_are_connected() {
+        sweep_seeds("stonedust monastery", StonedustMonasteryBuilder::build);
+    }
+
+    #[test]
+    fn test_zones_are_connected() {
+        sweep_seeds("test", TestZoneBuilder::build);
+    }
+}
@@ -1,7 +1,7 @@
 use hecs::World;
 
 use crate::{
-    constants::{MAP_HEIGHT, MAP_WIDTH},
+    constants::{DOOR_CHANCE, LOCKED_DOOR_CHANCE},
     maps::{
         ZoneFeatureBuilder, ZoneFeatureBuilderOrigin,
         zone::{TileType, Zone},
@@ -20,21 +20,30 @@ impl ZoneFeatureBuilder for CracksBuilder {
         //1 - select a start point with X or Y = 1
         let mut current_position = (1, 1);
         if Roll::d100() <= 50 {
-            current_position.1 = Roll::dice(1, MAP_HEIGHT - 1) + 1;
+            current_position.1 = Roll::dice(1, zone.height - 1) + 1;
             origin = ZoneFeatureBuilderOrigin::Left;
         } else {
-            current_position.0 = Roll::dice(1, MAP_WIDTH - 1) + 1;
+            current_position.0 = Roll::dice(1, zone.width - 1) + 1;
             origin = ZoneFeatureBuilderOrigin::Top;
         }
 
-        //2 - if point is X = MAP_WIDTH-1 or Y = MAP_HEIGHT-1, stop
-        while current_position.0 < MAP_WIDTH - 1 && current_position.1 < MAP_HEIGHT - 1 {
+        //2 - if point is X = zone.width-1 or Y = zone.height-1, stop
+        while current_position.0 < zone.width - 1 && current_position.1 < zone.height - 1 {
             //3 - draw a cracked tile there if there is a wall tile
-            let index = Zone::get_index_from_xy(&current_position.0, &current_position.1);
+            let index = zone.get_index_from_xy(&current_position.0, &current_position.1);
             if zone.tiles[index] == TileType::Wall {
-                // 1 on 6 chance to replace with floor instead of cracked wall
-                // so that crack paths will be more random
-                if Roll::dice(1, 6) == 1 {
+                // Where a crack breaks through a thin wall between two open spaces, there could be a door
+                if CracksBuilder::is_doorway(zone, current_position.0, current_position.1)
+                    && Roll::d6() <= DOOR_CHANCE
+                {
+                    if Roll::d6() <= LOCKED_DOOR_CHANCE {
+                        zone.tiles[index] = TileType::LockedDoor;
+                    } else {
+                        zone.tiles[index] = TileType::ClosedDoor;
+                    }
+                } else if Roll::dice(1, 6) == 1 {
+                    // 1 on 6 chance to replace with floor instead of cracked wall
+                    // so that crack paths will be more random
                     zone.tiles[index] = TileType::Floor;
                 } else {
                     zone.tiles[index] = TileType::CrackedWall;
@@ -64,7 +73,7 @@ impl ZoneFeatureBuilder for CracksBuilder {
             }
 
             // Avoid boundaries, or else skip iteration
-            if dest_x <= 1 || dest_x >= MAP_WIDTH || dest_y <= 1 || dest_y >= MAP_HEIGHT {
+            if dest_x <= 1 || dest_x >= zone.width || dest_y <= 1 || dest_y >= zone.height {
                 println!("CracksBuilder - Try again");
                 continue;
             }
@@ -77,3 +86,20 @@ impl ZoneFeatureBuilder for CracksBuilder {
         cracked_tiles
     }
 }
+
+impl CracksBuilder {
+    /// A doorway is a tile squeezed between two walls, that connects two floor tiles on the other side
+    fn is_doorway(zone: &Zone, x: i32, y: i32) -> bool {
+        let is_floor =
+            |x: i32, y: i32| zone.tiles[zone.get_index_from_xy(&x, &y)] == TileType::Floor;
+
+        (zone.is_tile_solid(&(x - 1), &y)
+            && zone.is_tile_solid(&(x + 1), &y)
+            && is_floor(x, y - 1)
+            && is_floor(x, y + 1))
+            || (zone.is_tile_solid(&x, &(y - 1))
+                && zone.is_tile_solid(&x, &(y + 1))
+                && is_floor(x - 1, y)
+                && is_floor(x + 1, y))
+    }
+}
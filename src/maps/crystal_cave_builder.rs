@@ -1,7 +1,9 @@
 use hecs::World;
 
 use crate::{
-    constants::{CRYSTAL_GROWTH_COUNTER_START, GOLD_LOCK_COUNTER_START, MAP_HEIGHT, MAP_WIDTH},
+    constants::{
+        CRYSTAL_GROWTH_COUNTER_START, GOLD_LOCK_COUNTER_START, VIEWPORT_HEIGHT, VIEWPORT_WIDTH,
+    },
     maps::{
         ZoneBuilder,
         zone::{TileType, Zone},
@@ -15,15 +17,14 @@ pub struct CrystalCaveBuilder {}
 /// Builder for the Crystal Cave specialzone.
 impl ZoneBuilder for CrystalCaveBuilder {
     fn build(depth: u32, ecs_world: &mut World) -> Zone {
-        let mut zone = Zone::new(depth, TileType::BigCrystal);
-        zone.special_tile_counter =
-            [CRYSTAL_GROWTH_COUNTER_START; (MAP_WIDTH * MAP_HEIGHT) as usize];
+        let mut zone = Zone::new(depth, TileType::BigCrystal, VIEWPORT_WIDTH, VIEWPORT_HEIGHT);
+        zone.special_tile_counter = vec![CRYSTAL_GROWTH_COUNTER_START; zone.tiles.len()];
 
         // Create boundaries
-        for x in 0..MAP_WIDTH {
-            for y in 0..MAP_HEIGHT {
-                if x != 0 && y != 0 && x != MAP_WIDTH - 1 && y != MAP_HEIGHT - 1 {
-                    let index = Zone::get_index_from_xy(&x, &y);
+        for x in 0..VIEWPORT_WIDTH {
+            for y in 0..VIEWPORT_HEIGHT {
+                if x != 0 && y != 0 && x != VIEWPORT_WIDTH - 1 && y != VIEWPORT_HEIGHT - 1 {
+                    let index = zone.get_index_from_xy(&x, &y);
 
                     if Roll::d20() <= 6 {
                         zone.tiles[index] = TileType::LittleCrystal;
@@ -36,16 +37,16 @@ impl ZoneBuilder for CrystalCaveBuilder {
             }
         }
 
-        let player_x = &((MAP_WIDTH / 2) - Roll::dice(2, 3));
-        zone.player_spawn_point = Zone::get_index_from_xy(player_x, &1);
+        let player_x = &((VIEWPORT_WIDTH / 2) - Roll::dice(2, 3));
+        zone.player_spawn_point = zone.get_index_from_xy(player_x, &1);
 
-        zone.tiles[Zone::get_index_from_xy(&(MAP_WIDTH / 2), &(MAP_HEIGHT / 2))] =
-            TileType::TripleGoldLock(GOLD_LOCK_COUNTER_START);
+        let lock_index = zone.get_index_from_xy(&(VIEWPORT_WIDTH / 2), &(VIEWPORT_HEIGHT / 2));
+        zone.tiles[lock_index] = TileType::TripleGoldLock(GOLD_LOCK_COUNTER_START);
 
         let lock_entity = Spawn::tile_entity(
             ecs_world,
-            MAP_WIDTH / 2,
-            MAP_HEIGHT / 2,
+            VIEWPORT_WIDTH / 2,
+            VIEWPORT_HEIGHT / 2,
             &TileType::TripleGoldLock(GOLD_LOCK_COUNTER_START),
         );
 
@@ -56,9 +57,9 @@ impl ZoneBuilder for CrystalCaveBuilder {
         // |k.....k|
         // TODO improve randomicity
         for (key_x, key_y) in [
-            (MAP_WIDTH / 2, 1),
-            (1, MAP_HEIGHT - 2),
-            (MAP_WIDTH - 2, MAP_HEIGHT - 2),
+            (VIEWPORT_WIDTH / 2, 1),
+            (1, VIEWPORT_HEIGHT - 2),
+            (VIEWPORT_WIDTH - 2, VIEWPORT_HEIGHT - 2),
         ] {
             let _ = Spawn::gold_key(
                 ecs_world,
@@ -70,10 +71,10 @@ impl ZoneBuilder for CrystalCaveBuilder {
 
         // place human refugees in random locations
         for _ in 0..6 {
-            Spawn::refugee(
+            let _ = Spawn::refugee(
                 ecs_world,
-                Roll::dice(1, MAP_WIDTH - 2),
-                Roll::dice(1, MAP_HEIGHT - 2),
+                Roll::dice(1, VIEWPORT_WIDTH - 2),
+                Roll::dice(1, VIEWPORT_HEIGHT - 2),
             );
         }
 
@@ -0,0 +1,105 @@
+use hecs::World;
+
+use crate::{
+    constants::{FLOODED_MINE_DEPTH, FUNGAL_GROTTO_DEPTH, STONEDUST_MONASTERY_DEPTH},
+    maps::{
+        ZoneBuilder,
+        connectivity_checker::ConnectivityChecker,
+        flooded_mine_builder::FloodedMineBuilder,
+        fungal_grotto_builder::FungalGrottoBuilder,
+        stonedust_monastery_builder::StonedustMonasteryBuilder,
+        zone::Zone,
+        zone_builder_table::{BuildZoneFn, ZoneBuilderTable},
+    },
+};
+
+/// Paths of the dungeon. Each branch leaves the main line through a side passage
+/// and rejoins it some levels below
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Branch {
+    Main,
+    FloodedMine,
+    FungalGrotto,
+    StonedustMonastery,
+}
+
+/// Branch, depth of the main line zone with the side passage, number of levels and builder
+const BRANCH_TABLE: [(Branch, u32, u32, BuildZoneFn); 3] = [
+    (
+        Branch::FloodedMine,
+        FLOODED_MINE_DEPTH,
+        2,
+        FloodedMineBuilder::build,
+    ),
+    (
+        Branch::FungalGrotto,
+        FUNGAL_GROTTO_DEPTH,
+        2,
+        FungalGrottoBuilder::build,
+    ),
+    (
+        Branch::StonedustMonastery,
+        STONEDUST_MONASTERY_DEPTH,
+        3,
+        StonedustMonasteryBuilder::build,
+    ),
+];
+
+/// Tells where each passage leads, and builds the zones of every branch
+pub struct DungeonGraph {}
+
+impl DungeonGraph {
+    /// Branch reached by the side passage of a zone, if there is any
+    pub fn branch_starting_at(branch: Branch, depth: u32) -> Option<Branch> {
+        if branch != Branch::Main {
+            return None;
+        }
+
+        BRANCH_TABLE
+            .iter()
+            .find(|(_, entry_depth, _, _)| *entry_depth == depth)
+            .map(|&(branch, _, _, _)| branch)
+    }
+
+    /// Branch and depth of the zone below. The last level of a branch leads back to the main line
+    pub fn next_zone(branch: Branch, depth: u32, take_side_passage: bool) -> (Branch, u32) {
+        if take_side_passage
+            && let Some(side_branch) = DungeonGraph::branch_starting_at(branch, depth)
+        {
+            return (side_branch, depth + 1);
+        }
+
+        match BRANCH_TABLE
+            .iter()
+            .find(|(table_branch, _, _, _)| *table_branch == branch)
+        {
+            Some(&(_, entry_depth, levels, _)) if depth >= entry_depth + levels => {
+                (Branch::Main, depth + 1)
+            }
+            _ => (branch, depth + 1),
+        }
+    }
+
+    /// Build the connected zone of a branch at a given depth
+    pub fn build(branch: Branch, depth: u32, ecs_world: &mut World) -> Zone {
+        match BRANCH_TABLE
+            .iter()
+            .find(|(table_branch, _, _, _)| *table_branch == branch)
+        {
+            Some(&(_, _, _, builder)) => {
+                ConnectivityChecker::build_connected(builder, depth, ecs_world)
+            }
+            None => ZoneBuilderTable::build(depth, ecs_world),
+        }
+    }
+
+    /// Name shown to the player when entering a branch
+    pub fn get_name(branch: Branch) -> &'static str {
+        match branch {
+            Branch::Main => "the main caverns",
+            Branch::FloodedMine => "the Flooded Mine",
+            Branch::FungalGrotto => "the Fungal Grotto",
+            Branch::StonedustMonastery => "the Stonedust Monastery",
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use hecs::World;
+
+use crate::{
+    constants::*,
+    maps::{
+        ZoneBuilder, ZoneFeatureBuilder,
+        dungeon_graph::Branch,
+        gold_mine_builder::GoldMineBuilder,
+        lake_builder::LakeBuilder,
+        main_zone_builder::MainZoneBuilder,
+        zone::{TileType, Zone},
+    },
+    utils::roll::Roll,
+};
+
+/// Builds an abandoned mine made of straight tunnels, flooded by underground lakes
+pub struct FloodedMineBuilder {}
+
+impl ZoneBuilder for FloodedMineBuilder {
+    fn build(depth: u32, ecs_world: &mut World) -> Zone {
+        let mut zone = Zone::new(depth, TileType::Wall, MAP_WIDTH, MAP_HEIGHT);
+        zone.branch = Branch::FloodedMine;
+
+        const MAX_SHAFTS: i32 = 8;
+        const MAX_GALLERIES: i32 = 12;
+        const MAX_GALLERY_LENGTH: i32 = 15;
+        const MAX_LAKES: i32 = 4;
+
+        // The main tunnel crosses the whole zone, every shaft starts from it
+        let main_tunnel_y = Roll::dice(1, zone.height - 4) + 1;
+        FloodedMineBuilder::dig(&mut zone, 1, main_tunnel_y, zone.width - 2, main_tunnel_y);
+
+        let mut shafts = Vec::new();
+        for _ in 0..Roll::dice(1, MAX_SHAFTS) + 2 {
+            let shaft_x = Roll::dice(1, zone.width - 4) + 1;
+            FloodedMineBuilder::dig(&mut zone, shaft_x, 1, shaft_x, zone.height - 2);
+            shafts.push(shaft_x);
+        }
+
+        // Side galleries follow the veins from the shafts
+        for _ in 0..Roll::dice(1, MAX_GALLERIES) {
+            let start_x = shafts[Roll::dice(1, shafts.len() as i32) as usize - 1];
+            let start_y = Roll::dice(1, zone.height - 4) + 1;
+            let end_x = (start_x + Roll::dice(1, MAX_GALLERY_LENGTH * 2) - MAX_GALLERY_LENGTH)
+                .clamp(1, zone.width - 2);
+            FloodedMineBuilder::dig(&mut zone, start_x, start_y, end_x, start_y);
+        }
+
+        // Water filled the lowest tunnels
+        for _ in 0..Roll::dice(1, MAX_LAKES) + 1 {
+            LakeBuilder::build(&mut zone, ecs_world);
+        }
+
+        // Not all the gold has been mined
+        GoldMineBuilder::build(&mut zone, ecs_world);
+
+        MainZoneBuilder::add_features(&mut zone, ecs_world);
+
+        zone
+    }
+}
+
+impl FloodedMineBuilder {
+    /// Dig a straight tunnel between two points, in a horizontal or vertical line
+    fn dig(zone: &mut Zone, x1: i32, y1: i32, x2: i32, y2: i32) {
+        for x in x1.min(x2)..=x1.max(x2) {
+            for y in y1.min(y2)..=y1.max(y2) {
+                let index = zone.get_index_from_xy(&x, &y);
+                if zone.tiles[index] == TileType::Wall {
+                    zone.tiles[index] = TileType::Floor;
+                }
+            }
+        }
+    }
+}
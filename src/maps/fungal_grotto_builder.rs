@@ -0,0 +1,62 @@
+use hecs::World;
+
+use crate::{
+    constants::*,
+    maps::{
+        ZoneBuilder, ZoneFeatureBuilder,
+        cavern_zone_builder::CavernZoneBuilder,
+        dungeon_graph::Branch,
+        main_zone_builder::MainZoneBuilder,
+        mushroom_field_builder::MushroomFieldBuilder,
+        zone::{TileType, Zone},
+    },
+    spawning::spawner::Spawn,
+    utils::{common::Utils, roll::Roll},
+};
+
+/// Builds damp caverns overgrown with fungi
+pub struct FungalGrottoBuilder {}
+
+impl ZoneBuilder for FungalGrottoBuilder {
+    fn build(depth: u32, ecs_world: &mut World) -> Zone {
+        let mut zone = Zone::new(depth, TileType::Wall, MAP_WIDTH, MAP_HEIGHT);
+        zone.branch = Branch::FungalGrotto;
+
+        const MAX_PATCHES: i32 = 10;
+        const MAX_PATCH_RADIUS: i32 = 3;
+        const MUSHROOM_CHANCE: i32 = 1;
+
+        CavernZoneBuilder::carve(&mut zone);
+
+        // Patches of fungi grow wherever there is some room
+        for _ in 0..Roll::dice(1, MAX_PATCHES) + 2 {
+            let center_x = Roll::dice(1, zone.width - 2);
+            let center_y = Roll::dice(1, zone.height - 2);
+            let radius = Roll::dice(1, MAX_PATCH_RADIUS);
+
+            for x in (center_x - radius).max(1)..=(center_x + radius).min(zone.width - 2) {
+                for y in (center_y - radius).max(1)..=(center_y + radius).min(zone.height - 2) {
+                    let index = zone.get_index_from_xy(&x, &y);
+                    if zone.tiles[index] != TileType::Floor
+                        || Utils::distance(&x, &center_x, &y, &center_y) > radius as f32
+                    {
+                        continue;
+                    }
+
+                    zone.tiles[index] = TileType::MushroomField;
+                    if Roll::d6() <= MUSHROOM_CHANCE {
+                        Spawn::random_mushroom(ecs_world, x, y);
+                    }
+                }
+            }
+        }
+
+        // Someone is farming them
+        let farm_tiles = MushroomFieldBuilder::build(&mut zone, ecs_world);
+        zone.special_rooms.push(("mushroom farm", farm_tiles));
+
+        MainZoneBuilder::add_features(&mut zone, ecs_world);
+
+        zone
+    }
+}
@@ -0,0 +1,55 @@
+use hecs::World;
+
+use crate::{
+    constants::{LAKE_MAX_RADIUS, LAKE_MIN_RADIUS},
+    maps::{
+        ZoneFeatureBuilder,
+        zone::{TileType, Zone},
+    },
+    utils::{common::Utils, roll::Roll},
+};
+
+///Lake Builder, an underground pool too deep to wade, with shallow water on the shores
+pub struct LakeBuilder {}
+
+impl ZoneFeatureBuilder for LakeBuilder {
+    fn build(zone: &mut Zone, _: &mut World) -> Vec<usize> {
+        let mut lake_tiles = Vec::new();
+        let radius = Roll::dice(1, LAKE_MAX_RADIUS - LAKE_MIN_RADIUS + 1) + LAKE_MIN_RADIUS - 1;
+
+        // The lake must fit entirely inside the zone borders
+        if zone.width <= (radius + 1) * 2 || zone.height <= (radius + 1) * 2 {
+            return lake_tiles;
+        }
+
+        let center_x = Roll::dice(1, zone.width - (radius + 1) * 2) + radius;
+        let center_y = Roll::dice(1, zone.height - (radius + 1) * 2) + radius;
+
+        for x in center_x - radius..=center_x + radius {
+            for y in center_y - radius..=center_y + radius {
+                let distance = Utils::distance(&x, &center_x, &y, &center_y);
+                let index = zone.get_index_from_xy(&x, &y);
+
+                // Shores are irregular, and only bare rock, floor and the rivers flowing in are flooded
+                if distance > radius as f32 + 0.5
+                    || (distance > (radius - 1) as f32 && Roll::d6() <= 3)
+                    || !matches!(
+                        zone.tiles[index],
+                        TileType::Floor | TileType::Wall | TileType::CrackedWall | TileType::Water
+                    )
+                {
+                    continue;
+                }
+
+                if distance < (radius - 1) as f32 {
+                    zone.tiles[index] = TileType::DeepWater;
+                } else {
+                    zone.tiles[index] = TileType::Water;
+                }
+                lake_tiles.push(index);
+            }
+        }
+
+        lake_tiles
+    }
+}
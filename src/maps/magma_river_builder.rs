@@ -0,0 +1,153 @@
+use hecs::World;
+
+use crate::{
+    constants::{LAVA_CRUST_CHANCE, LAVA_CRUST_STEPS, MAGMA_RIVER_TENTATIVES},
+    maps::{
+        ZoneFeatureBuilder,
+        river_builder::RiverBuilder,
+        zone::{TileType, Zone},
+    },
+    utils::roll::Roll,
+};
+
+///Magma River Builder, flowing like water rivers but much more dangerous to cross
+pub struct MagmaRiverBuilder {}
+
+impl ZoneFeatureBuilder for MagmaRiverBuilder {
+    fn build(zone: &mut Zone, ecs_world: &mut World) -> Vec<usize> {
+        // Magma follows the same winding path of a river. It never meets other lava or a lake,
+        // or the cooled ford alone would not be enough to cross them
+        let original_tiles = zone.tiles.clone();
+        let mut magma_tiles = Vec::new();
+        for _ in 0..MAGMA_RIVER_TENTATIVES {
+            magma_tiles = RiverBuilder::build(zone, ecs_world);
+            if !MagmaRiverBuilder::meets_hazards(zone, &original_tiles, &magma_tiles) {
+                break;
+            }
+            zone.tiles.clone_from(&original_tiles);
+            magma_tiles.clear();
+        }
+        if magma_tiles.is_empty() {
+            return magma_tiles;
+        }
+
+        // Somewhere across the flow the magma has cooled down into solid stone, so the river
+        // can always be crossed. Rivers starting from the left border flow across the zone,
+        // so the ford is a column. The others flow down, so the ford is a row
+        let (first_x, _) = zone.get_xy_from_index(magma_tiles[0]);
+        let flows_across = first_x == 1;
+        let ford_roll = Roll::dice(1, magma_tiles.len() as i32) as usize - 1;
+        let (ford_x, ford_y) = zone.get_xy_from_index(magma_tiles[ford_roll]);
+        let is_ford = |x: i32, y: i32| {
+            if flows_across {
+                x == ford_x
+            } else {
+                y == ford_y
+            }
+        };
+
+        // Here and there the lava has cooled down into a crust, that can be crossed with some luck
+        for &index in magma_tiles.iter() {
+            let (x, y) = zone.get_xy_from_index(index);
+            if is_ford(x, y) {
+                zone.tiles[index] = TileType::StoneFloor;
+            } else if Roll::d6() <= LAVA_CRUST_CHANCE {
+                zone.tiles[index] = TileType::LavaCrust;
+                zone.special_tile_counter[index] = LAVA_CRUST_STEPS;
+            } else {
+                zone.tiles[index] = TileType::Lava;
+            }
+        }
+
+        // The floor on the banks is scorching hot
+        for &index in magma_tiles.iter() {
+            let (x, y) = zone.get_xy_from_index(index);
+            for bank_x in x - 1..=x + 1 {
+                for bank_y in y - 1..=y + 1 {
+                    // The way to the ford stays cool
+                    if !(0..zone.width).contains(&bank_x)
+                        || !(0..zone.height).contains(&bank_y)
+                        || is_ford(bank_x, bank_y)
+                    {
+                        continue;
+                    }
+
+                    let bank_index = zone.get_index_from_xy(&bank_x, &bank_y);
+                    if zone.tiles[bank_index] == TileType::Floor {
+                        zone.tiles[bank_index] = TileType::HotRock;
+                    }
+                }
+            }
+        }
+
+        // The way to the ford is cooled as well on both sides, until the heat is left behind
+        let ford_ends: Vec<(i32, i32)> = magma_tiles
+            .iter()
+            .map(|&index| zone.get_xy_from_index(index))
+            .filter(|&(x, y)| is_ford(x, y))
+            .collect();
+        let (step_x, step_y) = if flows_across { (0, 1) } else { (1, 0) };
+        if let (Some(&start), Some(&end)) = (ford_ends.iter().min(), ford_ends.iter().max()) {
+            MagmaRiverBuilder::cool_way_to_ford(zone, start, (-step_x, -step_y));
+            MagmaRiverBuilder::cool_way_to_ford(zone, end, (step_x, step_y));
+        }
+
+        magma_tiles
+    }
+}
+
+impl MagmaRiverBuilder {
+    /// True if the river flows near lava, hot rock, a ford or deep water already in the zone
+    fn meets_hazards(zone: &Zone, original_tiles: &[TileType], magma_tiles: &[usize]) -> bool {
+        magma_tiles.iter().any(|&index| {
+            let (x, y) = zone.get_xy_from_index(index);
+            (x - 2..=x + 2).any(|near_x| {
+                (y - 2..=y + 2).any(|near_y| {
+                    zone.is_inside(&near_x, &near_y)
+                        && matches!(
+                            original_tiles[zone.get_index_from_xy(&near_x, &near_y)],
+                            TileType::Lava
+                                | TileType::LavaCrust
+                                | TileType::HotRock
+                                | TileType::StoneFloor
+                                | TileType::DeepWater
+                        )
+                })
+            })
+        })
+    }
+
+    /// Walks away from the ford, turning rock and floor into stone until no lava is near.
+    /// Stone cannot collapse or host anything, so the ford is never cut off
+    fn cool_way_to_ford(zone: &mut Zone, ford_end: (i32, i32), step: (i32, i32)) {
+        let (mut x, mut y) = ford_end;
+        loop {
+            x += step.0;
+            y += step.1;
+            if x < 1 || y < 1 || x > zone.width - 2 || y > zone.height - 2 {
+                return;
+            }
+
+            let index = zone.get_index_from_xy(&x, &y);
+            if matches!(
+                zone.tiles[index],
+                TileType::Floor | TileType::Wall | TileType::CrackedWall
+            ) {
+                zone.tiles[index] = TileType::StoneFloor;
+            }
+
+            let is_near_heat = (x - 1..=x + 1).any(|near_x| {
+                (y - 1..=y + 1).any(|near_y| {
+                    zone.is_inside(&near_x, &near_y)
+                        && matches!(
+                            zone.tiles[zone.get_index_from_xy(&near_x, &near_y)],
+                            TileType::Lava | TileType::LavaCrust | TileType::HotRock
+                        )
+                })
+            });
+            if !is_near_heat {
+                return;
+            }
+        }
+    }
+}
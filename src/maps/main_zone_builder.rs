@@ -6,13 +6,19 @@ use crate::{
     constants::*,
     maps::{
         ZoneBuilder, ZoneFeatureBuilder,
+        chasm_builder::ChasmBuilder,
         cracks_builder::CracksBuilder,
         crystal_patch_builder::CrystalPatchBuilder,
+        dungeon_graph::DungeonGraph,
         gold_mine_builder::GoldMineBuilder,
+        lake_builder::LakeBuilder,
+        magma_river_builder::MagmaRiverBuilder,
         mushroom_field_builder::MushroomFieldBuilder,
+        prefab_builder::PrefabBuilder,
         river_builder::RiverBuilder,
         stonedust_procession_builder::StonedustProcessionBuilder,
         stonedust_shrine_builder::StonedustShrineBuilder,
+        trap_builder::TrapBuilder,
         zone::{TileType, Zone},
     },
     utils::roll::Roll,
@@ -24,19 +30,21 @@ pub struct MainZoneBuilder {}
 impl ZoneBuilder for MainZoneBuilder {
     /// Create new dungeon zone (needed?)
     fn build(depth: u32, ecs_world: &mut World) -> Zone {
-        let mut zone = Zone::new(depth, TileType::Wall);
+        let mut zone = Zone::new(depth, TileType::Wall, MAP_WIDTH, MAP_HEIGHT);
 
         // Simple Drunken walk
-        let mut current_position = (MAP_WIDTH / 2, MAP_HEIGHT / 2);
+        let mut current_position = (zone.width / 2, zone.height / 2);
+        // Bigger zones need more walkers to be dug out
         let max_iterations = max(
             DRUNKEN_WALK_MIN_ITERATIONS,
             DRUNKEN_WALK_MAX_ITERATIONS - 2 * depth as i32,
-        );
+        ) * (zone.width * zone.height)
+            / (VIEWPORT_WIDTH * VIEWPORT_HEIGHT);
 
         // the more deep we are, the less free space we have
         for _ in 0..max_iterations {
-            zone.tiles[Zone::get_index_from_xy(&current_position.0, &current_position.1)] =
-                TileType::Floor;
+            let start_index = zone.get_index_from_xy(&current_position.0, &current_position.1);
+            zone.tiles[start_index] = TileType::Floor;
 
             let mut life_counter = 0;
             let mut unblock_tentatives = 10;
@@ -53,11 +61,14 @@ impl ZoneBuilder for MainZoneBuilder {
                 }
 
                 // Avoid boundaries, or else skip iteration
-                if dest_x <= 1 || dest_x >= MAP_WIDTH - 1 || dest_y <= 1 || dest_y >= MAP_HEIGHT - 1
+                if dest_x <= 1
+                    || dest_x >= zone.width - 1
+                    || dest_y <= 1
+                    || dest_y >= zone.height - 1
                 {
                     if unblock_tentatives < 0 {
                         unblock_tentatives = 10;
-                        current_position = (MAP_WIDTH / 2, MAP_HEIGHT / 2);
+                        current_position = (zone.width / 2, zone.height / 2);
                     } else {
                         unblock_tentatives -= 1;
                         println! {"DrunkenWalkZoneBuilder - unblock_tentatives {}",unblock_tentatives};
@@ -65,16 +76,31 @@ impl ZoneBuilder for MainZoneBuilder {
                     continue;
                 }
 
-                let index = Zone::get_index_from_xy(&dest_x, &dest_y);
+                let index = zone.get_index_from_xy(&dest_x, &dest_y);
                 if zone.tiles[index] == TileType::Wall {
                     zone.tiles[index] = TileType::Floor;
                 }
                 life_counter += 1;
                 current_position = (dest_x, dest_y);
             }
-            current_position = (Roll::dice(1, MAP_WIDTH - 2), Roll::dice(1, MAP_HEIGHT - 2));
+            current_position = (
+                Roll::dice(1, zone.width - 2),
+                Roll::dice(1, zone.height - 2),
+            );
         }
 
+        MainZoneBuilder::add_features(&mut zone, ecs_world);
+
+        zone
+    }
+}
+
+impl MainZoneBuilder {
+    /// Add rivers, special rooms, cracks, spawn points and the way down to an already carved zone.
+    /// Shared by all the builders of cavern like zones
+    pub fn add_features(zone: &mut Zone, ecs_world: &mut World) {
+        let depth = zone.depth;
+
         // Less river the more deep we are
         let river_number = max(
             0,
@@ -85,37 +111,70 @@ impl ZoneBuilder for MainZoneBuilder {
         if river_number == 0 {
             let mut pool = zone.tiles.len() / 2;
             while zone.tiles[pool] != TileType::Floor {
-                try_x = Roll::dice(1, MAP_WIDTH - 2);
-                try_y = Roll::dice(1, MAP_HEIGHT - 2);
-                pool = Zone::get_index_from_xy(&try_x, &try_y);
+                try_x = Roll::dice(1, zone.width - 2);
+                try_y = Roll::dice(1, zone.height - 2);
+                pool = zone.get_index_from_xy(&try_x, &try_y);
             }
             zone.tiles[pool] = TileType::Water;
         } else {
             for _ in 0..river_number {
-                RiverBuilder::build(&mut zone, ecs_world);
+                RiverBuilder::build(zone, ecs_world);
+            }
+        }
+
+        // Some underground lake, deep enough to swim
+        if Roll::d6() <= LAKE_CHANCE {
+            LakeBuilder::build(zone, ecs_world);
+        }
+
+        // After Crystal Cave, magma flows in the depths
+        if depth > CRYSTAL_CAVE_DEPTH {
+            for _ in 0..Roll::dice(1, MAX_MAGMA_RIVERS_IN_ZONE) {
+                MagmaRiverBuilder::build(zone, ecs_world);
             }
         }
         //Mushroom Field
         if depth.is_multiple_of(SPECIAL_ROOM_LEVEL) {
-            let _ = match Roll::dice(1, 3) {
-                1 => MushroomFieldBuilder::build(&mut zone, ecs_world),
-                2 => StonedustProcessionBuilder::build(&mut zone, ecs_world),
-                3 => StonedustShrineBuilder::build(&mut zone, ecs_world),
+            let special_room = match Roll::dice(1, 4) {
+                1 => (
+                    "mushroom farm",
+                    MushroomFieldBuilder::build(zone, ecs_world),
+                ),
+                2 => (
+                    "stonedust procession",
+                    StonedustProcessionBuilder::build(zone, ecs_world),
+                ),
+                3 => (
+                    "stonedust shrine",
+                    StonedustShrineBuilder::build(zone, ecs_world),
+                ),
+                4 => ("strange place", PrefabBuilder::build(zone, ecs_world)),
                 _ => panic!("Invalid special room"),
             };
+            // Remembered for the overview map
+            zone.special_rooms.push(special_room);
         }
         // Populate water and blocked tiles here, needed for correct spawning
         zone.populate_blocked();
         zone.populate_water();
 
         // First crack generation, used for player spawn point and down passage to ensure we have a path to the exit
-        let mut first_crack_tiles = CracksBuilder::build(&mut zone, ecs_world); // Ensure that in first crack there is at least one non blocked tile
-        // this is needed to ensure the player has a possible path to the exit
+        let mut first_crack_tiles = CracksBuilder::build(zone, ecs_world); // Ensure that in first crack there is at least one non blocked tile
+        // this is needed to ensure the player has a possible path to the exit.
+        // Passages only open on floor or cracked rock, never in lava, water or special rooms
+        first_crack_tiles
+            .retain(|&index| matches!(zone.tiles[index], TileType::Floor | TileType::CrackedWall));
         while first_crack_tiles.is_empty()
             || first_crack_tiles.iter().all(|&i| zone.blocked_tiles[i])
         {
-            first_crack_tiles = CracksBuilder::build(&mut zone, ecs_world);
+            first_crack_tiles = CracksBuilder::build(zone, ecs_world);
+            first_crack_tiles.retain(|&index| {
+                matches!(zone.tiles[index], TileType::Floor | TileType::CrackedWall)
+            });
         }
+        // A crack stuck on the border repeats the same tile
+        first_crack_tiles.sort_unstable();
+        first_crack_tiles.dedup();
 
         // Random starting point for player, taken from first crack
         // This must be done before generating monsters and items
@@ -129,10 +188,14 @@ impl ZoneBuilder for MainZoneBuilder {
         // Choose a random point for player spawn after
         // ensuring first crack gieves a path to the exit
         zone.player_spawn_point = zone.tiles.len() / 2;
-        while zone.blocked_tiles[zone.player_spawn_point] {
-            try_x = Roll::dice(1, MAP_WIDTH - 2);
-            try_y = Roll::dice(1, MAP_HEIGHT - 2);
-            zone.player_spawn_point = Zone::get_index_from_xy(&try_x, &try_y);
+        while zone.blocked_tiles[zone.player_spawn_point]
+            || zone.tiles[zone.player_spawn_point] == TileType::LavaCrust
+            || zone.tiles[zone.player_spawn_point] == TileType::HotRock
+            || zone.is_deep_water(zone.player_spawn_point)
+        {
+            try_x = Roll::dice(1, zone.width - 2);
+            try_y = Roll::dice(1, zone.height - 2);
+            zone.player_spawn_point = zone.get_index_from_xy(&try_x, &try_y);
         }
 
         // Generate monster and items spawn points within each room
@@ -146,23 +209,24 @@ impl ZoneBuilder for MainZoneBuilder {
         for _ in 0..braziers_number {
             let mut brazier_index = zone.tiles.len() / 2;
             while zone.tiles[brazier_index] != TileType::Floor {
-                try_x = Roll::dice(1, MAP_WIDTH - 2);
-                try_y = Roll::dice(1, MAP_HEIGHT - 2);
-                brazier_index = Zone::get_index_from_xy(&try_x, &try_y);
+                try_x = Roll::dice(1, zone.width - 2);
+                try_y = Roll::dice(1, zone.height - 2);
+                brazier_index = zone.get_index_from_xy(&try_x, &try_y);
             }
             zone.tiles[brazier_index] = TileType::Brazier;
         }
 
         for _ in 0..monster_number {
             for _ in 0..MAX_SPAWN_TENTATIVES {
-                let x = Roll::dice(1, MAP_WIDTH - 2) as f32;
-                let y = Roll::dice(1, MAP_HEIGHT - 2) as f32;
-                let index = Zone::get_index_from_xy_f32(x, y);
+                let x = Roll::dice(1, zone.width - 2) as f32;
+                let y = Roll::dice(1, zone.height - 2) as f32;
+                let index = zone.get_index_from_xy_f32(x, y);
 
                 // avoid walls, player and duplicate spawnpoints
                 if index != zone.player_spawn_point
-                    && (zone.tiles[Zone::get_index_from_xy_f32(x, y)] == TileType::Floor
-                        || zone.tiles[Zone::get_index_from_xy_f32(x, y)] == TileType::Water)
+                    && (zone.tiles[zone.get_index_from_xy_f32(x, y)] == TileType::Floor
+                        || zone.tiles[zone.get_index_from_xy_f32(x, y)] == TileType::Water
+                        || zone.tiles[zone.get_index_from_xy_f32(x, y)] == TileType::DeepWater)
                     && !zone.monster_spawn_points.contains(&index)
                 {
                     zone.monster_spawn_points.insert(index);
@@ -173,13 +237,13 @@ impl ZoneBuilder for MainZoneBuilder {
 
         for _ in 0..items_number {
             for _ in 0..MAX_SPAWN_TENTATIVES {
-                let x = Roll::dice(1, MAP_WIDTH - 2) as f32;
-                let y = Roll::dice(1, MAP_HEIGHT - 2) as f32;
-                let index = Zone::get_index_from_xy_f32(x, y);
+                let x = Roll::dice(1, zone.width - 2) as f32;
+                let y = Roll::dice(1, zone.height - 2) as f32;
+                let index = zone.get_index_from_xy_f32(x, y);
 
                 // avoid walls, player and duplicate spawnpoints
                 if index != zone.player_spawn_point
-                    && zone.tiles[Zone::get_index_from_xy_f32(x, y)] == TileType::Floor
+                    && zone.tiles[zone.get_index_from_xy_f32(x, y)] == TileType::Floor
                     && zone.item_spawn_points.insert(index)
                 {
                     break;
@@ -189,13 +253,13 @@ impl ZoneBuilder for MainZoneBuilder {
 
         for _ in 0..fauna_number {
             for _ in 0..MAX_SPAWN_TENTATIVES {
-                let x = Roll::dice(1, MAP_WIDTH - 2) as f32;
-                let y = Roll::dice(1, MAP_HEIGHT - 2) as f32;
-                let index = Zone::get_index_from_xy_f32(x, y);
+                let x = Roll::dice(1, zone.width - 2) as f32;
+                let y = Roll::dice(1, zone.height - 2) as f32;
+                let index = zone.get_index_from_xy_f32(x, y);
 
                 // avoid walls, player and duplicate spawnpoints
                 if index != zone.player_spawn_point
-                    && zone.tiles[Zone::get_index_from_xy_f32(x, y)] == TileType::Floor
+                    && zone.tiles[zone.get_index_from_xy_f32(x, y)] == TileType::Floor
                     && zone.fauna_spawn_points.insert(index)
                 {
                     break;
@@ -208,20 +272,55 @@ impl ZoneBuilder for MainZoneBuilder {
         let down_passage_index = first_crack_tiles[down_passage_roll];
         zone.tiles[down_passage_index] = TileType::DownPassage;
 
+        // Where a branch of the dungeon starts, a side passage opens along the same crack.
+        // If the crack has no other tile, it opens somewhere on the floor
+        if DungeonGraph::branch_starting_at(zone.branch, depth).is_some() {
+            let branch_candidates: Vec<usize> = first_crack_tiles
+                .iter()
+                .copied()
+                .filter(|&index| index != down_passage_index)
+                .collect();
+            let branch_passage_index = if branch_candidates.is_empty() {
+                let mut floor_index = zone.player_spawn_point;
+                while floor_index == zone.player_spawn_point
+                    || zone.tiles[floor_index] != TileType::Floor
+                    || zone.item_spawn_points.contains(&floor_index)
+                    || zone.monster_spawn_points.contains(&floor_index)
+                    || zone.fauna_spawn_points.contains(&floor_index)
+                {
+                    try_x = Roll::dice(1, zone.width - 2);
+                    try_y = Roll::dice(1, zone.height - 2);
+                    floor_index = zone.get_index_from_xy(&try_x, &try_y);
+                }
+                floor_index
+            } else {
+                let branch_passage_roll =
+                    Roll::dice(1, branch_candidates.len() as i32) as usize - 1;
+                branch_candidates[branch_passage_roll]
+            };
+            zone.tiles[branch_passage_index] = TileType::BranchPassage;
+        }
+
         // Add random cracks (the more deep we are, the more cracks we have)
         let cracks_number = max(Roll::dice(1, 4) + (depth as i32 / 2), MAX_CRACKS_IN_ZONE);
         for _ in 0..cracks_number {
-            CracksBuilder::build(&mut zone, ecs_world);
+            // Sometimes the floor along the crack collapses into a chasm
+            if Roll::d20() <= CHASM_CHANCE {
+                ChasmBuilder::build(zone, ecs_world);
+            } else {
+                CracksBuilder::build(zone, ecs_world);
+            }
         }
 
         // After Crystal Cave, a patch of carnivorous crystals may appear
         if depth > CRYSTAL_CAVE_DEPTH && Roll::d6() == 1 {
-            CrystalPatchBuilder::build(&mut zone, ecs_world);
+            CrystalPatchBuilder::build(zone, ecs_world);
         }
 
         // Add random gold mine
-        GoldMineBuilder::build(&mut zone, ecs_world);
+        GoldMineBuilder::build(zone, ecs_world);
 
-        zone
+        // Add hidden traps
+        TrapBuilder::build(zone, ecs_world);
     }
 }
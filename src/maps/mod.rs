@@ -3,18 +3,30 @@ use hecs::World;
 use crate::maps::zone::Zone;
 
 pub mod arena_zone_builder;
+pub mod cavern_zone_builder;
+pub mod chasm_builder;
+pub mod connectivity_checker;
 pub mod cracks_builder;
 pub mod crystal_cave_builder;
 pub mod crystal_patch_builder;
+pub mod dungeon_graph;
 pub mod dungeon_zone_builder;
+pub mod flooded_mine_builder;
+pub mod fungal_grotto_builder;
 pub mod gold_mine_builder;
+pub mod lake_builder;
+pub mod magma_river_builder;
 pub mod main_zone_builder;
 pub mod mushroom_field_builder;
+pub mod prefab_builder;
 pub mod river_builder;
+pub mod stonedust_monastery_builder;
 pub mod stonedust_procession_builder;
 pub mod stonedust_shrine_builder;
 pub mod test_zone_builder;
+pub mod trap_builder;
 pub mod zone;
+pub mod zone_builder_table;
 
 /// Trait for Zone Builders
 pub trait ZoneBuilder {
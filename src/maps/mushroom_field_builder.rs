@@ -3,7 +3,7 @@ use macroquad::math::Rect;
 
 use crate::{
     components::items::{ShopOwner, Tradable},
-    constants::{MAP_HEIGHT, MAP_WIDTH, MUSHROOM_EXCELLENT},
+    constants::MUSHROOM_EXCELLENT,
     maps::{
         ZoneFeatureBuilder,
         zone::{TileType, Zone},
@@ -24,8 +24,8 @@ impl ZoneFeatureBuilder for MushroomFieldBuilder {
         let mut tiles: Vec<usize> = Vec::new();
         //2 Create a potential fertilized space from 3x3 to 6x6
         let mut size = Roll::dice(1, SIZE_DICE) + SIZE_MODIFIER;
-        let mut x = Roll::dice(1, MAP_WIDTH - size) - 1;
-        let mut y = Roll::dice(1, MAP_HEIGHT - size) - 1;
+        let mut x = Roll::dice(1, zone.width - size) - 1;
+        let mut y = Roll::dice(1, zone.height - size) - 1;
         let mut field_rect = Rect::new_from_i32(x, y, size, size);
 
         for _ in 0..150 {
@@ -33,12 +33,12 @@ impl ZoneFeatureBuilder for MushroomFieldBuilder {
             let mut is_free = true;
             for y in field_rect.y as i32..(field_rect.y + field_rect.h) as i32 {
                 for x in field_rect.x as i32..(field_rect.x + field_rect.w) as i32 {
-                    if zone.tiles[Zone::get_index_from_xy(&x, &y)] != TileType::Floor {
+                    if zone.tiles[zone.get_index_from_xy(&x, &y)] != TileType::Floor {
                         is_free = false;
                         tiles.clear();
                         break;
                     } else {
-                        tiles.push(Zone::get_index_from_xy(&x, &y));
+                        tiles.push(zone.get_index_from_xy(&x, &y));
                     }
                 }
 
@@ -53,8 +53,8 @@ impl ZoneFeatureBuilder for MushroomFieldBuilder {
 
             // 2.2 get new size and rect for next iteration
             size = Roll::dice(1, 3) + 3;
-            x = Roll::dice(1, MAP_WIDTH - size);
-            y = Roll::dice(1, MAP_HEIGHT - size);
+            x = Roll::dice(1, zone.width - size);
+            y = Roll::dice(1, zone.height - size);
             field_rect = Rect::new_from_i32(x, y, size, size);
         }
 
@@ -64,14 +64,14 @@ impl ZoneFeatureBuilder for MushroomFieldBuilder {
             let mut counter = 0;
             let mut owner_opt: Option<Entity> = None;
             for &index in &tiles {
-                let (x, y) = Zone::get_xy_from_index(index);
+                let (x, y) = zone.get_xy_from_index(index);
 
                 counter += 1;
                 if owner_opt.is_none() && (counter >= size || Roll::dice(1, 4) == 1) {
                     // Guarantee an open space in the fence
                     owner_opt = Some(Spawn::moleman_farmer(ecs_world, x, y));
                 } else {
-                    let index = Zone::get_index_from_xy(&x, &y);
+                    let index = zone.get_index_from_xy(&x, &y);
                     zone.tiles[index] = TileType::MushroomField;
                     // Put mushrooms!
                     if Roll::dice(1, 4) == 1 {
@@ -87,6 +87,7 @@ impl ZoneFeatureBuilder for MushroomFieldBuilder {
                     ShopOwner {
                         shop_tiles: tiles.clone(),
                         wanted_items: vec![Tradable::Corpse],
+                        times_wronged: 0,
                     },
                 );
             } else {
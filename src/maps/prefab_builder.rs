@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use hecs::World;
+
+use crate::{
+    constants::{PREFAB_MIN_FLOOR_PERCENT, PREFAB_PLACEMENT_TENTATIVES},
+    maps::{
+        ZoneFeatureBuilder,
+        zone::{TileType, Zone},
+    },
+    spawning::spawner::Spawn,
+    utils::roll::Roll,
+};
+
+// Hand drawn templates, every file in assets/prefabs is bundled by the build script.
+// Each one can add its own legend entries to the default one:
+// `legend <char> tile <TileType>`, `legend <char> monster <name or random> [TileType]`,
+// `legend <char> item <name or random> [TileType]` and `legend <char> npc <name> [TileType]`.
+// Creatures and items stand on floor, unless another tile is given.
+// `depth <n>` is the first depth where the template can appear. The lines after `map` are the drawing
+include!(concat!(env!("OUT_DIR"), "/prefab_templates.rs"));
+
+/// What a character of a template stands for
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PrefabCell {
+    Keep,
+    Tile(TileType),
+    Monster(&'static str, TileType),
+    Item(&'static str, TileType),
+    Npc(&'static str, TileType),
+}
+
+/// A parsed template
+struct Prefab {
+    min_depth: u32,
+    cells: Vec<Vec<PrefabCell>>,
+}
+
+/// Stamps a random hand drawn template into the zone, rotated and mirrored
+pub struct PrefabBuilder {}
+
+impl ZoneFeatureBuilder for PrefabBuilder {
+    fn build(zone: &mut Zone, ecs_world: &mut World) -> Vec<usize> {
+        let prefabs: Vec<Prefab> = PREFAB_TEMPLATES
+            .iter()
+            .map(|template| PrefabBuilder::parse(template))
+            .filter(|prefab| prefab.min_depth <= zone.depth)
+            .collect();
+
+        if prefabs.is_empty() {
+            return Vec::new();
+        }
+
+        let prefab_roll = Roll::dice(1, prefabs.len() as i32) as usize - 1;
+        let mut cells = prefabs[prefab_roll].cells.clone();
+
+        // Same template, different look each time
+        for _ in 0..Roll::dice(1, 4) - 1 {
+            cells = PrefabBuilder::rotate(&cells);
+        }
+        if Roll::dice(1, 2) == 1 {
+            for row in cells.iter_mut() {
+                row.reverse();
+            }
+        }
+
+        let height = cells.len() as i32;
+        let width = cells.iter().map(|row| row.len()).max().unwrap_or(0) as i32;
+        if width > zone.width - 2 || height > zone.height - 2 {
+            return Vec::new();
+        }
+
+        for _ in 0..PREFAB_PLACEMENT_TENTATIVES {
+            let origin_x = Roll::dice(1, zone.width - 1 - width);
+            let origin_y = Roll::dice(1, zone.height - 1 - height);
+
+            if PrefabBuilder::is_valid_location(zone, &cells, origin_x, origin_y) {
+                return PrefabBuilder::stamp(zone, ecs_world, &cells, origin_x, origin_y);
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+impl PrefabBuilder {
+    /// Read a template. Malformed templates are a design error, so they panic
+    fn parse(template: &'static str) -> Prefab {
+        let mut legend = PrefabBuilder::default_legend();
+        let mut min_depth = 1;
+        let mut lines = template.lines();
+
+        for line in lines.by_ref() {
+            let words: Vec<&'static str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => {}
+                [comment, ..] if comment.starts_with('#') => {}
+                ["depth", value] => {
+                    min_depth = value
+                        .parse()
+                        .unwrap_or_else(|_| panic!("Invalid prefab depth {}", value));
+                }
+                ["legend", character, kind, name, tile_name @ ..] if tile_name.len() <= 1 => {
+                    let character = character
+                        .chars()
+                        .next()
+                        .expect("Prefab legend entry needs a character");
+                    let tile = tile_name.first().map_or(TileType::Floor, |tile_name| {
+                        PrefabBuilder::get_tile_type(tile_name)
+                    });
+                    let cell = match *kind {
+                        "tile" if tile_name.is_empty() => {
+                            PrefabCell::Tile(PrefabBuilder::get_tile_type(name))
+                        }
+                        "monster" => PrefabCell::Monster(*name, tile),
+                        "item" => PrefabCell::Item(*name, tile),
+                        "npc" => PrefabCell::Npc(*name, tile),
+                        _ => panic!("Invalid prefab legend entry {}", line),
+                    };
+                    legend.insert(character, cell);
+                }
+                ["map"] => break,
+                _ => panic!("Invalid prefab line {}", line),
+            }
+        }
+
+        let cells = lines
+            .map(|line| {
+                line.chars()
+                    .map(|character| {
+                        *legend.get(&character).unwrap_or_else(|| {
+                            panic!("Prefab character {} not in legend", character)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Prefab { min_depth, cells }
+    }
+
+    /// Characters every template can use without declaring them
+    fn default_legend() -> HashMap<char, PrefabCell> {
+        HashMap::from([
+            (' ', PrefabCell::Keep),
+            ('#', PrefabCell::Tile(TileType::Wall)),
+            ('.', PrefabCell::Tile(TileType::Floor)),
+            ('B', PrefabCell::Tile(TileType::BrickWall)),
+            ('_', PrefabCell::Tile(TileType::StoneFloor)),
+            ('~', PrefabCell::Tile(TileType::Water)),
+            ('+', PrefabCell::Tile(TileType::ClosedDoor)),
+            ('L', PrefabCell::Tile(TileType::LockedDoor)),
+            ('*', PrefabCell::Tile(TileType::Brazier)),
+            ('C', PrefabCell::Tile(TileType::CarvedStone)),
+            ('c', PrefabCell::Tile(TileType::CrackedWall)),
+            ('G', PrefabCell::Tile(TileType::GoldMine)),
+            ('M', PrefabCell::Monster("random", TileType::Floor)),
+            ('i', PrefabCell::Item("random", TileType::Floor)),
+        ])
+    }
+
+    fn get_tile_type(name: &str) -> TileType {
+        match name {
+            "Floor" => TileType::Floor,
+            "Wall" => TileType::Wall,
+            "Brazier" => TileType::Brazier,
+            "Water" => TileType::Water,
+            "CrackedWall" => TileType::CrackedWall,
+            "MushroomField" => TileType::MushroomField,
+            "FieldFence" => TileType::FieldFence,
+            "GoldMine" => TileType::GoldMine,
+            "BrickWall" => TileType::BrickWall,
+            "StoneFloor" => TileType::StoneFloor,
+            "MiniCrystal" => TileType::MiniCrystal,
+            "LittleCrystal" => TileType::LittleCrystal,
+            "MediumCrystal" => TileType::MediumCrystal,
+            "BigCrystal" => TileType::BigCrystal,
+            "CarvedStone" => TileType::CarvedStone,
+            "ClosedDoor" => TileType::ClosedDoor,
+            "LockedDoor" => TileType::LockedDoor,
+            "Chasm" => TileType::Chasm,
+            "Lava" => TileType::Lava,
+            "LavaCrust" => TileType::LavaCrust,
+            "HotRock" => TileType::HotRock,
+            "DeepWater" => TileType::DeepWater,
+            _ => panic!("Invalid prefab tile {}", name),
+        }
+    }
+
+    /// Rotate a template clockwise. Rows shorter than the longest one are padded with untouched tiles
+    fn rotate(cells: &[Vec<PrefabCell>]) -> Vec<Vec<PrefabCell>> {
+        let height = cells.len();
+        let width = cells.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        (0..width)
+            .map(|x| {
+                (0..height)
+                    .rev()
+                    .map(|y| cells[y].get(x).copied().unwrap_or(PrefabCell::Keep))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The template must not cover other special places, and must lie mostly on open ground
+    fn is_valid_location(
+        zone: &Zone,
+        cells: &[Vec<PrefabCell>],
+        origin_x: i32,
+        origin_y: i32,
+    ) -> bool {
+        let mut footprint = 0;
+        let mut floor_count = 0;
+
+        for (y, row) in cells.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if *cell == PrefabCell::Keep {
+                    continue;
+                }
+                let index = zone.get_index_from_xy(&(origin_x + x as i32), &(origin_y + y as i32));
+                match zone.tiles[index] {
+                    TileType::Floor => floor_count += 1,
+                    TileType::Wall | TileType::CrackedWall | TileType::Water => {}
+                    _ => return false,
+                }
+                if index == zone.player_spawn_point {
+                    return false;
+                }
+                footprint += 1;
+            }
+        }
+
+        footprint > 0 && floor_count * 100 >= footprint * PREFAB_MIN_FLOOR_PERCENT
+    }
+
+    /// Change the tiles of the zone and spawn what the template asks for
+    fn stamp(
+        zone: &mut Zone,
+        ecs_world: &mut World,
+        cells: &[Vec<PrefabCell>],
+        origin_x: i32,
+        origin_y: i32,
+    ) -> Vec<usize> {
+        let mut stamped_tiles = Vec::new();
+
+        for (y, row) in cells.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let (tile_x, tile_y) = (origin_x + x as i32, origin_y + y as i32);
+                let index = zone.get_index_from_xy(&tile_x, &tile_y);
+
+                match *cell {
+                    PrefabCell::Keep => continue,
+                    PrefabCell::Tile(tile) => zone.tiles[index] = tile,
+                    PrefabCell::Monster(name, tile) | PrefabCell::Npc(name, tile) => {
+                        zone.tiles[index] = tile;
+                        if name == "random" {
+                            zone.monster_spawn_points.insert(index);
+                        } else {
+                            PrefabBuilder::spawn_creature(ecs_world, name, tile_x, tile_y);
+                        }
+                    }
+                    PrefabCell::Item(name, tile) => {
+                        zone.tiles[index] = tile;
+                        if name == "random" {
+                            zone.item_spawn_points.insert(index);
+                        } else {
+                            PrefabBuilder::spawn_item(ecs_world, name, tile_x, tile_y);
+                        }
+                    }
+                }
+                stamped_tiles.push(index);
+            }
+        }
+
+        stamped_tiles
+    }
+
+    fn spawn_creature(ecs_world: &mut World, name: &str, x: i32, y: i32) {
+        match name {
+            "deep_one" => Spawn::deep_one(ecs_world, x, y),
+            "abyssal_one" => Spawn::abyssal_one(ecs_world, x, y),
+            "living_fossil" => Spawn::living_fossil(ecs_world, x, y),
+            "scorpion" => Spawn::scorpion(ecs_world, x, y),
+            "centipede" => Spawn::centipede(ecs_world, x, y),
+            "moleman" => Spawn::moleman(ecs_world, x, y),
+            "living_dead" => Spawn::living_dead(ecs_world, x, y),
+            "darkling" => Spawn::darkling(ecs_world, x, y),
+            "naked_refugee" => Spawn::naked_refugee(ecs_world, x, y),
+            "gremlin" => {
+                let _ = Spawn::gremlin(ecs_world, x, y);
+            }
+            "giant_cockroach" => {
+                let _ = Spawn::giant_cockroach(ecs_world, x, y);
+            }
+            "refugee" => {
+                let _ = Spawn::refugee(ecs_world, x, y);
+            }
+            "stonedust_cultist" => {
+                let _ = Spawn::stonedust_cultist(ecs_world, x, y);
+            }
+            "stonedust_acolyte" => {
+                let _ = Spawn::stonedust_acolyte(ecs_world, x, y);
+            }
+            _ => panic!("Invalid prefab creature {}", name),
+        }
+    }
+
+    fn spawn_item(ecs_world: &mut World, name: &str, x: i32, y: i32) {
+        match name {
+            "curing_paste" => Spawn::curing_paste(ecs_world, x, y),
+            "flask_of_oil" => Spawn::flask_of_oil(ecs_world, x, y),
+            "shiv" => Spawn::shiv(ecs_world, x, y),
+            "breastplate" => Spawn::breastplate(ecs_world, x, y),
+            "flask_of_water" => {
+                let _ = Spawn::flask_of_water(ecs_world, x, y);
+            }
+            "ration" => {
+                let _ = Spawn::ration(ecs_world, x, y);
+            }
+            "lantern" => {
+                let _ = Spawn::lantern(ecs_world, x, y);
+            }
+            "wand" => {
+                let _ = Spawn::wand(ecs_world, x, y);
+            }
+            "pickaxe" => {
+                let _ = Spawn::pickaxe(ecs_world, x, y);
+            }
+            "helmet" => {
+                let _ = Spawn::helmet(ecs_world, x, y);
+            }
+            "raw_gold" => {
+                let _ = Spawn::raw_gold(ecs_world, x, y);
+            }
+            _ => panic!("Invalid prefab item {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hecs::World;
+
+    use crate::constants::{MAP_HEIGHT, MAP_WIDTH};
+
+    use super::{PREFAB_TEMPLATES, PrefabBuilder, PrefabCell};
+
+    /// Parsing panics on unknown glyphs and legend entries, so every bundled template is read here
+    #[test]
+    fn bundled_templates_are_valid() {
+        for (template_index, template) in PREFAB_TEMPLATES.iter().enumerate() {
+            let prefab = PrefabBuilder::parse(template);
+            let height = prefab.cells.len();
+            assert!(height > 0, "template {template_index} has no map");
+
+            let width = prefab.cells[0].len();
+            for (y, row) in prefab.cells.iter().enumerate() {
+                assert_eq!(
+                    row.len(),
+                    width,
+                    "template {template_index}: row {y} is not as long as the first one"
+                );
+            }
+
+            // Templates are rotated, so they must fit inside the zone borders both ways
+            let max_side = (MAP_WIDTH.min(MAP_HEIGHT) - 2) as usize;
+            assert!(
+                width <= max_side && height <= max_side,
+                "template {template_index} is {width}x{height}, too big for the zone"
+            );
+
+            // Named creatures and items must be known to the spawner
+            let mut ecs_world = World::new();
+            for (y, row) in prefab.cells.iter().enumerate() {
+                for (x, cell) in row.iter().enumerate() {
+                    match *cell {
+                        PrefabCell::Monster(name, _) | PrefabCell::Npc(name, _)
+                            if name != "random" =>
+                        {
+                            PrefabBuilder::spawn_creature(&mut ecs_world, name, x as i32, y as i32)
+                        }
+                        PrefabCell::Item(name, _) if name != "random" => {
+                            PrefabBuilder::spawn_item(&mut ecs_world, name, x as i32, y as i32)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
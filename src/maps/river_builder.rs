@@ -1,7 +1,6 @@
 use hecs::World;
 
 use crate::{
-    constants::{MAP_HEIGHT, MAP_WIDTH},
     maps::{
         ZoneFeatureBuilder, ZoneFeatureBuilderOrigin,
         zone::{TileType, Zone},
@@ -20,17 +19,17 @@ impl ZoneFeatureBuilder for RiverBuilder {
         //1 - select a start point with X or Y = 1
         let mut current_position = (1, 1);
         if Roll::d100() <= 50 {
-            current_position.1 = Roll::dice(1, MAP_HEIGHT - 1) + 1;
+            current_position.1 = Roll::dice(1, zone.height - 1) + 1;
             origin = ZoneFeatureBuilderOrigin::Left;
         } else {
-            current_position.0 = Roll::dice(1, MAP_WIDTH - 1) + 1;
+            current_position.0 = Roll::dice(1, zone.width - 1) + 1;
             origin = ZoneFeatureBuilderOrigin::Top;
         }
 
-        //2 - if point is X = MAP_WIDTH-1 or Y = MAP_HEIGHT-1, stop
-        while current_position.0 < MAP_WIDTH - 1 && current_position.1 < MAP_HEIGHT - 1 {
+        //2 - if point is X = zone.width-1 or Y = zone.height-1, stop
+        while current_position.0 < zone.width - 1 && current_position.1 < zone.height - 1 {
             //3 - draw a water tile there
-            let index = Zone::get_index_from_xy(&current_position.0, &current_position.1);
+            let index = zone.get_index_from_xy(&current_position.0, &current_position.1);
             zone.tiles[index] = TileType::Water;
             river_tiles.push(index);
 
@@ -55,7 +54,7 @@ impl ZoneFeatureBuilder for RiverBuilder {
             }
 
             // Avoid boundaries, or else skip iteration
-            if dest_x <= 1 || dest_x >= MAP_WIDTH || dest_y <= 1 || dest_y >= MAP_HEIGHT {
+            if dest_x <= 1 || dest_x >= zone.width || dest_y <= 1 || dest_y >= zone.height {
                 println!("RiverBuilder - Try again");
                 continue;
             }
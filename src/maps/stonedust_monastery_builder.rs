@@ -0,0 +1,67 @@
+use hecs::World;
+use macroquad::math::Rect;
+
+use crate::{
+    constants::*,
+    maps::{
+        ZoneBuilder, ZoneFeatureBuilder,
+        dungeon_graph::Branch,
+        dungeon_zone_builder::DungeonZoneBuilder,
+        main_zone_builder::MainZoneBuilder,
+        stonedust_procession_builder::StonedustProcessionBuilder,
+        stonedust_shrine_builder::StonedustShrineBuilder,
+        zone::{TileType, Zone},
+    },
+    utils::roll::Roll,
+};
+
+/// Builds the monastery of the Stonedust cult: brick cells and halls, with shrines and processions
+pub struct StonedustMonasteryBuilder {}
+
+impl ZoneBuilder for StonedustMonasteryBuilder {
+    fn build(depth: u32, ecs_world: &mut World) -> Zone {
+        let mut zone = Zone::new(depth, TileType::Wall, MAP_WIDTH, MAP_HEIGHT);
+        zone.branch = Branch::StonedustMonastery;
+
+        const MAX_SHRINES: i32 = 2;
+
+        DungeonZoneBuilder::carve_rooms(&mut zone);
+
+        // The monks lined their halls with bricks, and paved them
+        for x in 1..zone.width - 1 {
+            for y in 1..zone.height - 1 {
+                let index = zone.get_index_from_xy(&x, &y);
+                if zone.tiles[index] == TileType::Wall
+                    && zone
+                        .rooms
+                        .iter()
+                        .any(|room| StonedustMonasteryBuilder::is_next_to_room(room, x, y))
+                {
+                    zone.tiles[index] = TileType::BrickWall;
+                }
+            }
+        }
+
+        for _ in 0..Roll::dice(1, MAX_SHRINES) {
+            let shrine_tiles = StonedustShrineBuilder::build(&mut zone, ecs_world);
+            zone.special_rooms.push(("stonedust shrine", shrine_tiles));
+        }
+        let procession_tiles = StonedustProcessionBuilder::build(&mut zone, ecs_world);
+        zone.special_rooms
+            .push(("stonedust procession", procession_tiles));
+
+        MainZoneBuilder::add_features(&mut zone, ecs_world);
+
+        zone
+    }
+}
+
+impl StonedustMonasteryBuilder {
+    /// Return true if a tile is on the border of a room, the corners included
+    fn is_next_to_room(room: &Rect, x: i32, y: i32) -> bool {
+        let (room_x, room_y) = (room.x as i32, room.y as i32);
+        let (room_w, room_h) = (room.w as i32, room.h as i32);
+
+        (room_x..=room_x + room_w).contains(&x) && (room_y..=room_y + room_h).contains(&y)
+    }
+}
@@ -4,7 +4,6 @@ use hecs::World;
 
 use crate::{
     components::monster::{SnakeBody, SnakeHead},
-    constants::{MAP_HEIGHT, MAP_WIDTH},
     maps::{ZoneFeatureBuilder, zone::Zone},
     spawning::spawner::Spawn,
     utils::roll::Roll,
@@ -16,8 +15,8 @@ pub struct StonedustProcessionBuilder {}
 impl ZoneFeatureBuilder for StonedustProcessionBuilder {
     fn build(zone: &mut Zone, ecs_world: &mut World) -> Vec<usize> {
         // 1. Select a random location for the acolyte
-        let x = Roll::dice(1, MAP_WIDTH - 1);
-        let y = Roll::dice(1, MAP_HEIGHT - 1);
+        let x = Roll::dice(1, zone.width - 1);
+        let y = Roll::dice(1, zone.height - 1);
         let acolyte = Spawn::stonedust_acolyte(ecs_world, x, y);
 
         //2. Generate procession body
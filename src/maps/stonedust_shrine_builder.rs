@@ -3,7 +3,6 @@ use macroquad::math::Rect;
 
 use crate::{
     components::items::{ShopOwner, Tradable},
-    constants::{MAP_HEIGHT, MAP_WIDTH},
     maps::{
         ZoneFeatureBuilder,
         zone::{TileType, Zone},
@@ -21,8 +20,8 @@ impl ZoneFeatureBuilder for StonedustShrineBuilder {
         let mut tiles: Vec<usize> = Vec::new();
         //2 Create a potential fertilized space from 3x3 to 6x6
         let size = 4;
-        let mut x = Roll::dice(1, MAP_WIDTH - size) - 1;
-        let mut y = Roll::dice(1, MAP_HEIGHT - size) - 1;
+        let mut x = Roll::dice(1, zone.width - size) - 1;
+        let mut y = Roll::dice(1, zone.height - size) - 1;
         let mut field_rect = Rect::new_from_i32(x, y, size, size);
 
         for _ in 0..150 {
@@ -30,12 +29,12 @@ impl ZoneFeatureBuilder for StonedustShrineBuilder {
             let mut is_free = true;
             for y in field_rect.y as i32..(field_rect.y + field_rect.h) as i32 {
                 for x in field_rect.x as i32..(field_rect.x + field_rect.w) as i32 {
-                    if zone.tiles[Zone::get_index_from_xy(&x, &y)] != TileType::Floor {
+                    if zone.tiles[zone.get_index_from_xy(&x, &y)] != TileType::Floor {
                         is_free = false;
                         tiles.clear();
                         break;
                     } else {
-                        tiles.push(Zone::get_index_from_xy(&x, &y));
+                        tiles.push(zone.get_index_from_xy(&x, &y));
                     }
                 }
 
@@ -49,8 +48,8 @@ impl ZoneFeatureBuilder for StonedustShrineBuilder {
             }
 
             // 2.2  next iteration
-            x = Roll::dice(1, MAP_WIDTH - size);
-            y = Roll::dice(1, MAP_HEIGHT - size);
+            x = Roll::dice(1, zone.width - size);
+            y = Roll::dice(1, zone.height - size);
             field_rect = Rect::new_from_i32(x, y, size, size);
         }
 
@@ -61,7 +60,7 @@ impl ZoneFeatureBuilder for StonedustShrineBuilder {
             let mut counter = 0;
             let mut owner_opt: Option<Entity> = None;
             for &index in &tiles {
-                let (x, y) = Zone::get_xy_from_index(index);
+                let (x, y) = zone.get_xy_from_index(index);
 
                 // Check if the tile is on the border, must be a fence
                 if x == field_rect.x as i32
@@ -75,16 +74,18 @@ impl ZoneFeatureBuilder for StonedustShrineBuilder {
                         || (((x - field_rect.x as i32) % (size - 1) == 0)
                             && ((y - field_rect.y as i32) % (size - 1) == 0))
                     {
-                        zone.tiles[Zone::get_index_from_xy(&x, &y)] = TileType::BrickWall;
+                        let tile_index = zone.get_index_from_xy(&x, &y);
+                        zone.tiles[tile_index] = TileType::BrickWall;
                     } else if !has_opening && (counter >= (size) || Roll::dice(1, 4) == 1) {
                         // Guarantee an open space in the fence
                         has_opening = true;
                         owner_opt = Some(Spawn::stonedust_abbot(ecs_world, x, y));
                     } else {
-                        zone.tiles[Zone::get_index_from_xy(&x, &y)] = TileType::BrickWall;
+                        let tile_index = zone.get_index_from_xy(&x, &y);
+                        zone.tiles[tile_index] = TileType::BrickWall;
                     }
                 } else {
-                    let index = Zone::get_index_from_xy(&x, &y);
+                    let index = zone.get_index_from_xy(&x, &y);
                     zone.tiles[index] = TileType::StoneFloor;
                     // Put curing paste!
                     if Roll::dice(1, 2) == 1 {
@@ -93,6 +94,22 @@ impl ZoneFeatureBuilder for StonedustShrineBuilder {
                 }
             }
 
+            // Put a door on one of the walls that are not in a corner, so the shrine can be closed
+            let door_candidates: Vec<usize> = tiles
+                .iter()
+                .copied()
+                .filter(|&index| {
+                    let (x, y) = zone.get_xy_from_index(index);
+                    zone.tiles[index] == TileType::BrickWall
+                        && ((x - field_rect.x as i32) % (size - 1) != 0
+                            || (y - field_rect.y as i32) % (size - 1) != 0)
+                })
+                .collect();
+            if !door_candidates.is_empty() {
+                let door_roll = Roll::dice(1, door_candidates.len() as i32) as usize - 1;
+                zone.tiles[door_candidates[door_roll]] = TileType::ClosedDoor;
+            }
+
             // Insert Mushroom field into ECS to be used as shop
             if let Some(owner) = owner_opt {
                 let _ = ecs_world.insert(
@@ -100,6 +117,7 @@ impl ZoneFeatureBuilder for StonedustShrineBuilder {
                     (ShopOwner {
                         shop_tiles: tiles.clone(),
                         wanted_items: vec![Tradable::RawGold],
+                        times_wronged: 0,
                     },),
                 );
             } else {
@@ -20,51 +20,59 @@ pub struct TestZoneBuilder {}
 impl ZoneBuilder for TestZoneBuilder {
     /// Create new dungeon zone (needed?)
     fn build(depth: u32, ecs_world: &mut World) -> Zone {
-        let mut zone = Zone::new(depth, TileType::Wall);
+        let mut zone = Zone::new(depth, TileType::Wall, VIEWPORT_WIDTH, VIEWPORT_HEIGHT);
 
         // Create boundaries
-        for x in 0..MAP_WIDTH {
-            for y in 0..MAP_HEIGHT {
-                if x != 0 && y != 0 && x != MAP_WIDTH - 1 && y != MAP_HEIGHT - 1 {
-                    let index = Zone::get_index_from_xy(&x, &y);
+        for x in 0..VIEWPORT_WIDTH {
+            for y in 0..VIEWPORT_HEIGHT {
+                if x != 0 && y != 0 && x != VIEWPORT_WIDTH - 1 && y != VIEWPORT_HEIGHT - 1 {
+                    let index = zone.get_index_from_xy(&x, &y);
                     zone.tiles[index] = TileType::Floor
                 }
             }
         }
 
         //Straight river
-        for y in 1..MAP_HEIGHT - 1 {
-            zone.tiles[Zone::get_index_from_xy(&10, &y)] = TileType::Water;
+        for y in 1..VIEWPORT_HEIGHT - 1 {
+            let tile_index = zone.get_index_from_xy(&10, &y);
+            zone.tiles[tile_index] = TileType::Water;
         }
         //Lake
-        for x in 0..MAP_WIDTH {
-            for y in 0..MAP_HEIGHT {
-                let distance = Utils::distance(&10, &x, &(MAP_HEIGHT / 2), &y);
+        for x in 0..VIEWPORT_WIDTH {
+            for y in 0..VIEWPORT_HEIGHT {
+                let distance = Utils::distance(&10, &x, &(VIEWPORT_HEIGHT / 2), &y);
 
                 if distance < 4.0 {
-                    zone.tiles[Zone::get_index_from_xy(&x, &y)] = TileType::Water;
+                    let tile_index = zone.get_index_from_xy(&x, &y);
+                    zone.tiles[tile_index] = TileType::Water;
                 }
             }
         }
 
         //Straight crack
-        for y in 3..MAP_HEIGHT - 3 {
-            zone.tiles[Zone::get_index_from_xy(&40, &y)] = TileType::CrackedWall;
+        for y in 3..VIEWPORT_HEIGHT - 3 {
+            let tile_index = zone.get_index_from_xy(&40, &y);
+            zone.tiles[tile_index] = TileType::CrackedWall;
         }
 
         // Populate water and blocked tiles here, needed for correct spawning
         zone.populate_blocked();
         zone.populate_water();
 
-        zone.player_spawn_point = Zone::get_index_from_xy(&(MAP_WIDTH / 2), &(MAP_HEIGHT / 2));
+        zone.player_spawn_point =
+            zone.get_index_from_xy(&(VIEWPORT_WIDTH / 2), &(VIEWPORT_HEIGHT / 2));
 
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.25, MAP_HEIGHT_F32 * 0.25)] =
+        zone.tiles
+            [zone.get_index_from_xy_f32(VIEWPORT_WIDTH_F32 * 0.25, VIEWPORT_HEIGHT_F32 * 0.25)] =
             TileType::Brazier;
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.75, MAP_HEIGHT_F32 * 0.25)] =
+        zone.tiles
+            [zone.get_index_from_xy_f32(VIEWPORT_WIDTH_F32 * 0.75, VIEWPORT_HEIGHT_F32 * 0.25)] =
             TileType::Brazier;
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.25, MAP_HEIGHT_F32 * 0.75)] =
+        zone.tiles
+            [zone.get_index_from_xy_f32(VIEWPORT_WIDTH_F32 * 0.25, VIEWPORT_HEIGHT_F32 * 0.75)] =
             TileType::Brazier;
-        zone.tiles[Zone::get_index_from_xy_f32(MAP_WIDTH_F32 * 0.75, MAP_HEIGHT_F32 * 0.75)] =
+        zone.tiles
+            [zone.get_index_from_xy_f32(VIEWPORT_WIDTH_F32 * 0.75, VIEWPORT_HEIGHT_F32 * 0.75)] =
             TileType::Brazier;
 
         //Mushroom Field
@@ -76,13 +84,13 @@ impl ZoneBuilder for TestZoneBuilder {
 
         for _ in 0..monster_number {
             for _ in 0..MAX_SPAWN_TENTATIVES {
-                let x = Roll::dice(1, MAP_WIDTH - 2) as f32;
-                let y = Roll::dice(1, MAP_HEIGHT - 2) as f32;
-                let index = Zone::get_index_from_xy_f32(x, y);
+                let x = Roll::dice(1, VIEWPORT_WIDTH - 2) as f32;
+                let y = Roll::dice(1, VIEWPORT_HEIGHT - 2) as f32;
+                let index = zone.get_index_from_xy_f32(x, y);
 
                 // avoid walls, player and duplicate spawnpoints
                 if index != zone.player_spawn_point
-                    && zone.tiles[Zone::get_index_from_xy_f32(x, y)] != TileType::Wall
+                    && zone.tiles[zone.get_index_from_xy_f32(x, y)] != TileType::Wall
                     && zone.monster_spawn_points.insert(index)
                 {
                     break;
@@ -92,9 +100,9 @@ impl ZoneBuilder for TestZoneBuilder {
 
         for _ in 0..items_number {
             for _ in 0..MAX_SPAWN_TENTATIVES {
-                let x = Roll::dice(1, MAP_WIDTH - 3) as f32 + 1.0;
-                let y = Roll::dice(1, MAP_HEIGHT - 3) as f32 + 1.0;
-                let index = Zone::get_index_from_xy_f32(x, y);
+                let x = Roll::dice(1, VIEWPORT_WIDTH - 3) as f32 + 1.0;
+                let y = Roll::dice(1, VIEWPORT_HEIGHT - 3) as f32 + 1.0;
+                let index = zone.get_index_from_xy_f32(x, y);
 
                 // avoid duplicate spawnpoints
                 if zone.tiles[index] != TileType::Floor {
@@ -111,7 +119,8 @@ impl ZoneBuilder for TestZoneBuilder {
         StonedustProcessionBuilder::build(&mut zone, ecs_world);
         StonedustShrineBuilder::build(&mut zone, ecs_world);
 
-        let sign_index = Zone::get_index_from_xy(&((MAP_WIDTH / 2) - 5), &(MAP_HEIGHT / 2));
+        let sign_index =
+            zone.get_index_from_xy(&((VIEWPORT_WIDTH / 2) - 5), &(VIEWPORT_HEIGHT / 2));
         zone.tiles[sign_index] = TileType::CarvedStone;
 
         zone
@@ -0,0 +1,55 @@
+use std::cmp::max;
+
+use hecs::World;
+
+use crate::{
+    components::common::TrapType,
+    constants::{MAX_SPAWN_TENTATIVES, MAX_TRAPS_IN_ZONE},
+    maps::{
+        ZoneFeatureBuilder,
+        zone::{TileType, Zone},
+    },
+    spawning::spawner::Spawn,
+    utils::roll::Roll,
+};
+
+///Hidden Traps Builder
+pub struct TrapBuilder {}
+
+impl ZoneFeatureBuilder for TrapBuilder {
+    fn build(zone: &mut Zone, ecs_world: &mut World) -> Vec<usize> {
+        let mut trap_tiles = Vec::new();
+        // the more deep we are, the more traps we have
+        let traps_number = max(
+            0,
+            Roll::dice(1, MAX_TRAPS_IN_ZONE) + (zone.depth as i32 / 3) - 1,
+        );
+
+        for _ in 0..traps_number {
+            for _ in 0..MAX_SPAWN_TENTATIVES {
+                let x = Roll::dice(1, zone.width - 2);
+                let y = Roll::dice(1, zone.height - 2);
+                let index = zone.get_index_from_xy(&x, &y);
+
+                // avoid walls, player and other traps
+                if index != zone.player_spawn_point
+                    && zone.tiles[index] == TileType::Floor
+                    && !trap_tiles.contains(&index)
+                {
+                    let trap_type = match Roll::d20() {
+                        (1..=5) => TrapType::Pit,
+                        (6..=9) => TrapType::SpikePlate,
+                        (10..=13) => TrapType::FallingRocks,
+                        (14..=16) => TrapType::AlarmTripwire,
+                        _ => TrapType::GasVent,
+                    };
+                    Spawn::trap(ecs_world, x, y, trap_type);
+                    trap_tiles.push(index);
+                    break;
+                }
+            }
+        }
+
+        trap_tiles
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 use hecs::Entity;
 use macroquad::math::Rect;
 
-use crate::constants::{MAP_HEIGHT, MAP_WIDTH};
+use crate::{constants::*, maps::dungeon_graph::Branch};
 
 #[allow(dead_code)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -26,6 +26,17 @@ pub enum TileType {
     TripleGoldLock(f32),
     CarvedStone,
     DisembodiedEntity,
+    ClosedDoor,
+    OpenDoor,
+    LockedDoor,
+    BrokenDoor,
+    Chasm,
+    Pit,
+    Lava,
+    LavaCrust,
+    HotRock,
+    DeepWater,
+    BranchPassage,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub enum DecalType {
@@ -34,47 +45,127 @@ pub enum DecalType {
     Slime,
     Acid,
     Filth,
+    Oil,
+    Ash,
 }
 
-/// Zone Struct
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GasType {
+    Poison,
+    Spores,
+    Smoke,
+    Sulfur,
+    Steam,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NoiseType {
+    Digging,
+    Fighting,
+    Shooting,
+    BreakingDoor,
+    ClankingArmor,
+    Splashing,
+}
+
+impl NoiseType {
+    /// How far the noise can be heard
+    pub fn radius(&self) -> f32 {
+        match *self {
+            NoiseType::Digging => DIGGING_NOISE_RADIUS,
+            NoiseType::Fighting => FIGHTING_NOISE_RADIUS,
+            NoiseType::Shooting => SHOOTING_NOISE_RADIUS,
+            NoiseType::BreakingDoor => BREAKING_DOOR_NOISE_RADIUS,
+            NoiseType::ClankingArmor => CLANKING_ARMOR_NOISE_RADIUS,
+            NoiseType::Splashing => SPLASHING_NOISE_RADIUS,
+        }
+    }
+
+    /// How likely is the noise to be noticed, rolled against a d20
+    pub fn loudness(&self) -> i32 {
+        match *self {
+            NoiseType::Digging => DIGGING_NOISE_LOUDNESS,
+            NoiseType::Fighting => FIGHTING_NOISE_LOUDNESS,
+            NoiseType::Shooting => SHOOTING_NOISE_LOUDNESS,
+            NoiseType::BreakingDoor => BREAKING_DOOR_NOISE_LOUDNESS,
+            NoiseType::ClankingArmor => CLANKING_ARMOR_NOISE_LOUDNESS,
+            NoiseType::Splashing => SPLASHING_NOISE_LOUDNESS,
+        }
+    }
+}
+
+/// A noise made somewhere in the zone during this tick
+pub struct Noise {
+    pub x: i32,
+    pub y: i32,
+    pub noise_type: NoiseType,
+}
+
+/// Cloud of gas lingering on a tile
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gas {
+    pub gas_type: GasType,
+    pub density: u8,
+}
+
+/// Zone Struct. Tiles are stored row by row, with a stride equal to the width of the zone
 pub struct Zone {
-    pub tiles: [TileType; (MAP_WIDTH * MAP_HEIGHT) as usize],
+    pub width: i32,
+    pub height: i32,
+    pub tiles: Vec<TileType>,
     pub rooms: Vec<Rect>,
-    pub revealed_tiles: [bool; (MAP_WIDTH * MAP_HEIGHT) as usize],
-    pub visible_tiles: [bool; (MAP_WIDTH * MAP_HEIGHT) as usize],
-    pub lit_tiles: [bool; (MAP_WIDTH * MAP_HEIGHT) as usize],
-    pub blocked_tiles: [bool; (MAP_WIDTH * MAP_HEIGHT) as usize],
+    pub revealed_tiles: Vec<bool>,
+    pub visible_tiles: Vec<bool>,
+    pub lit_tiles: Vec<bool>,
+    pub lava_lit_tiles: Vec<bool>,
+    pub must_recalculate_lava_light: bool,
+    pub blocked_tiles: Vec<bool>,
     pub tile_content: Vec<Vec<Entity>>,
     pub decals_tiles: HashMap<usize, DecalType>,
+    pub gas_tiles: HashMap<usize, Gas>,
+    pub noises: Vec<Noise>,
+    pub last_noises: Vec<Noise>,
     pub depth: u32,
+    pub branch: Branch,
     pub player_spawn_point: usize,
     pub monster_spawn_points: HashSet<usize>,
     pub item_spawn_points: HashSet<usize>,
     pub fauna_spawn_points: HashSet<usize>,
-    pub water_tiles: [bool; (MAP_WIDTH * MAP_HEIGHT) as usize],
-    pub special_tile_counter: [u8; (MAP_WIDTH * MAP_HEIGHT) as usize],
+    pub water_tiles: Vec<bool>,
+    pub special_tile_counter: Vec<u8>,
+    pub special_rooms: Vec<(&'static str, Vec<usize>)>,
 }
 
 /// Zone Simplementations
 impl Zone {
     /// Create new empty zone
-    pub fn new(depth: u32, fill_tile: TileType) -> Zone {
+    pub fn new(depth: u32, fill_tile: TileType, width: i32, height: i32) -> Zone {
+        let size = (width * height) as usize;
         Zone {
-            tiles: [fill_tile; (MAP_WIDTH * MAP_HEIGHT) as usize],
+            width,
+            height,
+            tiles: vec![fill_tile; size],
             rooms: Vec::new(),
-            revealed_tiles: [false; (MAP_WIDTH * MAP_HEIGHT) as usize],
-            visible_tiles: [false; (MAP_WIDTH * MAP_HEIGHT) as usize],
-            lit_tiles: [false; (MAP_WIDTH * MAP_HEIGHT) as usize],
-            blocked_tiles: [false; (MAP_WIDTH * MAP_HEIGHT) as usize],
-            tile_content: vec![Vec::new(); (MAP_WIDTH * MAP_HEIGHT) as usize],
+            revealed_tiles: vec![false; size],
+            visible_tiles: vec![false; size],
+            lit_tiles: vec![false; size],
+            lava_lit_tiles: vec![false; size],
+            must_recalculate_lava_light: true,
+            blocked_tiles: vec![false; size],
+            tile_content: vec![Vec::new(); size],
             player_spawn_point: 0,
             depth,
+            branch: Branch::Main,
             decals_tiles: HashMap::new(),
+            gas_tiles: HashMap::new(),
+            noises: Vec::new(),
+            last_noises: Vec::new(),
             monster_spawn_points: HashSet::new(),
             item_spawn_points: HashSet::new(),
             fauna_spawn_points: HashSet::new(),
-            water_tiles: [false; (MAP_WIDTH * MAP_HEIGHT) as usize],
-            special_tile_counter: [0u8; (MAP_WIDTH * MAP_HEIGHT) as usize],
+            water_tiles: vec![false; size],
+            special_tile_counter: vec![0u8; size],
+            special_rooms: Vec::new(),
         }
     }
 
@@ -92,11 +183,17 @@ impl Zone {
             for y in y_pos - 1..=y_pos + 1 {
                 //Manhattan Distance
                 if !use_manhattan_distance || (x == x_pos || y == y_pos) {
-                    let index = Self::get_index_from_xy(&x, &y);
                     // Safety check is needed for zone borders
-                    if self.blocked_tiles.len() > index && !self.blocked_tiles[index] {
-                        // Aquatic monster can move only on water tiles
-                        if !only_water_tiles || self.water_tiles[index] {
+                    if !self.is_inside(&x, &y) {
+                        continue;
+                    }
+                    let index = self.get_index_from_xy(&x, &y);
+                    if !self.blocked_tiles[index] {
+                        // Aquatic monster can move only on water tiles,
+                        // while land dwellers keep away from deep water
+                        if (!only_water_tiles || self.water_tiles[index])
+                            && (only_water_tiles || !self.is_deep_water(index))
+                        {
                             adjacent_passable_tiles.push((x, y));
                         }
                     }
@@ -112,6 +209,7 @@ impl Zone {
         for (index, tile) in self.tiles.iter_mut().enumerate() {
             match tile {
                 TileType::DownPassage
+                | TileType::BranchPassage
                 | TileType::Floor
                 | TileType::Water
                 | TileType::StoneFloor
@@ -119,7 +217,12 @@ impl Zone {
                 | TileType::MiniCrystal
                 | TileType::LittleCrystal
                 | TileType::MediumCrystal
-                | TileType::DisembodiedEntity => self.blocked_tiles[index] = false,
+                | TileType::DisembodiedEntity
+                | TileType::OpenDoor
+                | TileType::BrokenDoor
+                | TileType::LavaCrust
+                | TileType::HotRock
+                | TileType::DeepWater => self.blocked_tiles[index] = false,
                 _ => self.blocked_tiles[index] = true,
             }
         }
@@ -128,18 +231,91 @@ impl Zone {
     /// Populates the water tiles vector appropiately (true = is water )
     pub fn populate_water(&mut self) {
         for (index, tile) in self.tiles.iter_mut().enumerate() {
-            self.water_tiles[index] = *tile == TileType::Water;
+            self.water_tiles[index] = *tile == TileType::Water || *tile == TileType::DeepWater;
         }
     }
 
     /// Return true if cannot see through a tile
     pub fn is_tile_opaque(&self, x: &i32, y: &i32) -> bool {
-        let index = Self::get_index_from_xy(x, y);
+        let index = self.get_index_from_xy(x, y);
+        self.is_tile_solid(x, y)
+            || self.is_door_closed(index)
+            || self
+                .gas_tiles
+                .get(&index)
+                .is_some_and(|gas| gas.density >= GAS_OPAQUE_DENSITY)
+    }
+
+    /// Return true if a tile is made of solid rock
+    pub fn is_tile_solid(&self, x: &i32, y: &i32) -> bool {
+        let index = self.get_index_from_xy(x, y);
         self.tiles[index] == TileType::Wall
             || self.tiles[index] == TileType::CrackedWall
             || self.tiles[index] == TileType::BrickWall
     }
 
+    /// Return true if there is a closed or locked door on a tile
+    pub fn is_door_closed(&self, index: usize) -> bool {
+        self.tiles[index] == TileType::ClosedDoor || self.tiles[index] == TileType::LockedDoor
+    }
+
+    /// Return true if there is a chasm or a pit on a tile, where anyone could fall
+    pub fn is_chasm(&self, index: usize) -> bool {
+        self.tiles[index] == TileType::Chasm || self.tiles[index] == TileType::Pit
+    }
+
+    /// Return true if there is molten lava on a tile, where anyone could be burnt to death
+    pub fn is_lava(&self, index: usize) -> bool {
+        self.tiles[index] == TileType::Lava
+    }
+
+    /// Return true if the water on a tile is too deep to wade, anyone not aquatic must swim
+    pub fn is_deep_water(&self, index: usize) -> bool {
+        self.tiles[index] == TileType::DeepWater
+    }
+
+    /// Gets which closed doors are adjacent from a x,y position
+    pub fn get_adjacent_closed_doors(
+        &self,
+        &x_pos: &i32,
+        &y_pos: &i32,
+        use_manhattan_distance: bool,
+    ) -> Vec<(i32, i32)> {
+        let mut adjacent_doors = Vec::new();
+
+        for x in x_pos - 1..=x_pos + 1 {
+            for y in y_pos - 1..=y_pos + 1 {
+                //Manhattan Distance
+                if !use_manhattan_distance || (x == x_pos || y == y_pos) {
+                    // Safety check is needed for zone borders
+                    if self.is_inside(&x, &y) && self.is_door_closed(self.get_index_from_xy(&x, &y))
+                    {
+                        adjacent_doors.push((x, y));
+                    }
+                }
+            }
+        }
+
+        adjacent_doors
+    }
+
+    /// Release some gas on a tile. Only the densest gas lingers there
+    pub fn add_gas(&mut self, index: usize, gas_type: GasType, density: u8) {
+        let density = density.min(MAX_GAS_DENSITY);
+        if self
+            .gas_tiles
+            .get(&index)
+            .is_none_or(|gas| gas.density < density)
+        {
+            self.gas_tiles.insert(index, Gas { gas_type, density });
+        }
+    }
+
+    /// Make some noise on a tile, monsters nearby could hear it
+    pub fn add_noise(&mut self, x: i32, y: i32, noise_type: NoiseType) {
+        self.noises.push(Noise { x, y, noise_type });
+    }
+
     /// Clears content index for this zone
     pub fn clear_content_index(&mut self) {
         for content in self.tile_content.iter_mut() {
@@ -168,26 +344,43 @@ impl Zone {
             TileType::TripleGoldLock(lock_to_open) => (*lock_to_open, 4.0),
             TileType::CarvedStone => (4.0, 2.0),
             TileType::DisembodiedEntity => (4.0, 1.0),
+            TileType::ClosedDoor => (0.0, 5.0),
+            TileType::OpenDoor => (1.0, 5.0),
+            TileType::LockedDoor => (2.0, 5.0),
+            TileType::BrokenDoor => (3.0, 5.0),
+            TileType::Chasm => (0.0, 2.0),
+            TileType::Pit => (4.0, 4.0),
+            TileType::Lava => (0.0, 7.0),
+            TileType::LavaCrust => (1.0, 7.0),
+            TileType::HotRock => (2.0, 7.0),
+            TileType::DeepWater => (3.0, 7.0),
+            TileType::BranchPassage => (4.0, 7.0),
         }
     }
 
+    /// Return true if a x,y position is inside the boundaries of the zone
+    #[inline]
+    pub fn is_inside(&self, x: &i32, y: &i32) -> bool {
+        *x >= 0 && *y >= 0 && *x < self.width && *y < self.height
+    }
+
     /// trasfroms x,y position into a vector index
     #[inline]
-    pub fn get_index_from_xy(x: &i32, y: &i32) -> usize {
-        ((y * MAP_WIDTH) + x) as usize
+    pub fn get_index_from_xy(&self, x: &i32, y: &i32) -> usize {
+        ((y * self.width) + x) as usize
     }
 
     /// trasfroms x,y position into a vector index, using usizes
     #[inline]
-    pub fn get_index_from_xy_f32(x: f32, y: f32) -> usize {
-        ((y as i32 * MAP_WIDTH) + x as i32) as usize
+    pub fn get_index_from_xy_f32(&self, x: f32, y: f32) -> usize {
+        ((y as i32 * self.width) + x as i32) as usize
     }
 
     /// trasfroms x,y position into a vector index, using usizes
     #[inline]
-    pub fn get_xy_from_index(index: usize) -> (i32, i32) {
-        let x = index as i32 % MAP_WIDTH;
-        let y = index as i32 / MAP_WIDTH;
+    pub fn get_xy_from_index(&self, index: usize) -> (i32, i32) {
+        let x = index as i32 % self.width;
+        let y = index as i32 / self.width;
         (x, y)
     }
 }
@@ -0,0 +1,61 @@
+use hecs::World;
+
+use crate::{
+    constants::CRYSTAL_CAVE_DEPTH,
+    maps::{
+        ZoneBuilder, cavern_zone_builder::CavernZoneBuilder,
+        connectivity_checker::ConnectivityChecker, crystal_cave_builder::CrystalCaveBuilder,
+        main_zone_builder::MainZoneBuilder, zone::Zone,
+    },
+    utils::roll::Roll,
+};
+
+pub type BuildZoneFn = fn(u32, &mut World) -> Zone;
+
+/// Builder, first and last depth where it can be used, and weight of its choice
+const ZONE_BUILDER_TABLE: [(BuildZoneFn, u32, u32, i32); 5] = [
+    (MainZoneBuilder::build, 1, CRYSTAL_CAVE_DEPTH - 1, 6),
+    (CavernZoneBuilder::build, 1, CRYSTAL_CAVE_DEPTH - 1, 2),
+    (
+        CrystalCaveBuilder::build,
+        CRYSTAL_CAVE_DEPTH,
+        CRYSTAL_CAVE_DEPTH,
+        1,
+    ),
+    (MainZoneBuilder::build, CRYSTAL_CAVE_DEPTH + 1, u32::MAX, 4),
+    (
+        CavernZoneBuilder::build,
+        CRYSTAL_CAVE_DEPTH + 1,
+        u32::MAX,
+        4,
+    ),
+];
+
+/// Chooses which builder creates the zone of a given depth
+pub struct ZoneBuilderTable {}
+
+impl ZoneBuilderTable {
+    /// Build a connected zone with a builder randomly picked among the ones available at this depth
+    pub fn build(depth: u32, ecs_world: &mut World) -> Zone {
+        let available: Vec<(BuildZoneFn, i32)> = ZONE_BUILDER_TABLE
+            .iter()
+            .filter(|(_, min_depth, max_depth, _)| (*min_depth..=*max_depth).contains(&depth))
+            .map(|&(builder, _, _, weight)| (builder, weight))
+            .collect();
+
+        let total_weight: i32 = available.iter().map(|(_, weight)| weight).sum();
+        if total_weight <= 0 {
+            return ConnectivityChecker::build_connected(MainZoneBuilder::build, depth, ecs_world);
+        }
+
+        let mut roll = Roll::dice(1, total_weight);
+        for (builder, weight) in available {
+            if roll <= weight {
+                return ConnectivityChecker::build_connected(builder, depth, ecs_world);
+            }
+            roll -= weight;
+        }
+
+        ConnectivityChecker::build_connected(MainZoneBuilder::build, depth, ecs_world)
+    }
+}
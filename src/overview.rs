@@ -0,0 +1,228 @@
+use hecs::World;
+use macroquad::{
+    color::{BLACK, Color, GREEN, LIGHTGRAY, WHITE},
+    input::{KeyCode, clear_input_queue, is_key_pressed},
+    shapes::draw_rectangle,
+    text::draw_text,
+};
+
+use crate::{
+    components::{
+        items::ShopOwner,
+        overview::{DungeonOverview, ZoneSummary},
+    },
+    constants::*,
+    engine::state::{GameState, RunState},
+    maps::{
+        dungeon_graph::DungeonGraph,
+        zone::{TileType, Zone},
+    },
+};
+
+/// Screen listing the visited depths with what was discovered there
+pub struct Overview {}
+
+impl Overview {
+    pub fn handle_input(game_state: &mut GameState) {
+        if is_key_pressed(KeyCode::Escape) {
+            // Exit overview, clear queue to avoid to reopen on cancel
+            // caused by char input queue
+            clear_input_queue();
+
+            game_state.run_state = RunState::WaitingPlayerInput;
+        }
+    }
+
+    /// Remember what the player discovered in the current zone. Must be called before leaving it
+    pub fn record_zone(game_state: &mut GameState) {
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+
+        let summary = Overview::summarize(zone, &game_state.ecs_world);
+        if let Ok(mut overview) = game_state.ecs_world.get::<&mut DungeonOverview>(player) {
+            overview.zones.push(summary);
+        }
+    }
+
+    /// Notable features of a zone. Only what is on revealed tiles is known to the player
+    fn summarize(zone: &Zone, ecs_world: &World) -> ZoneSummary {
+        let mut features = Vec::new();
+        let is_revealed = |tiles: &[usize]| tiles.iter().any(|&index| zone.revealed_tiles[index]);
+        let has_revealed_tile = |is_wanted: fn(&TileType) -> bool| {
+            zone.tiles
+                .iter()
+                .enumerate()
+                .any(|(index, tile)| zone.revealed_tiles[index] && is_wanted(tile))
+        };
+
+        if ecs_world
+            .query::<&ShopOwner>()
+            .iter()
+            .any(|(_, shop_owner)| is_revealed(&shop_owner.shop_tiles))
+        {
+            features.push("shop");
+        }
+        if (0..zone.water_tiles.len())
+            .any(|index| zone.revealed_tiles[index] && zone.water_tiles[index])
+        {
+            features.push("water");
+        }
+        if has_revealed_tile(|tile| *tile == TileType::Brazier) {
+            features.push("braziers");
+        }
+        for (name, tiles) in zone.special_rooms.iter() {
+            if is_revealed(tiles) {
+                features.push(*name);
+            }
+        }
+        if has_revealed_tile(|tile| *tile == TileType::DownPassage) {
+            features.push("way down");
+        }
+        if has_revealed_tile(|tile| *tile == TileType::BranchPassage) {
+            features.push("side passage");
+        }
+        if has_revealed_tile(|tile| *tile == TileType::GoldMine) {
+            features.push("gold mine");
+        }
+        if has_revealed_tile(|tile| {
+            matches!(
+                tile,
+                TileType::MiniCrystal
+                    | TileType::LittleCrystal
+                    | TileType::MediumCrystal
+                    | TileType::BigCrystal
+            )
+        }) {
+            features.push("crystals");
+        }
+
+        ZoneSummary {
+            depth: zone.depth,
+            branch: zone.branch,
+            features,
+        }
+    }
+
+    pub fn draw(game_state: &GameState) {
+        let header_text = "Dungeon overview";
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let zone = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone");
+
+        // ------- Background Rectangle -----------
+        draw_rectangle(
+            INVENTORY_X as f32,
+            INVENTORY_Y as f32,
+            INVENTORY_WIDTH as f32,
+            INVENTORY_HEIGHT as f32,
+            WHITE,
+        );
+        draw_rectangle(
+            (INVENTORY_X + HUD_BORDER) as f32,
+            (INVENTORY_Y + HUD_BORDER) as f32,
+            (INVENTORY_WIDTH - UI_BORDER) as f32,
+            (INVENTORY_HEIGHT - UI_BORDER) as f32,
+            BLACK,
+        );
+
+        // ------- Header -----------
+        draw_rectangle(
+            (INVENTORY_X + INVENTORY_LEFT_SPAN) as f32,
+            (INVENTORY_Y - UI_BORDER) as f32,
+            header_text.len() as f32 * LETTER_SIZE,
+            HEADER_HEIGHT as f32,
+            BLACK,
+        );
+
+        draw_text(
+            header_text,
+            (INVENTORY_X + INVENTORY_LEFT_SPAN + HUD_BORDER) as f32,
+            (INVENTORY_Y + UI_BORDER) as f32,
+            FONT_SIZE,
+            WHITE,
+        );
+
+        // ------- Depth List -----------
+        // Each depth is followed by its features. The current one is green and always listed
+        let mut lines: Vec<(String, Color)> = Vec::new();
+        if let Ok(overview) = game_state.ecs_world.get::<&DungeonOverview>(player) {
+            for summary in overview.zones.iter() {
+                Overview::add_summary_lines(summary, WHITE, &mut lines);
+            }
+        }
+        Overview::add_summary_lines(
+            &Overview::summarize(zone, &game_state.ecs_world),
+            GREEN,
+            &mut lines,
+        );
+
+        // Only the deepest ones are shown when there are too many
+        let max_lines =
+            ((INVENTORY_HEIGHT - INVENTORY_TOP_SPAN) as f32 / (FONT_SIZE + LETTER_SIZE)) as usize;
+        let x = (INVENTORY_X + UI_BORDER * 2) as f32;
+        for (index, (text, color)) in lines
+            .iter()
+            .skip(lines.len().saturating_sub(max_lines))
+            .enumerate()
+        {
+            let y = (INVENTORY_Y + INVENTORY_TOP_SPAN) as f32
+                + ((FONT_SIZE + LETTER_SIZE) * index as f32);
+            draw_text(text, x, y, FONT_SIZE, *color);
+        }
+
+        // ------- Footer -----------
+        draw_rectangle(
+            (INVENTORY_X + INVENTORY_LEFT_SPAN) as f32,
+            (INVENTORY_Y + INVENTORY_HEIGHT - UI_BORDER) as f32,
+            INVENTORY_FOOTER_WIDTH as f32,
+            HEADER_HEIGHT as f32,
+            BLACK,
+        );
+        draw_text(
+            "ESC to close",
+            (INVENTORY_X + INVENTORY_LEFT_SPAN + HUD_BORDER) as f32,
+            (INVENTORY_Y + INVENTORY_HEIGHT + HUD_BORDER) as f32,
+            FONT_SIZE,
+            WHITE,
+        );
+    }
+
+    /// Lines describing a zone: its depth, followed by the features wrapped to fit in the screen
+    fn add_summary_lines(summary: &ZoneSummary, color: Color, lines: &mut Vec<(String, Color)>) {
+        lines.push((
+            format!(
+                "Depth {} in {}",
+                summary.depth,
+                DungeonGraph::get_name(summary.branch)
+            ),
+            color,
+        ));
+
+        if summary.features.is_empty() {
+            lines.push(("  nothing notable".to_string(), LIGHTGRAY));
+            return;
+        }
+
+        let mut line = String::from(" ");
+        for feature in summary.features.iter() {
+            if line.len() + feature.len() + 2 > OVERVIEW_MAX_LINE_LENGTH {
+                lines.push((line, LIGHTGRAY));
+                line = String::from(" ");
+            }
+            line.push(' ');
+            line.push_str(feature);
+            line.push(',');
+        }
+        line.pop();
+        lines.push((line, LIGHTGRAY));
+    }
+}
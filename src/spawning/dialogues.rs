@@ -0,0 +1,330 @@
+use crate::{
+    components::{
+        common::{DialogueCondition, DialogueEffect, DialogueNode, DialogueOption, SpeciesEnum},
+        quest::QuestId,
+    },
+    constants::DIALOGUE_REVEAL_RADIUS,
+    spawning::spawner::Spawn,
+};
+
+/// Dialogue trees of the creatures that can hold a conversation.
+/// Node 0 is always where the conversation starts
+pub const MOLEMAN_DIALOGUE: &[DialogueNode] = &[
+    DialogueNode {
+        text: "Dig stone I must.\nYou enemy?\nYou friend?",
+        options: &[
+            DialogueOption {
+                text: "I am a friend",
+                conditions: &[DialogueCondition::Not(&DialogueCondition::HatesPlayer)],
+                effects: &[],
+                next: Some(1),
+            },
+            DialogueOption {
+                text: "Sorry, take this gold",
+                conditions: &[
+                    DialogueCondition::HatesPlayer,
+                    DialogueCondition::HasItem("raw gold"),
+                ],
+                effects: &[
+                    DialogueEffect::TakeItem("raw gold"),
+                    DialogueEffect::SetHostility(false),
+                ],
+                next: None,
+            },
+            DialogueOption {
+                text: "Enemy, get out of my way",
+                conditions: &[DialogueCondition::Not(&DialogueCondition::HatesPlayer)],
+                effects: &[DialogueEffect::SetHostility(true)],
+                next: None,
+            },
+            DialogueOption {
+                text: "Goodbye",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+    DialogueNode {
+        text: "Friend, good.\nDeep tunnels I know,\nall of them",
+        options: &[
+            DialogueOption {
+                text: "Show me the way down",
+                conditions: &[DialogueCondition::MinDepth(2)],
+                effects: &[DialogueEffect::RevealMapArea(DIALOGUE_REVEAL_RADIUS)],
+                next: None,
+            },
+            DialogueOption {
+                text: "Teach me to fell stones",
+                conditions: &[
+                    DialogueCondition::HasItem("raw gold"),
+                    DialogueCondition::Not(&DialogueCondition::KnowsSpell("Stone fell")),
+                ],
+                effects: &[
+                    DialogueEffect::TakeItem("raw gold"),
+                    DialogueEffect::TeachSpell(Spawn::stone_fell),
+                ],
+                next: Some(2),
+            },
+            DialogueOption {
+                text: "Do you need help?",
+                conditions: &[DialogueCondition::Not(&DialogueCondition::QuestActive(
+                    QuestId::MolemanHunt,
+                ))],
+                effects: &[],
+                next: Some(3),
+            },
+            DialogueOption {
+                text: "Goodbye",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+    DialogueNode {
+        text: "Hit rock, rock falls.\nCareful you be",
+        options: &[DialogueOption {
+            text: "Thanks",
+            conditions: &[],
+            effects: &[],
+            next: None,
+        }],
+    },
+    DialogueNode {
+        text: "Big beast near.\nTunnels unsafe.\nKill it you must",
+        options: &[
+            DialogueOption {
+                text: "I will hunt it",
+                conditions: &[],
+                effects: &[DialogueEffect::StartQuest(QuestId::MolemanHunt)],
+                next: None,
+            },
+            DialogueOption {
+                text: "Too dangerous for me",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+];
+
+pub const MOLEMAN_FARMER_DIALOGUE: &[DialogueNode] = &[
+    DialogueNode {
+        text: "Good mushrooms I trade.\nWant corpses.\nYou have?",
+        options: &[
+            DialogueOption {
+                text: "Let's trade",
+                conditions: &[DialogueCondition::Not(&DialogueCondition::HatesPlayer)],
+                effects: &[DialogueEffect::StartTrade],
+                next: None,
+            },
+            DialogueOption {
+                text: "I will find you a corpse",
+                conditions: &[
+                    DialogueCondition::Not(&DialogueCondition::HatesPlayer),
+                    DialogueCondition::Not(&DialogueCondition::QuestActive(QuestId::FarmerCorpse)),
+                ],
+                effects: &[DialogueEffect::StartQuest(QuestId::FarmerCorpse)],
+                next: Some(1),
+            },
+            DialogueOption {
+                text: "Goodbye",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+    DialogueNode {
+        text: "Corpse bring\nto any of us.\nGood helmet I give",
+        options: &[DialogueOption {
+            text: "Deal",
+            conditions: &[],
+            effects: &[],
+            next: None,
+        }],
+    },
+];
+
+pub const REFUGEE_DIALOGUE: &[DialogueNode] = &[
+    DialogueNode {
+        text: "Leave me alone,\nyou weirdo",
+        options: &[
+            DialogueOption {
+                text: "I'm human, like you",
+                conditions: &[
+                    DialogueCondition::PlayerSpecies(SpeciesEnum::Human),
+                    DialogueCondition::Not(&DialogueCondition::HatesPlayer),
+                ],
+                effects: &[],
+                next: Some(1),
+            },
+            DialogueOption {
+                text: "Sorry, take this ration",
+                conditions: &[
+                    DialogueCondition::HatesPlayer,
+                    DialogueCondition::HasItem("ration"),
+                ],
+                effects: &[
+                    DialogueEffect::TakeItem("ration"),
+                    DialogueEffect::SetHostility(false),
+                ],
+                next: None,
+            },
+            DialogueOption {
+                text: "Hand over your stuff!",
+                conditions: &[DialogueCondition::Not(&DialogueCondition::HatesPlayer)],
+                effects: &[DialogueEffect::SetHostility(true)],
+                next: None,
+            },
+            DialogueOption {
+                text: "Goodbye",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+    DialogueNode {
+        text: "Sorry, it's hard\nto trust anyone\ndown here.\nWe are all starving",
+        options: &[
+            DialogueOption {
+                text: "Here, have a ration",
+                conditions: &[DialogueCondition::HasItem("ration")],
+                effects: &[DialogueEffect::TakeItem("ration")],
+                next: Some(2),
+            },
+            DialogueOption {
+                text: "Come with me",
+                conditions: &[DialogueCondition::Not(&DialogueCondition::QuestActive(
+                    QuestId::RefugeeEscort,
+                ))],
+                effects: &[],
+                next: Some(3),
+            },
+            DialogueOption {
+                text: "Goodbye",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+    DialogueNode {
+        text: "Thank you!\nI saw a way down,\nlet me tell you\nwhere it is",
+        options: &[DialogueOption {
+            text: "Thanks",
+            conditions: &[],
+            effects: &[DialogueEffect::RevealMapArea(DIALOGUE_REVEAL_RADIUS)],
+            next: None,
+        }],
+    },
+    DialogueNode {
+        text: "Will you take me\nto the way down?\nI found some gold,\nit's yours if\nwe get there",
+        options: &[
+            DialogueOption {
+                text: "Follow me",
+                conditions: &[],
+                effects: &[DialogueEffect::StartQuest(QuestId::RefugeeEscort)],
+                next: None,
+            },
+            DialogueOption {
+                text: "Not now",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+];
+
+pub const STONEDUST_ABBOT_DIALOGUE: &[DialogueNode] = &[
+    DialogueNode {
+        text: "Welcome, pilgrim.\nHast thou any\nraw gold?",
+        options: &[
+            DialogueOption {
+                text: "Let's trade",
+                conditions: &[DialogueCondition::Not(&DialogueCondition::HatesPlayer)],
+                effects: &[DialogueEffect::StartTrade],
+                next: None,
+            },
+            DialogueOption {
+                text: "Teach me your ways",
+                conditions: &[
+                    DialogueCondition::Not(&DialogueCondition::HatesPlayer),
+                    DialogueCondition::HasItem("raw gold"),
+                    DialogueCondition::Not(&DialogueCondition::KnowsSpell("Daze")),
+                ],
+                effects: &[
+                    DialogueEffect::TakeItem("raw gold"),
+                    DialogueEffect::TeachSpell(Spawn::daze),
+                ],
+                next: Some(1),
+            },
+            DialogueOption {
+                text: "I am so thirsty",
+                conditions: &[DialogueCondition::MinDepth(3)],
+                effects: &[DialogueEffect::GiveItem("flask of water")],
+                next: None,
+            },
+            DialogueOption {
+                text: "Can I help you?",
+                conditions: &[
+                    DialogueCondition::Not(&DialogueCondition::HatesPlayer),
+                    DialogueCondition::Not(&DialogueCondition::QuestActive(QuestId::AbbotGold)),
+                ],
+                effects: &[],
+                next: Some(2),
+            },
+            DialogueOption {
+                text: "I brought the gold",
+                conditions: &[DialogueCondition::QuestReady(QuestId::AbbotGold)],
+                effects: &[DialogueEffect::CompleteQuest(QuestId::AbbotGold)],
+                next: Some(3),
+            },
+            DialogueOption {
+                text: "Goodbye",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+    DialogueNode {
+        text: "Let the holy dust\nguide thy mind.\nThy foes shall\nfalter before thee",
+        options: &[DialogueOption {
+            text: "Thanks, father",
+            conditions: &[],
+            effects: &[],
+            next: None,
+        }],
+    },
+    DialogueNode {
+        text: "Our shrine needs\nmore raw gold.\nBring it to me\nand thou shalt\nbe blessed",
+        options: &[
+            DialogueOption {
+                text: "I will bring it",
+                conditions: &[],
+                effects: &[DialogueEffect::StartQuest(QuestId::AbbotGold)],
+                next: None,
+            },
+            DialogueOption {
+                text: "Not now",
+                conditions: &[],
+                effects: &[],
+                next: None,
+            },
+        ],
+    },
+    DialogueNode {
+        text: "Blessed be thee.\nThe holy dust shall\nkeep thy bones from\nturning into stone",
+        options: &[DialogueOption {
+            text: "Thanks, father",
+            conditions: &[],
+            effects: &[],
+            next: None,
+        }],
+    },
+];
@@ -0,0 +1,95 @@
+use hecs::{Entity, World};
+
+use crate::{
+    components::monster::PackMember, maps::zone::Zone, spawning::spawner::Spawn, utils::roll::Roll,
+};
+
+/// Groups are monsters spawned together around a leader.
+/// Members follow the leader, help it and break off when it dies.
+impl Spawn {
+    /// A stonedust cultist leading some acolytes
+    pub fn stonedust_congregation(ecs_world: &mut World, x: i32, y: i32, zone: &Zone) {
+        let cultist = Spawn::stonedust_cultist(ecs_world, x, y);
+        Spawn::pack_members(
+            ecs_world,
+            zone,
+            cultist,
+            (x, y),
+            Roll::dice(1, 2),
+            Spawn::stonedust_acolyte,
+        );
+    }
+
+    /// A swarm of giant cockroaches
+    pub fn cockroach_swarm(ecs_world: &mut World, x: i32, y: i32, zone: &Zone) {
+        let cockroach = Spawn::giant_cockroach(ecs_world, x, y);
+        Spawn::pack_members(
+            ecs_world,
+            zone,
+            cockroach,
+            (x, y),
+            Roll::dice(2, 3),
+            Spawn::giant_cockroach,
+        );
+    }
+
+    /// A gang of gremlins
+    pub fn gremlin_gang(ecs_world: &mut World, x: i32, y: i32, zone: &Zone) {
+        let gremlin = Spawn::gremlin(ecs_world, x, y);
+        Spawn::pack_members(
+            ecs_world,
+            zone,
+            gremlin,
+            (x, y),
+            Roll::dice(1, 3),
+            Spawn::gremlin,
+        );
+    }
+
+    /// A family of refugees
+    pub fn refugee_family(ecs_world: &mut World, x: i32, y: i32, zone: &Zone) {
+        let refugee = Spawn::refugee(ecs_world, x, y);
+        Spawn::pack_members(
+            ecs_world,
+            zone,
+            refugee,
+            (x, y),
+            Roll::dice(1, 3),
+            Spawn::refugee,
+        );
+    }
+
+    /// Spawn the members of a group around their leader.
+    /// If there is no space left, the group will be smaller
+    fn pack_members(
+        ecs_world: &mut World,
+        zone: &Zone,
+        leader: Entity,
+        (leader_x, leader_y): (i32, i32),
+        size: i32,
+        spawn_member: fn(&mut World, i32, i32) -> Entity,
+    ) {
+        let mut occupied_tiles = vec![(leader_x, leader_y)];
+
+        for _ in 0..size {
+            // Search for a free tile next to the members already placed
+            let free_tile = occupied_tiles.iter().find_map(|(x, y)| {
+                zone.get_adjacent_passable_tiles(x, y, false, false)
+                    .into_iter()
+                    .find(|tile| {
+                        !occupied_tiles.contains(tile)
+                            && !zone.water_tiles[zone.get_index_from_xy(&tile.0, &tile.1)]
+                    })
+            });
+
+            // The members already placed are enough
+            let Some((member_x, member_y)) = free_tile else {
+                return;
+            };
+
+            let member = spawn_member(ecs_world, member_x, member_y);
+            let _ = ecs_world.insert_one(member, PackMember { leader });
+            occupied_tiles.push((member_x, member_y));
+        }
+    }
+}
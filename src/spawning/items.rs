@@ -2,10 +2,11 @@ use std::collections::HashSet;
 
 use crate::{
     components::{
-        common::{DigProductEnum, ImmunityTypeEnum, Key},
+        common::{DigProductEnum, EmitsGas, Flammable, ImmunityTypeEnum, Key},
         health::DiseaseType,
-        items::{Ammo, AmmoType, Cure, DiggingTool, GivesImmunity, RangedWeapon},
+        items::{Ammo, AmmoType, Cure, DiggingTool, DisarmingTool, GivesImmunity, RangedWeapon},
     },
+    maps::zone::GasType,
     spawning::spawner::Spawn,
 };
 use hecs::{Entity, World};
@@ -30,6 +31,7 @@ impl Spawn {
         let common_components = (
             Item {
                 item_tile: (mushroom_type, 1),
+                value: 1,
             },
             Position { x, y },
             Renderable {
@@ -145,6 +147,11 @@ impl Spawn {
                             name: "patch of mold",
                             attack_verb: None,
                         },
+                        EmitsGas {
+                            of: GasType::Spores,
+                            density: MOLD_SPORES_DENSITY,
+                            chance: MOLD_SPORES_CHANCE,
+                        },
                     ),
                 );
                 //Mold does not rot after being harvested
@@ -181,6 +188,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 2,
             },
             Quaffable {
                 thirst_dice_number: 4,
@@ -211,6 +219,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 6,
             },
             Appliable {
                 application_time: VERY_LONG_ACTION_MULTIPLIER,
@@ -243,6 +252,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 3,
             },
             Smellable {
                 smell_log: Some("dry meat"),
@@ -277,6 +287,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 8,
             },
             ProduceLight {
                 radius: LANTERN_RADIUS,
@@ -317,6 +328,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 15,
             },
             Appliable {
                 application_time: STANDARD_ACTION_MULTIPLIER,
@@ -357,6 +369,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 12,
             },
             Equippable {
                 body_location: BodyLocation::BothHands,
@@ -395,6 +408,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 5,
             },
             Equippable {
                 body_location: BodyLocation::BothHands,
@@ -432,6 +446,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 3,
             },
             Refiller {
                 fuel_counter: STARTING_FUEL + Roll::d100(),
@@ -443,6 +458,7 @@ impl Spawn {
             Appliable {
                 application_time: STANDARD_ACTION_MULTIPLIER,
             },
+            Flammable {},
         );
 
         ecs_world.spawn(flask_of_oil);
@@ -468,6 +484,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 3,
             },
             Equippable {
                 body_location: BodyLocation::RightHand,
@@ -477,6 +494,7 @@ impl Spawn {
                 number_of_dices: 1,
                 dice_size: 4,
             },
+            DisarmingTool {},
         );
 
         ecs_world.spawn(shiv);
@@ -502,6 +520,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 6,
             },
             Equippable {
                 body_location: BodyLocation::RightHand,
@@ -513,6 +532,7 @@ impl Spawn {
             },
             Metallic {},
             DiggingTool {},
+            DisarmingTool {},
         );
 
         //TODO Bonus to climb while wielded
@@ -540,6 +560,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 10,
             },
             Equippable {
                 body_location: BodyLocation::BothHands,
@@ -577,11 +598,13 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 8,
             },
             Equippable {
                 body_location: BodyLocation::Torso,
             },
             Armor { value: 1 },
+            Flammable {},
         );
 
         ecs_world.spawn(leather_armor)
@@ -607,6 +630,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 15,
             },
             Equippable {
                 body_location: BodyLocation::Torso,
@@ -642,6 +666,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 6,
             },
             Equippable {
                 body_location: BodyLocation::Torso,
@@ -677,6 +702,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 4,
             },
             Equippable {
                 body_location: BodyLocation::Feet,
@@ -684,6 +710,7 @@ impl Spawn {
             GivesImmunity {
                 to: HashSet::from([ImmunityTypeEnum::DamagingFloor]),
             },
+            Flammable {},
         );
 
         ecs_world.spawn(leather_shoes)
@@ -709,6 +736,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 8,
             },
             Equippable {
                 body_location: BodyLocation::Feet,
@@ -741,6 +769,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 1,
             },
             Ammo {
                 ammo_type: AmmoType::Crossbow,
@@ -771,6 +800,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 1,
             },
             Ammo {
                 ammo_type: AmmoType::Slingshot,
@@ -801,17 +831,19 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 3,
             },
             Equippable {
                 body_location: BodyLocation::Head,
             },
             Armor { value: 1 },
+            Flammable {},
         );
 
         ecs_world.spawn(leather_cap);
     }
 
-    pub fn helmet(ecs_world: &mut World, x: i32, y: i32) {
+    pub fn helmet(ecs_world: &mut World, x: i32, y: i32) -> Entity {
         let item_tile_index = (1, 4);
         let helmet = (
             Position { x, y },
@@ -831,6 +863,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 8,
             },
             Equippable {
                 body_location: BodyLocation::Head,
@@ -842,7 +875,7 @@ impl Spawn {
             },
         );
 
-        ecs_world.spawn(helmet);
+        ecs_world.spawn(helmet)
     }
 
     //TODO improve avoiding preassigned characters
@@ -929,6 +962,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 5,
             },
             DigProductEnum::Gold,
         );
@@ -956,6 +990,7 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 0,
             },
             Key { lock },
             Appliable {
@@ -965,4 +1000,35 @@ impl Spawn {
 
         ecs_world.spawn(gold_key)
     }
+
+    pub fn iron_key(ecs_world: &mut World, x: i32, y: i32, lock: Entity) -> Entity {
+        let item_tile_index = (2, 5);
+        let iron_key = (
+            Position { x, y },
+            Renderable {
+                texture_name: TextureName::Items,
+                texture_region: Rect {
+                    x: (item_tile_index.0 * TILE_SIZE) as f32,
+                    y: (item_tile_index.1 * TILE_SIZE) as f32,
+                    w: TILE_SIZE_F32,
+                    h: TILE_SIZE_F32,
+                },
+                z_index: 0,
+            },
+            Named {
+                name: "iron key",
+                attack_verb: None,
+            },
+            Item {
+                item_tile: item_tile_index,
+                value: 0,
+            },
+            Key { lock },
+            Appliable {
+                application_time: STANDARD_ACTION_MULTIPLIER,
+            },
+        );
+
+        ecs_world.spawn(iron_key)
+    }
 }
@@ -1,3 +1,5 @@
+mod dialogues;
+mod groups;
 mod items;
 mod monsters;
 pub mod spawner;
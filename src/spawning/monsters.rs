@@ -8,23 +8,31 @@ use crate::{
         actions::WantsToApply,
         combat::{CanHide, CombatStats, GazeAttack, GazeEffectEnum, SufferingDamage},
         common::{
-            BlocksTile, Hates, Immobile, Immunity, ImmunityTypeEnum, MyTurn, Named, Position,
-            ProduceCorpse, ProduceSound, Renderable, SmellIntensity, Smellable, Species,
-            SpeciesEnum, SpellList, Viewshed, WillChat,
+            BlocksTile, CanListen, CanSmell, EmitsGas, Faction, FactionEnum, Hates, Immobile,
+            Immunity, ImmunityTypeEnum, MyTurn, Named, Position, ProduceCorpse, ProduceSound,
+            Renderable, SmellIntensity, Smellable, Species, SpeciesEnum, SpellList, Viewshed,
+            WillChat,
         },
         health::{DiseaseType, Hunger},
         items::{BodyLocation, Deadly, Edible, Equipped, InBackback},
         monster::{
-            Aquatic, DiseaseBearer, Grappler, LeaveTrail, Monster, Prey, SingleSnakeCreature,
-            Small, Smart, SnakeBody, SnakeHead, StoneEater, Venomous,
+            Aquatic, Awareness, AwarenessState, DiseaseBearer, Grappler, Hireable, LeaveTrail,
+            Monster, Morale, Prey, SingleSnakeCreature, Small, Smart, SnakeBody, SnakeHead,
+            StoneEater, Venomous,
         },
     },
     constants::{
         BASE_MONSTER_VIEW_RADIUS, BASE_VIEW_RADIUS, FAST, FILTH_TRAIL_LIFETIME,
-        MAX_HUNGER_TICK_COUNTER, NORMAL, SLOW, SLUG_TRAIL_LIFETIME, TILE_SIZE_F32,
+        MAX_HUNGER_TICK_COUNTER, MONSTER_LISTEN_RADIUS, MONSTER_SMELL_RADIUS, NORMAL, SLOW,
+        SLUG_TRAIL_LIFETIME, SULFUR_FUMES_CHANCE, SULFUR_FUMES_DENSITY, TILE_SIZE_F32,
+    },
+    maps::zone::{DecalType, GasType, Zone},
+    spawning::{
+        dialogues::{
+            MOLEMAN_DIALOGUE, MOLEMAN_FARMER_DIALOGUE, REFUGEE_DIALOGUE, STONEDUST_ABBOT_DIALOGUE,
+        },
+        spawner::Spawn,
     },
-    maps::zone::{DecalType, Zone},
-    spawning::spawner::Spawn,
     systems::hunger_check::HungerStatus,
     utils::{assets::TextureName, roll::Roll},
 };
@@ -110,7 +118,23 @@ impl Spawn {
         for immunity in immunities {
             immunity_comp.to.insert(immunity, 1);
         }
-        let _ = ecs_world.insert(monster_spawned, (immunity_comp,));
+        // Not all monsters are awake and ready to fight when spawned
+        let state = match Roll::d6() {
+            1..=2 => AwarenessState::Asleep,
+            3..=4 => AwarenessState::Unaware,
+            _ => AwarenessState::Alert,
+        };
+        let _ = ecs_world.insert(
+            monster_spawned,
+            (
+                immunity_comp,
+                Awareness { state },
+                Morale {
+                    allies_lost: 0,
+                    has_tested_wounds: false,
+                },
+            ),
+        );
 
         // Not all monsters produce corpses on death
         if produce_corpse {
@@ -121,7 +145,7 @@ impl Spawn {
     }
 
     pub fn deep_one(ecs_world: &mut World, x: i32, y: i32) {
-        Spawn::create_monster(
+        let deep_one = Spawn::create_monster(
             ecs_world,
             (
                 Named {
@@ -163,6 +187,13 @@ impl Spawn {
                 y,
             ),
         );
+
+        let _ = ecs_world.insert_one(
+            deep_one,
+            Faction {
+                value: FactionEnum::DeepSpawn,
+            },
+        );
     }
 
     pub fn abyssal_one(ecs_world: &mut World, x: i32, y: i32) {
@@ -212,6 +243,9 @@ impl Spawn {
         let _ = ecs_world.insert(
             abyssal_one,
             (
+                Faction {
+                    value: FactionEnum::DeepSpawn,
+                },
                 Smart {},
                 DiseaseBearer {
                     disease_type: DiseaseType::FleshRot,
@@ -632,7 +666,7 @@ impl Spawn {
         let _ = ecs_world.insert(scorpion, (Grappler {}, Venomous {}));
     }
 
-    pub fn gremlin(ecs_world: &mut World, x: i32, y: i32) {
+    pub fn gremlin(ecs_world: &mut World, x: i32, y: i32) -> Entity {
         let gremlin = Spawn::create_monster(
             ecs_world,
             (
@@ -676,7 +710,18 @@ impl Spawn {
             ),
         );
 
-        let _ = ecs_world.insert(gremlin, (Smart {}, Small {}));
+        let _ = ecs_world.insert(
+            gremlin,
+            (
+                Smart {},
+                Small {},
+                CanListen {
+                    listen_cache: HashMap::new(),
+                    radius: MONSTER_LISTEN_RADIUS,
+                    cooldown: 0,
+                },
+            ),
+        );
 
         if Roll::d6() > 5 {
             let wand = Spawn::wand(ecs_world, x, y);
@@ -691,6 +736,8 @@ impl Spawn {
         } else if Roll::d6() > 3 {
             Spawn::give_slingshot_and_ammo(ecs_world, gremlin);
         }
+
+        gremlin
     }
 
     pub fn enthropic_gremlin(ecs_world: &mut World, x: i32, y: i32) {
@@ -846,7 +893,18 @@ impl Spawn {
             ),
         );
 
-        let _ = ecs_world.insert(giant_trogloraptor, (CanHide { cooldown: 0 }, Grappler {}));
+        let _ = ecs_world.insert(
+            giant_trogloraptor,
+            (
+                CanHide { cooldown: 0 },
+                Grappler {},
+                CanListen {
+                    listen_cache: HashMap::new(),
+                    radius: MONSTER_LISTEN_RADIUS,
+                    cooldown: 0,
+                },
+            ),
+        );
     }
 
     pub fn moleman(ecs_world: &mut World, x: i32, y: i32) {
@@ -935,13 +993,22 @@ impl Spawn {
         let _ = ecs_world.insert(
             moleman,
             (
+                Faction {
+                    value: FactionEnum::Molemen,
+                },
                 Smart {},
+                Hireable {},
+                CanSmell {
+                    intensity: SmellIntensity::Faint,
+                    radius: MONSTER_SMELL_RADIUS,
+                },
                 WillChat {
                     dialogues: vec![
                         "Dig stone I must",
                         "You enemy? You friend?",
                         "Humans somewhere, I heard",
                     ],
+                    tree: Some(MOLEMAN_DIALOGUE),
                 },
             ),
         );
@@ -1008,6 +1075,9 @@ impl Spawn {
         let _ = ecs_world.insert(
             moleman_farmer,
             (
+                Faction {
+                    value: FactionEnum::Molemen,
+                },
                 Smart {},
                 Immobile {},
                 WillChat {
@@ -1016,13 +1086,14 @@ impl Spawn {
                         "No steal, I kill thieves",
                         "Want corpses. You have?",
                     ],
+                    tree: Some(MOLEMAN_FARMER_DIALOGUE),
                 },
             ),
         );
         moleman_farmer
     }
 
-    pub fn giant_cockroach(ecs_world: &mut World, x: i32, y: i32) {
+    pub fn giant_cockroach(ecs_world: &mut World, x: i32, y: i32) -> Entity {
         let centipede = Spawn::create_monster(
             ecs_world,
             (
@@ -1067,6 +1138,8 @@ impl Spawn {
         );
 
         let _ = ecs_world.insert(centipede, (Small {}, Prey {}));
+
+        centipede
     }
 
     pub fn bombardier_bettle(ecs_world: &mut World, x: i32, y: i32) {
@@ -1219,7 +1292,7 @@ impl Spawn {
                     sound_log: "something sizzling",
                 },
                 true,
-                vec![],
+                vec![ImmunityTypeEnum::Heat],
                 7.0,
                 1.0,
                 x,
@@ -1238,11 +1311,16 @@ impl Spawn {
                     of: DecalType::Acid,
                     trail_lifetime: SLUG_TRAIL_LIFETIME,
                 },
+                EmitsGas {
+                    of: GasType::Sulfur,
+                    density: SULFUR_FUMES_DENSITY,
+                    chance: SULFUR_FUMES_CHANCE,
+                },
             ),
         );
     }
 
-    pub fn refugee(ecs_world: &mut World, x: i32, y: i32) {
+    pub fn refugee(ecs_world: &mut World, x: i32, y: i32) -> Entity {
         let refugee = Spawn::create_monster(
             ecs_world,
             (
@@ -1339,17 +1417,24 @@ impl Spawn {
         let _ = ecs_world.insert(
             refugee,
             (
+                Faction {
+                    value: FactionEnum::Refugees,
+                },
                 Smart {},
                 Prey {},
+                Hireable {},
                 WillChat {
                     dialogues: vec![
                         "Leave me alone,\nyou weirdo",
                         "I don't want you\nanywhere near me,\n get lost!",
                         "Go away, you'll\nattract some darn\nmonster here!",
                     ],
+                    tree: Some(REFUGEE_DIALOGUE),
                 },
             ),
         );
+
+        refugee
     }
 
     /// Spawn a naked human refugee, test only
@@ -1414,10 +1499,14 @@ impl Spawn {
         let _ = ecs_world.insert(
             refugee,
             (
+                Faction {
+                    value: FactionEnum::Refugees,
+                },
                 Smart {},
                 Prey {},
                 WillChat {
                     dialogues: vec!["Leave me alone"],
+                    tree: None,
                 },
             ),
         );
@@ -1491,6 +1580,9 @@ impl Spawn {
         let _ = ecs_world.insert(
             stonedust_cultist,
             (
+                Faction {
+                    value: FactionEnum::StonedustCult,
+                },
                 WantsToApply { item: lantern },
                 Smart {},
                 WillChat {
@@ -1499,6 +1591,7 @@ impl Spawn {
                         "I envy thyne path\nof descent in\nthe bowels of\nthis world",
                         "Our sacred scripts\nspoke of your\ndescent, brave one!",
                     ],
+                    tree: None,
                 },
             ),
         );
@@ -1616,6 +1709,9 @@ impl Spawn {
         let _ = ecs_world.insert(
             stonedust_acolyte,
             (
+                Faction {
+                    value: FactionEnum::StonedustCult,
+                },
                 WantsToApply { item: lantern },
                 Smart {},
                 WillChat {
@@ -1624,6 +1720,7 @@ impl Spawn {
                         "I do not know\nwhat awaits thou\nin the depths below,\n sacred one",
                         "Our sacred scripts\nspoke of your\ndescent, brave one!",
                     ],
+                    tree: None,
                 },
             ),
         );
@@ -1720,6 +1817,9 @@ impl Spawn {
         let _ = ecs_world.insert(
             stonedust_abbot,
             (
+                Faction {
+                    value: FactionEnum::StonedustCult,
+                },
                 WantsToApply { item: lantern },
                 Smart {},
                 Immobile {},
@@ -1729,6 +1829,7 @@ impl Spawn {
                         "I have special\npaste made from\nholy stone dust.",
                         "All diseases can\nbe cured with\nour sacred paste",
                     ],
+                    tree: Some(STONEDUST_ABBOT_DIALOGUE),
                 },
             ),
         );
@@ -1737,7 +1838,7 @@ impl Spawn {
     }
 
     pub fn living_dead(ecs_world: &mut World, x: i32, y: i32) {
-        let _ = Spawn::create_monster(
+        let living_dead = Spawn::create_monster(
             ecs_world,
             (
                 Named {
@@ -1783,6 +1884,15 @@ impl Spawn {
                 y,
             ),
         );
+
+        // The dead can smell the living
+        let _ = ecs_world.insert_one(
+            living_dead,
+            CanSmell {
+                intensity: SmellIntensity::Faint,
+                radius: MONSTER_SMELL_RADIUS,
+            },
+        );
     }
 
     pub fn darkling(ecs_world: &mut World, x: i32, y: i32) {
@@ -1831,9 +1941,19 @@ impl Spawn {
 
         let _ = ecs_world.insert(
             darkling,
-            (GazeAttack {
-                effect: GazeEffectEnum::Blindness,
-            },),
+            (
+                Faction {
+                    value: FactionEnum::DeepSpawn,
+                },
+                GazeAttack {
+                    effect: GazeEffectEnum::Blindness,
+                },
+                CanListen {
+                    listen_cache: HashMap::new(),
+                    radius: MONSTER_LISTEN_RADIUS,
+                    cooldown: 0,
+                },
+            ),
         );
     }
 
@@ -1,11 +1,12 @@
 use std::cmp::max;
 use std::collections::HashMap;
 
-use crate::components::combat::{CombatStats, SufferingDamage};
+use crate::components::combat::{CanHide, CombatStats, IsHidden, SufferingDamage};
 use crate::components::common::{
-    BlocksTile, CanListen, CanSmell, DigProductEnum, Diggable, Experience, Immunity, Inspectable,
-    Lock, MyTurn, Named, Position, ProduceSound, Renderable, SmellIntensity, Smellable, Species,
-    SpeciesEnum, Viewshed,
+    BlocksTile, Burning, CanListen, CanSmell, DigProductEnum, Diggable, Experience, Fire,
+    Flammable, Immunity, Inspectable, Lock, MyTurn, Named, Position, ProduceSound, Purse,
+    Renderable, Reputation, SmellIntensity, Smellable, Species, SpeciesEnum, Trap, TrapType,
+    Viewshed,
 };
 use crate::components::health::{CanAutomaticallyHeal, DiseaseType, Hunger, Thirst};
 use crate::components::items::{
@@ -13,7 +14,9 @@ use crate::components::items::{
 };
 use crate::components::monster::DiseaseBearer;
 use crate::components::player::Player;
+use crate::components::{overview::DungeonOverview, quest::QuestJournal};
 use crate::constants::*;
+use crate::maps::connectivity_checker::ConnectivityChecker;
 use crate::maps::zone::{TileType, Zone};
 use crate::systems::hunger_check::HungerStatus;
 use crate::systems::thirst_check::ThirstStatus;
@@ -45,7 +48,7 @@ impl Spawn {
         // TODO Player with Soldier background must have 5+2d3 starting stamina
         let rolled_stamina = Roll::d6() + 5;
 
-        let (spawn_x, spawn_y) = Zone::get_xy_from_index(zone.player_spawn_point);
+        let (spawn_x, spawn_y) = zone.get_xy_from_index(zone.player_spawn_point);
 
         let player_components = (
             Player {},
@@ -129,6 +132,15 @@ impl Spawn {
                 },
                 BlocksTile {},
                 Immunity { to: HashMap::new() },
+                CanHide { cooldown: 0 },
+                QuestJournal { quests: Vec::new() },
+                DungeonOverview { zones: Vec::new() },
+                Reputation {
+                    values: HashMap::from(STARTING_REPUTATIONS),
+                },
+                Purse {
+                    gold: STARTING_GOLD,
+                },
             ),
         );
 
@@ -139,7 +151,7 @@ impl Spawn {
     pub fn everyhing_in_map(ecs_world: &mut World, zone: &Zone) {
         // Actually spawn the monsters
         for &index in zone.monster_spawn_points.iter() {
-            let (x, y) = Zone::get_xy_from_index(index);
+            let (x, y) = zone.get_xy_from_index(index);
 
             if zone.water_tiles[index] {
                 Spawn::random_water_monster(ecs_world, x, y, zone.depth);
@@ -149,19 +161,39 @@ impl Spawn {
         }
         // Actually spawn the items
         for &index in zone.item_spawn_points.iter() {
-            let (x, y) = Zone::get_xy_from_index(index);
+            let (x, y) = zone.get_xy_from_index(index);
             Spawn::random_item(ecs_world, x, y);
         }
         // Actually spawn the fauna
         for &index in zone.fauna_spawn_points.iter() {
-            let (x, y) = Zone::get_xy_from_index(index);
+            let (x, y) = zone.get_xy_from_index(index);
             Spawn::random_fauna(ecs_world, x, y);
         }
 
+        // Keys lie where the player can walk without opening any locked door,
+        // so no key ends up behind its own door
+        let key_tiles: Vec<usize> =
+            ConnectivityChecker::flood_fill_without_keys(zone, zone.player_spawn_point)
+                .iter()
+                .enumerate()
+                .filter(|&(index, &reachable)| reachable && zone.tiles[index] == TileType::Floor)
+                .map(|(index, _)| index)
+                .collect();
+
         // Spawn special entities
         for (index, tile) in zone.tiles.iter().enumerate() {
-            let (x, y) = Zone::get_xy_from_index(index);
-            Spawn::tile_entity(ecs_world, x, y, tile);
+            let (x, y) = zone.get_xy_from_index(index);
+            let tile_entity = Spawn::tile_entity(ecs_world, x, y, tile);
+
+            // Each locked door has its own key, lying somewhere in the zone
+            if *tile == TileType::LockedDoor
+                && let Some(lock) = tile_entity
+                && !key_tiles.is_empty()
+            {
+                let key_roll = Roll::dice(1, key_tiles.len() as i32) as usize - 1;
+                let (key_x, key_y) = zone.get_xy_from_index(key_tiles[key_roll]);
+                Spawn::iron_key(ecs_world, key_x, key_y, lock);
+            }
         }
     }
 
@@ -173,11 +205,23 @@ impl Spawn {
         match dice_roll {
             (1..=10) => match Roll::dice(1, 22) {
                 (1..=5) => Spawn::giant_slug(ecs_world, x, y),
-                (6..=8) => Spawn::giant_cockroach(ecs_world, x, y),
+                (6..=8) => {
+                    if Roll::d6() > 3 {
+                        Spawn::cockroach_swarm(ecs_world, x, y, zone);
+                    } else {
+                        let _ = Spawn::giant_cockroach(ecs_world, x, y);
+                    }
+                }
                 (9..=11) => Spawn::deep_one(ecs_world, x, y),
                 (12..=14) => Spawn::living_dead(ecs_world, x, y),
                 (15..=17) => Spawn::living_filth(ecs_world, x, y),
-                (18..=19) => Spawn::refugee(ecs_world, x, y),
+                (18..=19) => {
+                    if Roll::d6() > 4 {
+                        Spawn::refugee_family(ecs_world, x, y, zone);
+                    } else {
+                        let _ = Spawn::refugee(ecs_world, x, y);
+                    }
+                }
                 (20..=21) => Spawn::pseudoscorpion(ecs_world, x, y),
                 22 => Spawn::moleman(ecs_world, x, y),
                 _ => {}
@@ -185,19 +229,31 @@ impl Spawn {
             (11..=20) => match Roll::dice(1, 27) {
                 (1..=5) => Spawn::calcificator(ecs_world, x, y),
                 (6..=8) => Spawn::centipede(ecs_world, x, y),
-                (9..=11) => Spawn::gremlin(ecs_world, x, y),
+                (9..=11) => {
+                    if Roll::d6() > 4 {
+                        Spawn::gremlin_gang(ecs_world, x, y, zone);
+                    } else {
+                        let _ = Spawn::gremlin(ecs_world, x, y);
+                    }
+                }
                 (12..=14) => Spawn::moleman(ecs_world, x, y),
                 (15..=17) => Spawn::sulfuric_slug(ecs_world, x, y),
-                (18..=19) => Spawn::refugee(ecs_world, x, y),
+                (18..=19) => {
+                    let _ = Spawn::refugee(ecs_world, x, y);
+                }
                 (20..=21) => Spawn::bombardier_bettle(ecs_world, x, y),
                 (22..=24) => {
-                    let _ = Spawn::stonedust_cultist(ecs_world, x, y);
+                    if Roll::d6() > 3 {
+                        Spawn::stonedust_congregation(ecs_world, x, y, zone);
+                    } else {
+                        let _ = Spawn::stonedust_cultist(ecs_world, x, y);
+                    }
                 }
                 (25..=27) => Spawn::giant_trogloraptor(ecs_world, x, y),
                 _ => {}
             },
             (21..) => match Roll::dice(1, 28) {
-                (1..=4) => Spawn::gremlin(ecs_world, x, y),
+                (1..=4) => Spawn::gremlin_gang(ecs_world, x, y, zone),
                 (5..=7) => Spawn::moleman(ecs_world, x, y),
                 (8..=11) => {
                     let _ = Spawn::stonedust_acolyte(ecs_world, x, y);
@@ -205,7 +261,9 @@ impl Spawn {
                 (12..=14) => Spawn::enthropic_gremlin(ecs_world, x, y),
                 (15..=17) => Spawn::abyssal_one(ecs_world, x, y),
                 (18..=20) => Spawn::sulfuric_slug(ecs_world, x, y),
-                (21..=22) => Spawn::refugee(ecs_world, x, y),
+                (21..=22) => {
+                    let _ = Spawn::refugee(ecs_world, x, y);
+                }
                 (23..=24) => Spawn::living_fossil(ecs_world, x, y),
                 (25..=26) => Spawn::scorpion(ecs_world, x, y),
                 27 => Spawn::colossal_worm(ecs_world, x, y, zone),
@@ -268,7 +326,9 @@ impl Spawn {
             25 => {
                 let _ = Spawn::wand(ecs_world, x, y);
             }
-            26 => Spawn::helmet(ecs_world, x, y),
+            26 => {
+                let _ = Spawn::helmet(ecs_world, x, y);
+            }
             27 => Spawn::curing_paste(ecs_world, x, y),
             28 => {
                 let _ = Spawn::leather_shoes(ecs_world, x, y);
@@ -309,9 +369,11 @@ impl Spawn {
             },
             Item {
                 item_tile: item_tile_index,
+                value: 2,
             },
             data.edible,
             Corpse {},
+            Flammable {},
             Perishable {
                 rot_counter: STARTING_ROT_COUNTER + Roll::d20(),
             },
@@ -368,6 +430,16 @@ impl Spawn {
                     sound_log: "breeze from below",
                 },
             ))),
+            TileType::BranchPassage => Some(ecs_world.spawn((
+                Position { x, y },
+                ProduceSound {
+                    sound_log: "echoes from a side passage",
+                },
+                Inspectable {
+                    description: "You see a side passage\nleading down.\nWho knows where\nit will take you",
+                    despawn_on_inspect: false,
+                },
+            ))),
             TileType::CrackedWall => Some(ecs_world.spawn((
                 Position { x, y },
                 Diggable {
@@ -407,6 +479,53 @@ impl Spawn {
                     despawn_on_inspect: false,
                 },
             ))),
+            TileType::LockedDoor => Some(ecs_world.spawn((
+                Position { x, y },
+                Lock { keys_to_unlock: 1 },
+                Diggable {
+                    dig_points: Roll::dice(2, 10),
+                    produces: DigProductEnum::Nothing,
+                },
+                Inspectable {
+                    description: "You see a sturdy\nwooden door, locked tight.\nA key could open it,\nor you could try\nto force it",
+                    despawn_on_inspect: false,
+                },
+            ))),
+            TileType::Chasm => Some(ecs_world.spawn((
+                Position { x, y },
+                Inspectable {
+                    description: "You see a deep chasm.\nYou cannot see the bottom,\nbut a fall down there\nwill surely hurt",
+                    despawn_on_inspect: false,
+                },
+            ))),
+            TileType::Lava => Some(ecs_world.spawn((
+                Position { x, y },
+                Inspectable {
+                    description: "You see a river of\nmolten rock.\nNothing that falls in\ncomes out again",
+                    despawn_on_inspect: false,
+                },
+            ))),
+            TileType::LavaCrust => Some(ecs_world.spawn((
+                Position { x, y },
+                Inspectable {
+                    description: "You see a thin crust\nof cooling lava.\nIt will not hold\nfor long",
+                    despawn_on_inspect: false,
+                },
+            ))),
+            TileType::HotRock => Some(ecs_world.spawn((
+                Position { x, y },
+                Inspectable {
+                    description: "You see some rock\nglowing with heat.\nBetter not walk on it\nbarefoot",
+                    despawn_on_inspect: false,
+                },
+            ))),
+            TileType::DeepWater => Some(ecs_world.spawn((
+                Position { x, y },
+                Inspectable {
+                    description: "You see dark, deep water.\nYou cannot see the bottom.\nHeavy armor would drag\nyou down",
+                    despawn_on_inspect: false,
+                },
+            ))),
             TileType::CarvedStone => Some(ecs_world.spawn((
                 Position { x, y },
                 Inspectable {
@@ -429,6 +548,109 @@ impl Spawn {
         }
     }
 
+    /// Spawn fire burning on a tile
+    pub fn fire(ecs_world: &mut World, x: i32, y: i32, tick_counter: i32) -> Entity {
+        ecs_world.spawn((
+            Position { x, y },
+            Renderable {
+                texture_name: TextureName::Particles,
+                texture_region: Rect {
+                    x: DOWN_DIR * TILE_SIZE_F32,
+                    y: FLAME_PARTICLE_TYPE as f32 * TILE_SIZE_F32,
+                    w: TILE_SIZE_F32,
+                    h: TILE_SIZE_F32,
+                },
+                z_index: 0,
+            },
+            Named {
+                name: "fire",
+                attack_verb: Some("burn"),
+            },
+            Fire {},
+            Burning {
+                tick_counter,
+                gives_light: false,
+            },
+            ProduceLight {
+                radius: FIRE_LIGHT_RADIUS,
+            },
+            TurnedOn {},
+            Smellable {
+                smell_log: Some("smoke"),
+                intensity: SmellIntensity::Strong,
+            },
+            ProduceSound {
+                sound_log: "crackling flames",
+            },
+        ))
+    }
+
+    /// Spawn a hidden trap. Some traps give away their presence with sounds or smells
+    pub fn trap(ecs_world: &mut World, x: i32, y: i32, trap_type: TrapType) -> Entity {
+        let (name, tile_index) = match trap_type {
+            TrapType::Pit => ("pit", 0.0),
+            TrapType::FallingRocks => ("falling rocks trap", 1.0),
+            TrapType::SpikePlate => ("spike plate", 2.0),
+            TrapType::AlarmTripwire => ("alarm tripwire", 3.0),
+            TrapType::GasVent => ("gas vent", 4.0),
+        };
+
+        let trap = ecs_world.spawn((
+            Position { x, y },
+            Renderable {
+                texture_name: TextureName::Tiles,
+                texture_region: Rect {
+                    x: tile_index * TILE_SIZE_F32,
+                    y: 6.0 * TILE_SIZE_F32,
+                    w: TILE_SIZE_F32,
+                    h: TILE_SIZE_F32,
+                },
+                z_index: 0,
+            },
+            Named {
+                name,
+                attack_verb: None,
+            },
+            Trap {
+                trap_type,
+                armed: true,
+            },
+            // Traps stay hidden until spotted or triggered, the counter is not used
+            IsHidden { hidden_counter: 0 },
+        ));
+
+        match trap_type {
+            TrapType::FallingRocks => {
+                let _ = ecs_world.insert_one(
+                    trap,
+                    ProduceSound {
+                        sound_log: "pebbles trickling from the ceiling",
+                    },
+                );
+            }
+            TrapType::AlarmTripwire => {
+                let _ = ecs_world.insert_one(
+                    trap,
+                    ProduceSound {
+                        sound_log: "the faint tinkle of a bell",
+                    },
+                );
+            }
+            TrapType::GasVent => {
+                let _ = ecs_world.insert_one(
+                    trap,
+                    Smellable {
+                        smell_log: Some("rotten eggs"),
+                        intensity: SmellIntensity::Faint,
+                    },
+                );
+            }
+            _ => {}
+        }
+
+        trap
+    }
+
     /// Generate ad hoc quaffable entity from lake
     pub fn river_water_entity(ecs_world: &mut World) -> Entity {
         ecs_world.spawn((
@@ -442,6 +664,57 @@ impl Spawn {
             },
         ))
     }
+    /// Make an ordinary wall diggable when something starts tunnelling into it.
+    /// Walls on the border of the zone cannot be tunnelled
+    pub fn tunnel_entity(ecs_world: &mut World, zone: &Zone, x: i32, y: i32) -> Option<Entity> {
+        if x <= 0 || y <= 0 || x >= zone.width - 1 || y >= zone.height - 1 {
+            return None;
+        }
+
+        // Keep digging where someone already started, instead of starting over
+        let index = zone.get_index_from_xy(&x, &y);
+        if let Some(&tunnel) = zone.tile_content[index]
+            .iter()
+            .find(|&&entity| ecs_world.satisfies::<&Diggable>(entity).unwrap_or(false))
+        {
+            return Some(tunnel);
+        }
+
+        match zone.tiles[index] {
+            TileType::Wall => Some(ecs_world.spawn((
+                Position { x, y },
+                Diggable {
+                    dig_points: Roll::dice(WALL_DIG_DICE_NUMBER, TUNNEL_DIG_DICE_SIZE),
+                    produces: DigProductEnum::Stone,
+                },
+            ))),
+            TileType::BrickWall => Some(ecs_world.spawn((
+                Position { x, y },
+                Diggable {
+                    dig_points: Roll::dice(BRICK_WALL_DIG_DICE_NUMBER, TUNNEL_DIG_DICE_SIZE),
+                    produces: DigProductEnum::Stone,
+                },
+            ))),
+            TileType::BigCrystal => {
+                // The crystal already has its own entity, the light goes away with it
+                let crystal = zone.tile_content[index].iter().copied().find(|&entity| {
+                    ecs_world
+                        .satisfies::<&ProduceLight>(entity)
+                        .unwrap_or(false)
+                })?;
+                let _ = ecs_world.insert_one(
+                    crystal,
+                    Diggable {
+                        dig_points: Roll::dice(CRYSTAL_DIG_DICE_NUMBER, TUNNEL_DIG_DICE_SIZE),
+                        produces: DigProductEnum::Nothing,
+                    },
+                );
+                Some(crystal)
+            }
+            _ => None,
+        }
+    }
+
     /// Generate ad hoc quaffable entity from lake
     pub fn edible_stone(ecs_world: &mut World, dig_roll: i32) -> Entity {
         // Nasty hack: let's roll a number of d1 equal to the dig roll
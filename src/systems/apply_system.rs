@@ -13,7 +13,7 @@ use crate::{
     },
     constants::NEXT_TO_DISTANCE,
     engine::state::GameState,
-    maps::zone::{TileType, Zone},
+    maps::zone::TileType,
     utils::common::Utils,
 };
 
@@ -162,9 +162,15 @@ impl ApplySystem {
                 let key_user_position = ecs_world
                     .get::<&Position>(in_backback.owner)
                     .expect("key user must have position");
-                let mut q = ecs_world
-                    .query_one::<(&Position, &mut Lock)>(key.lock)
-                    .unwrap_or_else(|_| panic!("Key lock {} not found", key.lock.id()));
+                // The lock could have been destroyed (like a forced door)
+                let Ok(mut q) = ecs_world.query_one::<(&Position, &mut Lock)>(key.lock) else {
+                    entities_applied.push(applied_key_entity);
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("There is no lock left for this key"));
+                    continue;
+                };
                 let (lock_position, lock) = q.get().expect("q for lock failed");
 
                 if Utils::distance(
@@ -177,30 +183,35 @@ impl ApplySystem {
                     entities_applied.push(applied_key_entity);
                     entities_to_despawn.push(applied_key_entity);
                     lock.keys_to_unlock -= 1;
+                    let lock_index = zone.get_index_from_xy(&lock_position.x, &lock_position.y);
                     //Remove lock when opened
                     if lock.keys_to_unlock == 0 {
                         entities_to_despawn.push(key.lock);
-                        game_state
-                            .game_log
-                            .entries
-                            .push(Cow::Borrowed("The lock opens!"));
-                        zone.tiles[Zone::get_index_from_xy(&lock_position.x, &lock_position.y)] =
-                            TileType::DownPassage;
+                        if zone.tiles[lock_index] == TileType::LockedDoor {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("The door unlocks!"));
+                            zone.tiles[lock_index] = TileType::ClosedDoor;
+                        } else {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("The lock opens!"));
+                            zone.tiles[lock_index] = TileType::DownPassage;
+                        }
                     } else {
                         game_state
                             .game_log
                             .entries
                             .push(Cow::Borrowed("The lock moves, but is not open yet."));
 
-                        zone.tiles[Zone::get_index_from_xy(&lock_position.x, &lock_position.y)] =
-                            match zone.tiles
-                                [Zone::get_index_from_xy(&lock_position.x, &lock_position.y)]
-                            {
-                                TileType::TripleGoldLock(locks_to_open) => {
-                                    TileType::TripleGoldLock(locks_to_open - 1.0)
-                                }
-                                _ => panic!("Unexpected tile type for lock tile"),
+                        zone.tiles[lock_index] = match zone.tiles[lock_index] {
+                            TileType::TripleGoldLock(locks_to_open) => {
+                                TileType::TripleGoldLock(locks_to_open - 1.0)
                             }
+                            _ => panic!("Unexpected tile type for lock tile"),
+                        }
                     }
                 } else {
                     game_state.game_log.add_entry(Cow::Borrowed(
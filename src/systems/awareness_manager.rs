@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+
+use hecs::Entity;
+
+use crate::{
+    components::{
+        combat::{CombatStats, IsHidden, Sneaking},
+        common::{MyTurn, Named, Position, Viewshed},
+        monster::{Awareness, AwarenessState, Monster},
+    },
+    constants::{SNEAK_BONUS, WAKE_UP_DISTANCE},
+    engine::state::GameState,
+    maps::zone::Zone,
+    utils::{common::Utils, roll::Roll},
+};
+
+/// Handles how much monsters are paying attention to the player
+pub struct AwarenessManager {}
+
+impl AwarenessManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        let player_x;
+        let player_y;
+        let stealth;
+        let is_hidden;
+
+        // Scope for keeping borrow checker quiet
+        {
+            let position = ecs_world
+                .get::<&Position>(player)
+                .expect("Player must have Position");
+            let stats = ecs_world
+                .get::<&CombatStats>(player)
+                .expect("Player must have CombatStats");
+            player_x = position.x;
+            player_y = position.y;
+
+            // Sneaking makes it easier to go unnoticed
+            stealth = if ecs_world.satisfies::<&Sneaking>(player).unwrap_or(false) {
+                stats.current_dexterity + SNEAK_BONUS
+            } else {
+                stats.current_dexterity
+            };
+            is_hidden = ecs_world.satisfies::<&IsHidden>(player).unwrap_or(false);
+        }
+
+        let mut awareness_changes: Vec<(Entity, AwarenessState)> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let mut monsters = ecs_world
+                .query::<(&Awareness, &Position, &Viewshed, &Named)>()
+                .with::<(&Monster, &MyTurn)>();
+
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            let player_index = zone.get_index_from_xy(&player_x, &player_y);
+
+            for (monster, (awareness, position, viewshed, named)) in &mut monsters {
+                let sees_player = !is_hidden && viewshed.visible_tiles.contains(&player_index);
+                let is_visible =
+                    zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)];
+
+                match awareness.state {
+                    AwarenessState::Asleep => {
+                        // A clumsy player walking nearby will wake the monster up
+                        if Utils::distance(&position.x, &player_x, &position.y, &player_y)
+                            <= WAKE_UP_DISTANCE
+                            && Roll::d20() > stealth
+                        {
+                            awareness_changes.push((monster, AwarenessState::Alert));
+                            if is_visible {
+                                game_state
+                                    .game_log
+                                    .add_entry(Cow::Owned(format!("The {} wakes up!", named.name)));
+                            }
+                        }
+                    }
+                    AwarenessState::Unaware => {
+                        if sees_player && Roll::d20() > stealth {
+                            awareness_changes.push((monster, AwarenessState::Hunting));
+                            if is_visible {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The {} notices you!",
+                                    named.name
+                                )));
+                            }
+                        }
+                    }
+                    AwarenessState::Alert => {
+                        if sees_player {
+                            awareness_changes.push((monster, AwarenessState::Hunting));
+                        }
+                    }
+                    AwarenessState::Hunting => {
+                        if !sees_player {
+                            awareness_changes.push((monster, AwarenessState::Alert));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (monster, state) in awareness_changes {
+            if let Ok(mut awareness) = ecs_world.get::<&mut Awareness>(monster) {
+                awareness.state = state;
+            }
+        }
+    }
+}
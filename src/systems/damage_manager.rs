@@ -7,14 +7,21 @@ use crate::{
         combat::{CombatStats, Grappled, SufferingDamage},
         common::{Experience, Hates, Named, Position, ProduceCorpse, Species, SpeciesEnum},
         health::{CanAutomaticallyHeal, DiseaseType, Paralyzed},
-        items::{Deadly, Edible},
-        monster::{DiseaseBearer, SingleSnakeCreature, SnakeBody, SnakeHead, Venomous},
+        items::{Deadly, Edible, ShopOwner},
+        monster::{
+            Awareness, AwarenessState, DiseaseBearer, PackMember, SingleSnakeCreature, SnakeBody,
+            SnakeHead, Venomous,
+        },
+    },
+    constants::{
+        AUTO_ADVANCE_EXP_COUNTER_START, MAX_STAMINA_HEAL_TICK_COUNTER, REPUTATION_ATTACK_PENALTY,
     },
-    constants::{AUTO_ADVANCE_EXP_COUNTER_START, MAX_STAMINA_HEAL_TICK_COUNTER},
     engine::state::{GameState, RunState},
     maps::zone::{DecalType, Zone},
     spawning::spawner::{CorpseSpawnData, Spawn},
-    systems::item_dropping::ItemDropping,
+    systems::{
+        faction_manager::FactionManager, item_dropping::ItemDropping, morale_manager::MoraleManager,
+    },
     utils::roll::Roll,
 };
 
@@ -26,6 +33,10 @@ pub struct DamageManager {}
 impl DamageManager {
     pub fn run(game_state: &mut GameState) {
         let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let mut attacked_members: Vec<Entity> = Vec::new();
         let zone = game_state
             .current_zone
             .as_mut()
@@ -48,7 +59,7 @@ impl DamageManager {
                     damageable.damage_received = 0;
                     //Drench the tile with blood
                     zone.decals_tiles.insert(
-                        Zone::get_index_from_xy(&position.x, &position.y),
+                        zone.get_index_from_xy(&position.x, &position.y),
                         DecalType::Blood,
                     );
                 }
@@ -86,6 +97,19 @@ impl DamageManager {
                     target_hates.list.insert(damager.id());
                 }
 
+                // Hurting a member of a faction makes the whole faction like the player less
+                if damageable
+                    .damager
+                    .is_some_and(|damager| damager.id() == player.id())
+                {
+                    attacked_members.push(damaged_entity);
+                }
+
+                // Getting hurt wakes up anyone
+                if let Ok(mut awareness) = ecs_world.get::<&mut Awareness>(damaged_entity) {
+                    awareness.state = AwarenessState::Hunting;
+                }
+
                 must_reset_heal_counter = true;
                 stats.current_stamina -= damageable.damage_received;
                 //Decrease stamina. If less then 0, delta is subtracted from toughness
@@ -97,7 +121,7 @@ impl DamageManager {
 
                 //Drench the tile with blood
                 zone.decals_tiles.insert(
-                    Zone::get_index_from_xy(&position.x, &position.y),
+                    zone.get_index_from_xy(&position.x, &position.y),
                     DecalType::Blood,
                 );
             }
@@ -132,6 +156,20 @@ impl DamageManager {
                 can_heal.tick_counter = MAX_STAMINA_HEAL_TICK_COUNTER + 2;
             }
         }
+
+        for member in attacked_members {
+            // Shop owners remember being hurt and raise their prices
+            if let Ok(mut shop_owner) = ecs_world.get::<&mut ShopOwner>(member) {
+                shop_owner.times_wronged += 1;
+            }
+            FactionManager::change_reputation(
+                ecs_world,
+                &mut game_state.game_log,
+                player,
+                member,
+                -REPUTATION_ATTACK_PENALTY,
+            );
+        }
     }
 
     /// Check which entities are dead and removes them. Returns true if Player is dead
@@ -187,8 +225,7 @@ impl DamageManager {
                                 .entries
                                 .push(Cow::Borrowed("You stagger in pain!"));
                         }
-                    } else if zone.visible_tiles[Zone::get_index_from_xy(&position.x, &position.y)]
-                    {
+                    } else if zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)] {
                         // Log npc deaths only if visible by player
                         if is_killed {
                             game_state
@@ -249,6 +286,27 @@ impl DamageManager {
 
             DamageManager::handle_snake_entity_death(ecs_world, zone, killed_entity, damager_opt);
             DamageManager::handle_grappler_death(ecs_world, killed_entity);
+            DamageManager::handle_pack_leader_death(ecs_world, killed_entity);
+
+            // Monsters that saw their kin die could lose courage
+            if let Ok(species) = ecs_world.get::<&Species>(killed_entity) {
+                MoraleManager::register_death(ecs_world, zone, x, y, &species.value);
+            }
+
+            // Members of its faction that saw the player kill it will not forget
+            if let Some(damager) = damager_opt
+                && damager.id() == player_id
+            {
+                FactionManager::register_kill(
+                    ecs_world,
+                    &mut game_state.game_log,
+                    zone,
+                    damager,
+                    killed_entity,
+                    x,
+                    y,
+                );
+            }
 
             ItemDropping::drop_all_of(killed_entity, ecs_world, x, y);
 
@@ -341,7 +399,7 @@ impl DamageManager {
                     .expect("Snake body should have position");
                 //Drench the tile of the body with blood
                 zone.decals_tiles.insert(
-                    Zone::get_index_from_xy(&position.x, &position.y),
+                    zone.get_index_from_xy(&position.x, &position.y),
                     DecalType::Blood,
                 );
             }
@@ -395,4 +453,23 @@ impl DamageManager {
             let _ = ecs_world.remove_one::<Grappled>(grappler);
         }
     }
+
+    /// Without their leader, pack members break off and go on their own
+    fn handle_pack_leader_death(ecs_world: &mut World, killed_entity: Entity) {
+        let mut leaderless: Vec<Entity> = Vec::new();
+        // Scope to keep the borrow checker quiet
+        {
+            let mut pack_members = ecs_world.query::<&PackMember>();
+
+            for (entity, pack_member) in &mut pack_members.iter() {
+                if pack_member.leader.id() == killed_entity.id() {
+                    leaderless.push(entity);
+                }
+            }
+        }
+
+        for member in leaderless {
+            let _ = ecs_world.remove_one::<PackMember>(member);
+        }
+    }
 }
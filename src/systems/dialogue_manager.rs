@@ -0,0 +1,330 @@
+use std::borrow::Cow;
+
+use hecs::{Entity, World};
+
+use crate::{
+    components::{
+        actions::{WantsItem, WantsToTrade},
+        common::{
+            DialogueCondition, DialogueEffect, DialogueNode, Hates, Named, Position, Species,
+            SpellList, WillChat,
+        },
+        items::{Equipped, InBackback, Item},
+        player::Player,
+    },
+    constants::{REPUTATION_HELP_BONUS, STANDARD_ACTION_MULTIPLIER},
+    engine::state::{GameState, RunState},
+    inventory::InventoryAction,
+    maps::zone::{TileType, Zone},
+    systems::{faction_manager::FactionManager, quest_manager::QuestManager},
+    utils::{common::Utils, dialog::DialogAction},
+};
+
+/// Creature talking, current node and options that can be chosen in it
+pub type ChatDtt = (Entity, usize, Vec<usize>);
+
+/// Handles conversations between the player and creatures with a dialogue tree
+pub struct DialogueManager {}
+
+impl DialogueManager {
+    /// Start a conversation from the first node of the creature's dialogue tree
+    pub fn start_chat(game_state: &mut GameState, chatter: Entity) {
+        DialogueManager::open_node(game_state, chatter, 0);
+    }
+
+    /// Apply the effects of the chosen option and go on with the conversation
+    pub fn choose_option(game_state: &mut GameState, chat_info: ChatDtt, choice: usize) {
+        let (chatter, node_index, available_options) = chat_info;
+        let Some(tree) = DialogueManager::get_tree(&game_state.ecs_world, chatter) else {
+            DialogueManager::end_chat(game_state);
+            return;
+        };
+        let option = &tree[node_index].options[available_options[choice]];
+
+        let mut starts_trade = false;
+        for effect in option.effects {
+            DialogueManager::apply_effect(game_state, chatter, effect);
+            starts_trade |= matches!(effect, DialogueEffect::StartTrade);
+        }
+
+        if starts_trade {
+            game_state.run_state = RunState::ShowInventory(InventoryAction::Trade);
+        } else if let Some(next) = option.next {
+            DialogueManager::open_node(game_state, chatter, next);
+        } else {
+            DialogueManager::end_chat(game_state);
+        }
+    }
+
+    /// Talking takes time. Gifts will be picked up, which takes time on its own
+    pub fn end_chat(game_state: &mut GameState) {
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        if !game_state
+            .ecs_world
+            .satisfies::<&WantsItem>(player)
+            .unwrap_or(false)
+        {
+            Player::wait_after_action(game_state, STANDARD_ACTION_MULTIPLIER);
+        }
+        game_state.run_state = RunState::DoTick;
+    }
+
+    /// Get the dialogue tree of a creature, if it has one
+    pub fn get_tree(ecs_world: &World, chatter: Entity) -> Option<&'static [DialogueNode]> {
+        ecs_world
+            .get::<&WillChat>(chatter)
+            .ok()
+            .and_then(|will_chat| will_chat.tree)
+    }
+
+    /// Show a node of the conversation with only the options whose conditions are met
+    fn open_node(game_state: &mut GameState, chatter: Entity, node_index: usize) {
+        let Some(tree) = DialogueManager::get_tree(&game_state.ecs_world, chatter) else {
+            DialogueManager::end_chat(game_state);
+            return;
+        };
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let depth = game_state
+            .current_zone
+            .as_ref()
+            .expect("must have Some Zone")
+            .depth;
+
+        let available_options: Vec<usize> = tree[node_index]
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(index, option)| {
+                if option.conditions.iter().all(|condition| {
+                    DialogueManager::check_condition(
+                        &game_state.ecs_world,
+                        depth,
+                        player,
+                        chatter,
+                        condition,
+                    )
+                }) {
+                    Some(index)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        game_state.run_state =
+            RunState::ShowDialog(DialogAction::Chat((chatter, node_index, available_options)));
+    }
+
+    fn check_condition(
+        ecs_world: &World,
+        depth: u32,
+        player: Entity,
+        chatter: Entity,
+        condition: &DialogueCondition,
+    ) -> bool {
+        match condition {
+            DialogueCondition::HasItem(name) => {
+                DialogueManager::find_item(ecs_world, player, name).is_some()
+            }
+            DialogueCondition::PlayerSpecies(species) => ecs_world
+                .get::<&Species>(player)
+                .is_ok_and(|player_species| player_species.value == *species),
+            DialogueCondition::MinDepth(min_depth) => depth >= *min_depth,
+            DialogueCondition::HatesPlayer => ecs_world
+                .get::<&Hates>(chatter)
+                .is_ok_and(|hates| hates.list.contains(&player.id())),
+            DialogueCondition::QuestActive(id) => QuestManager::is_active(ecs_world, player, *id),
+            DialogueCondition::QuestReady(id) => QuestManager::is_ready(ecs_world, player, *id),
+            DialogueCondition::KnowsSpell(name) => {
+                DialogueManager::knows_spell(ecs_world, player, name)
+            }
+            DialogueCondition::Not(negated) => {
+                !DialogueManager::check_condition(ecs_world, depth, player, chatter, negated)
+            }
+        }
+    }
+
+    fn apply_effect(game_state: &mut GameState, chatter: Entity, effect: &DialogueEffect) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let chatter_name = ecs_world
+            .get::<&Named>(chatter)
+            .expect("Chatter must be Named")
+            .name;
+
+        match effect {
+            DialogueEffect::GiveItem(name) => {
+                let Some(item) = DialogueManager::find_item(ecs_world, chatter, name) else {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "The {} has no {} to give",
+                        chatter_name, name
+                    )));
+                    return;
+                };
+
+                // The gift is put at the player's feet and picked up
+                let (x, y) = {
+                    let position = ecs_world
+                        .get::<&Position>(player)
+                        .expect("Player must have Position");
+                    (position.x, position.y)
+                };
+                let _ = ecs_world.remove_one::<Equipped>(item);
+                let _ = ecs_world.exchange_one::<InBackback, Position>(item, Position { x, y });
+                if let Ok(mut wants_item) = ecs_world.get::<&mut WantsItem>(player) {
+                    wants_item.items.push(item);
+                } else {
+                    let _ = ecs_world.insert_one(
+                        player,
+                        WantsItem {
+                            items: vec![item],
+                            was_bought: false,
+                        },
+                    );
+                }
+                game_state.game_log.add_entry(Cow::Owned(format!(
+                    "The {} gives you a {}",
+                    chatter_name, name
+                )));
+            }
+            DialogueEffect::TakeItem(name) => {
+                if let Some(item) = DialogueManager::find_item(ecs_world, player, name) {
+                    let _ = ecs_world.remove_one::<Equipped>(item);
+                    if let Ok(mut in_backpack) = ecs_world.get::<&mut InBackback>(item) {
+                        in_backpack.owner = chatter;
+                    }
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "You give your {} to the {}",
+                        name, chatter_name
+                    )));
+                    FactionManager::change_reputation(
+                        ecs_world,
+                        &mut game_state.game_log,
+                        player,
+                        chatter,
+                        REPUTATION_HELP_BONUS,
+                    );
+                }
+            }
+            DialogueEffect::RevealMapArea(radius) => {
+                let zone = game_state
+                    .current_zone
+                    .as_mut()
+                    .expect("must have Some Zone");
+
+                // Creatures will tell where the way down is
+                if let Some(passage_index) = zone
+                    .tiles
+                    .iter()
+                    .position(|tile| *tile == TileType::DownPassage)
+                {
+                    let (passage_x, passage_y) = zone.get_xy_from_index(passage_index);
+                    for index in 0..zone.revealed_tiles.len() {
+                        let (x, y) = zone.get_xy_from_index(index);
+                        if Utils::distance(&x, &passage_x, &y, &passage_y) <= *radius as f32 {
+                            zone.revealed_tiles[index] = true;
+                        }
+                    }
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "The {} tells you the way down",
+                        chatter_name
+                    )));
+                }
+            }
+            DialogueEffect::StartTrade => {
+                let _ = ecs_world.insert_one(
+                    player,
+                    WantsToTrade {
+                        target: chatter,
+                        item: None,
+                    },
+                );
+            }
+            DialogueEffect::SetHostility(is_hostile) => {
+                if let Ok(mut hates) = ecs_world.get::<&mut Hates>(chatter) {
+                    if *is_hostile {
+                        hates.list.insert(player.id());
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("The {} gets angry!", chatter_name)));
+                    } else {
+                        hates.list.remove(&player.id());
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("The {} calms down", chatter_name)));
+                    }
+                }
+            }
+            DialogueEffect::TeachSpell(spawn_spell) => {
+                let spell = spawn_spell(ecs_world);
+                let spell_name = ecs_world
+                    .get::<&Named>(spell)
+                    .expect("Spell must be Named")
+                    .name;
+
+                // A spell can't be learned twice
+                if DialogueManager::knows_spell(ecs_world, player, spell_name) {
+                    let _ = ecs_world.despawn(spell);
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "You already know how to cast {}",
+                        spell_name
+                    )));
+                    return;
+                }
+
+                if let Ok(mut spell_list) = ecs_world.get::<&mut SpellList>(player) {
+                    spell_list.spells.push(spell);
+                } else {
+                    let _ = ecs_world.insert_one(
+                        player,
+                        SpellList {
+                            spells: vec![spell],
+                        },
+                    );
+                }
+                game_state.game_log.add_entry(Cow::Owned(format!(
+                    "The {} teaches you how to cast {}",
+                    chatter_name, spell_name
+                )));
+            }
+            DialogueEffect::StartQuest(id) => {
+                QuestManager::start_quest(game_state, chatter, *id);
+            }
+            DialogueEffect::CompleteQuest(id) => {
+                QuestManager::turn_in(game_state, chatter, *id);
+            }
+        }
+    }
+
+    /// Check if the caster already knows a spell with the given name
+    fn knows_spell(ecs_world: &World, caster: Entity, name: &str) -> bool {
+        ecs_world.get::<&SpellList>(caster).is_ok_and(|list| {
+            list.spells.iter().any(|&known| {
+                ecs_world
+                    .get::<&Named>(known)
+                    .is_ok_and(|named| named.name == name)
+            })
+        })
+    }
+
+    /// Find an item with the given name carried by the owner
+    fn find_item(ecs_world: &World, owner: Entity, name: &str) -> Option<Entity> {
+        ecs_world
+            .query::<(&InBackback, &Named)>()
+            .with::<&Item>()
+            .iter()
+            .find_map(|(item, (in_backpack, named))| {
+                if in_backpack.owner.id() == owner.id() && named.name == name {
+                    Some(item)
+                } else {
+                    None
+                }
+            })
+    }
+}
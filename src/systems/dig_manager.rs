@@ -5,11 +5,13 @@ use hecs::Entity;
 use crate::{
     components::{
         actions::{WantsToDig, WantsToEat},
-        combat::{CombatStats, InflictsDamage},
+        combat::{CombatStats, InflictsDamage, SufferingDamage},
         common::{DigProductEnum, Diggable, MyTurn, Named, Position},
+        player::Player,
     },
+    constants::{CAVE_IN_CHANCE, CAVE_IN_DAMAGE_DICE},
     engine::state::GameState,
-    maps::zone::{TileType, Zone},
+    maps::zone::{NoiseType, TileType, Zone},
     spawning::spawner::Spawn,
     utils::{common::Utils, roll::Roll},
 };
@@ -27,6 +29,8 @@ impl DigManager {
         let mut digged_list: Vec<Entity> = Vec::new();
         let mut produced_list: Vec<(i32, i32, DigProductEnum)> = Vec::new();
         let mut wants_to_eat_list: Vec<(Entity, i32)> = Vec::new();
+        let mut cave_in_list: Vec<(i32, i32)> = Vec::new();
+        let mut must_recalculate_view = false;
 
         // Scope for keeping borrow checker quiet
         {
@@ -81,25 +85,70 @@ impl DigManager {
                     wants_to_eat_list.push((digger, dig_roll));
                 }
 
+                let target_index = zone.get_index_from_xy(&pos.x, &pos.y);
+                let is_door = zone.tiles[target_index] == TileType::LockedDoor;
+                // Ordinary walls are tunnelled through, instead of opening along a crack
+                let is_tunnel = matches!(
+                    zone.tiles[target_index],
+                    TileType::Wall | TileType::BrickWall
+                );
+                let target_name = match zone.tiles[target_index] {
+                    TileType::Wall => "stone wall",
+                    TileType::BrickWall => "brick wall",
+                    TileType::BigCrystal => "big crystal",
+                    _ => "cracked stone wall",
+                };
+
                 if digger.id() == player_id {
-                    game_state
-                        .game_log
-                        .entries
-                        .push(Cow::Borrowed("You dig the cracked stone wall"));
-                } else if zone.visible_tiles[Zone::get_index_from_xy(&pos.x, &pos.y)] {
-                    game_state.game_log.add_entry(Cow::Owned(format!(
-                        "The {} digs the cracked stone wall",
-                        named.name
-                    )));
+                    // Digging is a noisy business
+                    zone.add_noise(pos.x, pos.y, NoiseType::Digging);
+                    if is_door {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("You hack at the locked door"));
+                    } else {
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("You dig the {}", target_name)));
+                    }
+                } else if zone.visible_tiles[target_index] {
+                    if is_door {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} hacks at the locked door",
+                            named.name
+                        )));
+                    } else {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} digs the {}",
+                            named.name, target_name
+                        )));
+                    }
                 }
 
-                // Clear path if digged enough
+                // Clear path if digged enough. Doors are left broken on their hinges
                 if diggable.dig_points <= 0 {
-                    if zone.visible_tiles[Zone::get_index_from_xy(&pos.x, &pos.y)] {
+                    if zone.visible_tiles[target_index] {
+                        if is_door {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("The locked door breaks!"));
+                        } else if zone.tiles[target_index] == TileType::BigCrystal {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("The big crystal shatters!"));
+                        } else {
+                            game_state
+                                .game_log
+                                .add_entry(Cow::Owned(format!("The {} opens!", target_name)));
+                        }
+                    } else if is_door {
                         game_state
                             .game_log
                             .entries
-                            .push(Cow::Borrowed("The cracked wall opens!"));
+                            .push(Cow::Borrowed("You hear splintering wood"));
                     } else {
                         game_state
                             .game_log
@@ -107,14 +156,30 @@ impl DigManager {
                             .push(Cow::Borrowed("You hear falling rocks"));
                     }
 
-                    zone.tiles[Zone::get_index_from_xy(&pos.x, &pos.y)] = TileType::Floor;
+                    if is_door {
+                        zone.tiles[target_index] = TileType::BrokenDoor;
+                    } else {
+                        zone.tiles[target_index] = TileType::Floor;
+                    }
+                    zone.blocked_tiles[target_index] = false;
+                    zone.must_recalculate_lava_light = true;
+                    must_recalculate_view = true;
 
                     digged_list.push(wants_to_dig.target);
 
+                    // A fresh tunnel could collapse on whoever is around
+                    if is_tunnel && Roll::d20() <= CAVE_IN_CHANCE {
+                        cave_in_list.push((pos.x, pos.y));
+                    }
+
                     match diggable.produces {
                         DigProductEnum::Gold => {
                             produced_list.push((pos.x, pos.y, diggable.produces.clone()));
                         }
+                        // A whole wall leaves plenty of rubble behind
+                        DigProductEnum::Stone if is_tunnel => {
+                            produced_list.push((pos.x, pos.y, diggable.produces.clone()));
+                        }
                         _ => {
                             // Could randomly produce the diggable's product (usually stone)
                             if Roll::d6() == 1 {
@@ -150,6 +215,82 @@ impl DigManager {
                 DigProductEnum::Stone => {
                     let _ = Spawn::slingshot_ammo(ecs_world, x, y);
                 }
+                DigProductEnum::Nothing => {}
+            }
+        }
+
+        for (x, y) in cave_in_list {
+            DigManager::cave_in(game_state, x, y);
+        }
+
+        if must_recalculate_view {
+            Player::force_view_recalculation(game_state);
+        }
+    }
+
+    /// Rocks fall from the ceiling of a new tunnel, hitting everyone next to it
+    fn cave_in(game_state: &mut GameState, x: i32, y: i32) {
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+
+        zone.add_noise(x, y, NoiseType::Digging);
+        if zone.visible_tiles[zone.get_index_from_xy(&x, &y)] {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("The ceiling caves in!"));
+        } else {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("You hear a rumbling collapse"));
+        }
+
+        let mut victims =
+            game_state
+                .ecs_world
+                .query::<(&Position, &CombatStats, &mut SufferingDamage, &Named)>();
+
+        for (victim, (position, stats, suffering_damage, named)) in &mut victims {
+            if (position.x - x).abs() > 1 || (position.y - y).abs() > 1 {
+                continue;
+            }
+
+            let is_player = victim.id() == player_id;
+            let is_visible = zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)];
+
+            // Dexterity save to dodge the rocks
+            if Roll::d20() <= stats.current_dexterity {
+                if is_player {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("You dodge the falling rocks!"));
+                } else if is_visible {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "The {} dodges the falling rocks",
+                        named.name
+                    )));
+                }
+            } else {
+                let damage = Roll::dice(1, CAVE_IN_DAMAGE_DICE);
+                suffering_damage.damage_received += damage;
+                if is_player {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "Rocks fall on you for {} damage!",
+                        damage
+                    )));
+                } else if is_visible {
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("Rocks fall on the {}", named.name)));
+                }
             }
         }
     }
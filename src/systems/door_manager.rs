@@ -0,0 +1,228 @@
+use std::{borrow::Cow, cmp::max};
+
+use hecs::Entity;
+
+use crate::{
+    components::{
+        actions::{WantsToCloseDoor, WantsToOpenDoor},
+        combat::CombatStats,
+        common::{Lock, MyTurn, Named},
+        monster::Smart,
+        player::Player,
+    },
+    constants::FORCE_DOOR_PENALTY,
+    engine::state::GameState,
+    maps::zone::{NoiseType, TileType, Zone},
+    utils::{common::Utils, roll::Roll},
+};
+
+/// Handles opening, closing, forcing and bashing doors
+pub struct DoorManager {}
+
+impl DoorManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+
+        let mut openers_list: Vec<(Entity, i32)> = Vec::new();
+        let mut closers_list: Vec<(Entity, i32)> = Vec::new();
+        let mut destroyed_locks: Vec<usize> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_mut()
+                .expect("must have Some Zone");
+
+            let mut openers = ecs_world
+                .query::<(&WantsToOpenDoor, &CombatStats, &Named, Option<&Smart>)>()
+                .with::<&MyTurn>();
+
+            for (opener, (wants_to_open, stats, named, smart_opt)) in &mut openers {
+                openers_list.push((opener, stats.speed));
+                let is_player = opener.id() == player_id;
+                let door_index =
+                    zone.get_index_from_xy(&wants_to_open.target.0, &wants_to_open.target.1);
+                let is_visible = zone.visible_tiles[door_index];
+
+                match zone.tiles[door_index] {
+                    TileType::ClosedDoor => {
+                        // Only the player and smart monsters know how to use a handle, the others bash doors open
+                        if is_player || smart_opt.is_some() {
+                            zone.tiles[door_index] = TileType::OpenDoor;
+                            // An open door lets the glow of lava through
+                            zone.must_recalculate_lava_light = true;
+                            if is_player {
+                                game_state
+                                    .game_log
+                                    .entries
+                                    .push(Cow::Borrowed("You open the door"));
+                            } else if is_visible {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The {} opens the door",
+                                    named.name
+                                )));
+                            }
+                        } else if Roll::d20() <= stats.current_toughness {
+                            zone.tiles[door_index] = TileType::BrokenDoor;
+                            zone.must_recalculate_lava_light = true;
+                            if is_visible {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The {} bashes the door open!",
+                                    named.name
+                                )));
+                            } else {
+                                game_state
+                                    .game_log
+                                    .entries
+                                    .push(Cow::Borrowed("You hear splintering wood"));
+                            }
+                        } else if is_visible {
+                            game_state.game_log.add_entry(Cow::Owned(format!(
+                                "The {} bashes the door",
+                                named.name
+                            )));
+                        } else {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("You hear a loud thud"));
+                        }
+                    }
+                    TileType::LockedDoor => {
+                        // Force the door with a strength or dexterity roll, whichever is better.
+                        // Strong ones break the door, nimble ones break the lock
+                        if Roll::d20() + FORCE_DOOR_PENALTY
+                            <= max(stats.current_toughness, stats.current_dexterity)
+                        {
+                            destroyed_locks.push(door_index);
+                            zone.must_recalculate_lava_light = true;
+                            if stats.current_toughness >= stats.current_dexterity {
+                                zone.tiles[door_index] = TileType::BrokenDoor;
+                            } else {
+                                zone.tiles[door_index] = TileType::OpenDoor;
+                            }
+
+                            if is_player {
+                                zone.add_noise(
+                                    wants_to_open.target.0,
+                                    wants_to_open.target.1,
+                                    NoiseType::BreakingDoor,
+                                );
+                                game_state
+                                    .game_log
+                                    .entries
+                                    .push(Cow::Borrowed("You force the locked door open!"));
+                            } else if is_visible {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The {} forces the locked door open!",
+                                    named.name
+                                )));
+                            } else {
+                                game_state
+                                    .game_log
+                                    .entries
+                                    .push(Cow::Borrowed("You hear splintering wood"));
+                            }
+                        } else if is_player {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("The door is locked and does not budge"));
+                        } else if is_visible {
+                            game_state.game_log.add_entry(Cow::Owned(format!(
+                                "The {} tries to force the locked door",
+                                named.name
+                            )));
+                        }
+                    }
+                    _ => {
+                        if is_player {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("There is no closed door there"));
+                        }
+                    }
+                }
+            }
+
+            let mut closers = ecs_world
+                .query::<(&WantsToCloseDoor, &CombatStats, &Named)>()
+                .with::<&MyTurn>();
+
+            for (closer, (wants_to_close, stats, named)) in &mut closers {
+                closers_list.push((closer, stats.speed));
+                let is_player = closer.id() == player_id;
+                let door_index =
+                    zone.get_index_from_xy(&wants_to_close.target.0, &wants_to_close.target.1);
+
+                if zone.tiles[door_index] != TileType::OpenDoor {
+                    if is_player {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("There is no open door there"));
+                    }
+                } else if !zone.tile_content[door_index].is_empty() {
+                    // Cannot close a door on something standing or lying in the doorway
+                    if is_player {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("Something is in the way"));
+                    }
+                } else {
+                    zone.tiles[door_index] = TileType::ClosedDoor;
+                    zone.must_recalculate_lava_light = true;
+                    if is_player {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("You close the door"));
+                    } else if zone.visible_tiles[door_index] {
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("The {} closes the door", named.name)));
+                    }
+                }
+            }
+        }
+
+        // Forced doors have no lock anymore
+        for door_index in destroyed_locks {
+            let lock_opt = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone")
+                .tile_content[door_index]
+                .iter()
+                .copied()
+                .find(|&entity| ecs_world.satisfies::<&Lock>(entity).unwrap_or(false));
+            if let Some(lock) = lock_opt {
+                let _ = ecs_world.despawn(lock);
+            }
+        }
+
+        let must_recalculate_view = !openers_list.is_empty() || !closers_list.is_empty();
+
+        for (opener, speed) in openers_list {
+            let _ = ecs_world.remove_one::<WantsToOpenDoor>(opener);
+            Utils::wait_after_action(ecs_world, opener, speed);
+        }
+
+        for (closer, speed) in closers_list {
+            let _ = ecs_world.remove_one::<WantsToCloseDoor>(closer);
+            Utils::wait_after_action(ecs_world, closer, speed);
+        }
+
+        // Doors change what can be seen
+        if must_recalculate_view {
+            Player::force_view_recalculation(game_state);
+        }
+    }
+}
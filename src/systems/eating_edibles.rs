@@ -11,7 +11,7 @@ use crate::{
         combat::{CombatStats, SufferingDamage},
         common::{Hates, Named, Position},
         health::{DiseaseType, Diseased, Hunger},
-        items::{Corpse, Deadly, Edible, Poisonous, Rotten},
+        items::{Corpse, Deadly, Edible, Poisonous, Rotten, ShopOwner},
         monster::DiseaseBearer,
     },
     constants::MAX_DISEASE_TICK_COUNTER,
@@ -76,8 +76,7 @@ impl EatingEdibles {
                             named_edible.name,
                             Utils::get_corpse_string(corpse_opt.is_some())
                         )));
-                    } else if zone.visible_tiles[Zone::get_index_from_xy(&position.x, &position.y)]
-                    {
+                    } else if zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)] {
                         // Log NPC infighting only if visible
                         game_state.game_log.add_entry(Cow::Owned(format!(
                             "{} ate a {}{}",
@@ -152,7 +151,7 @@ impl EatingEdibles {
                                     .push(Cow::Borrowed("You ate poisonous food! You vomit!"));
                             }
                         } else if zone.visible_tiles
-                            [Zone::get_index_from_xy(&position.x, &position.y)]
+                            [zone.get_index_from_xy(&position.x, &position.y)]
                         {
                             // Log NPC infighting only if visible
                             game_state
@@ -162,7 +161,7 @@ impl EatingEdibles {
                         }
 
                         zone.decals_tiles.insert(
-                            Zone::get_index_from_xy(&position.x, &position.y),
+                            zone.get_index_from_xy(&position.x, &position.y),
                             DecalType::Vomit,
                         );
                     } else {
@@ -174,20 +173,24 @@ impl EatingEdibles {
 
                     // Check if the item is being stolen from a shop
                     if let Ok(item_pos) = ecs_world.get::<&Position>(wants_to_eat.item)
-                        && let Some(owner) =
-                            Utils::get_item_owner_by_position(ecs_world, &item_pos.x, &item_pos.y)
+                        && let Some(owner) = Utils::get_item_owner_by_position(
+                            ecs_world,
+                            zone,
+                            &item_pos.x,
+                            &item_pos.y,
+                        )
                     {
                         let mut shop_owner_query = ecs_world
-                            .query_one::<(&mut Hates, &Named)>(owner)
+                            .query_one::<(&mut Hates, &Named, &mut ShopOwner)>(owner)
                             .expect("owner must be named and hate");
-                        if let Some((hates, named_owner)) = shop_owner_query.get() {
+                        if let Some((hates, named_owner, shop_owner)) = shop_owner_query.get() {
                             if eater.id() == player_id {
                                 game_state.game_log.add_entry(Cow::Owned(format!(
                                     "You eat the stolen {}! The {} gets angry!",
                                     named_edible.name, named_owner.name
                                 )));
                             } else if zone.visible_tiles
-                                [Zone::get_index_from_xy(&item_pos.x, &item_pos.y)]
+                                [zone.get_index_from_xy(&item_pos.x, &item_pos.y)]
                             {
                                 game_state.game_log.add_entry(Cow::Owned(format!(
                                     "The {} eats the stolen {}! The {} gets angry!",
@@ -196,6 +199,7 @@ impl EatingEdibles {
                             }
 
                             hates.list.insert(eater.id());
+                            shop_owner.times_wronged += 1;
                         }
                     }
                 } else {
@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+use hecs::{Entity, World};
+
+use crate::{
+    components::common::{Faction, FactionEnum, GameLog, Hates, Reputation, Viewshed},
+    constants::{
+        FRIENDLY_REPUTATION, HOSTILE_REPUTATION, MAX_REPUTATION, MIN_REPUTATION,
+        REPUTATION_KILL_PENALTY, UNFRIENDLY_REPUTATION,
+    },
+    maps::zone::Zone,
+};
+
+/// Handles the reputation of the player with the factions of the world
+pub struct FactionManager {}
+
+impl FactionManager {
+    /// Change the reputation of the player with the faction of a creature, if it has any
+    pub fn change_reputation(
+        ecs_world: &World,
+        game_log: &mut GameLog,
+        player: Entity,
+        member: Entity,
+        delta: i32,
+    ) {
+        let Ok(faction) = ecs_world
+            .get::<&Faction>(member)
+            .map(|faction| faction.value)
+        else {
+            return;
+        };
+        FactionManager::change_faction_reputation(ecs_world, game_log, player, faction, delta);
+    }
+
+    /// Change the reputation of the player with a faction
+    pub fn change_faction_reputation(
+        ecs_world: &World,
+        game_log: &mut GameLog,
+        player: Entity,
+        faction: FactionEnum,
+        delta: i32,
+    ) {
+        let Ok(mut reputation) = ecs_world.get::<&mut Reputation>(player) else {
+            return;
+        };
+
+        let value = reputation.values.entry(faction).or_insert(0);
+        let old_value = *value;
+        *value = (*value + delta).clamp(MIN_REPUTATION, MAX_REPUTATION);
+
+        // Tell the player only when the attitude of the faction changes
+        let faction_name = FactionManager::get_faction_name(&faction);
+        let new_value = *value;
+        let message_opt = if old_value < FRIENDLY_REPUTATION && new_value >= FRIENDLY_REPUTATION {
+            Some("now consider you a friend")
+        } else if old_value >= FRIENDLY_REPUTATION && new_value < FRIENDLY_REPUTATION {
+            Some("do not consider you a friend anymore")
+        } else if old_value > HOSTILE_REPUTATION && new_value <= HOSTILE_REPUTATION {
+            Some("want you dead")
+        } else if old_value <= HOSTILE_REPUTATION && new_value > HOSTILE_REPUTATION {
+            Some("do not want you dead anymore")
+        } else if old_value > UNFRIENDLY_REPUTATION && new_value <= UNFRIENDLY_REPUTATION {
+            Some("do not want to deal with you anymore")
+        } else if old_value <= UNFRIENDLY_REPUTATION && new_value > UNFRIENDLY_REPUTATION {
+            Some("are willing to deal with you again")
+        } else {
+            None
+        };
+
+        if let Some(message) = message_opt {
+            game_log.add_entry(Cow::Owned(format!("The {} {}", faction_name, message)));
+        }
+    }
+
+    /// Killing a creature in sight of its fellow members angers the whole faction
+    pub fn register_kill(
+        ecs_world: &World,
+        game_log: &mut GameLog,
+        zone: &Zone,
+        player: Entity,
+        killed: Entity,
+        x: i32,
+        y: i32,
+    ) {
+        let Ok(faction) = ecs_world
+            .get::<&Faction>(killed)
+            .map(|faction| faction.value)
+        else {
+            return;
+        };
+        let index = zone.get_index_from_xy(&x, &y);
+
+        let witnesses: Vec<Entity> = ecs_world
+            .query::<(&Faction, &Viewshed)>()
+            .iter()
+            .filter_map(|(witness, (witness_faction, viewshed))| {
+                if witness.id() != killed.id()
+                    && witness_faction.value == faction
+                    && viewshed.visible_tiles.contains(&index)
+                {
+                    Some(witness)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if witnesses.is_empty() {
+            return;
+        }
+
+        for witness in witnesses {
+            if let Ok(mut hates) = ecs_world.get::<&mut Hates>(witness) {
+                hates.list.insert(player.id());
+            }
+        }
+
+        FactionManager::change_reputation(
+            ecs_world,
+            game_log,
+            player,
+            killed,
+            -REPUTATION_KILL_PENALTY,
+        );
+    }
+
+    /// Members of the factions that want the player dead will hunt it as soon as they are spawned
+    pub fn apply_to_zone(ecs_world: &World, player: Entity) {
+        let Ok(reputation) = ecs_world.get::<&Reputation>(player) else {
+            return;
+        };
+
+        for (_, (faction, hates)) in &mut ecs_world.query::<(&Faction, &mut Hates)>() {
+            if reputation
+                .values
+                .get(&faction.value)
+                .is_some_and(|&value| value <= HOSTILE_REPUTATION)
+            {
+                hates.list.insert(player.id());
+            }
+        }
+    }
+
+    /// True if the faction of the creature likes the player enough to ignore any species hatred
+    pub fn is_friendly(ecs_world: &World, player: Entity, member: Entity) -> bool {
+        FactionManager::get_reputation(ecs_world, player, member)
+            .is_some_and(|value| value >= FRIENDLY_REPUTATION)
+    }
+
+    /// True if the faction of the creature refuses to chat or trade with the player
+    pub fn is_unfriendly(ecs_world: &World, player: Entity, member: Entity) -> bool {
+        FactionManager::get_reputation(ecs_world, player, member)
+            .is_some_and(|value| value <= UNFRIENDLY_REPUTATION)
+    }
+
+    fn get_reputation(ecs_world: &World, player: Entity, member: Entity) -> Option<i32> {
+        let faction = ecs_world.get::<&Faction>(member).ok()?.value;
+        ecs_world
+            .get::<&Reputation>(player)
+            .ok()?
+            .values
+            .get(&faction)
+            .copied()
+    }
+
+    fn get_faction_name(faction: &FactionEnum) -> &'static str {
+        match faction {
+            FactionEnum::StonedustCult => "Stonedust cult members",
+            FactionEnum::Molemen => "molemen",
+            FactionEnum::Refugees => "refugees",
+            FactionEnum::DeepSpawn => "deep spawn",
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+
+use hecs::Entity;
+
+use crate::{
+    components::{
+        combat::{CombatStats, Grappled},
+        common::{Named, Position},
+        items::InBackback,
+        monster::{SnakeBody, SnakeHead},
+    },
+    engine::state::{GameState, RunState},
+    maps::zone::{TileType, Zone},
+};
+
+/// Handles creatures falling into chasms and pits
+pub struct FallManager {}
+
+impl FallManager {
+    /// The player falls to the next depth, monsters are lost forever
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+        let mut fallen_monsters: Vec<Entity> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            // Snake bodies are dragged down by their head
+            let mut fallers = ecs_world
+                .query::<(&Position, &Named)>()
+                .with::<&CombatStats>()
+                .without::<&SnakeBody>();
+
+            for (faller, (position, named)) in &mut fallers {
+                let index = zone.get_index_from_xy(&position.x, &position.y);
+                if !zone.is_chasm(index) {
+                    continue;
+                }
+
+                let hole_name = if zone.tiles[index] == TileType::Pit {
+                    "pit"
+                } else {
+                    "chasm"
+                };
+
+                if faller.id() == player_id {
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("You fall into the {}!", hole_name)));
+                    game_state.run_state = RunState::FallToNextZone;
+                } else {
+                    fallen_monsters.push(faller);
+                    if zone.visible_tiles[index] {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} falls into the {}!",
+                            named.name, hole_name
+                        )));
+                    }
+                }
+            }
+        }
+
+        for monster in fallen_monsters {
+            // Whatever the monster was carrying falls with it
+            let mut lost_entities: Vec<Entity> = ecs_world
+                .query::<&InBackback>()
+                .iter()
+                .filter_map(|(item, in_backpack)| {
+                    if in_backpack.owner.id() == monster.id() {
+                        Some(item)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            if let Ok(head) = ecs_world.get::<&SnakeHead>(monster) {
+                lost_entities.extend(head.body.iter().copied());
+            }
+
+            // Anyone grappled by the monster is now free
+            let grappled_entities: Vec<Entity> = ecs_world
+                .query::<&Grappled>()
+                .iter()
+                .filter_map(|(entity, grappled)| {
+                    if grappled.by.id() == monster.id() {
+                        Some(entity)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            for entity in grappled_entities {
+                let _ = ecs_world.remove_one::<Grappled>(entity);
+            }
+
+            for entity in lost_entities {
+                let _ = ecs_world.despawn(entity);
+            }
+            let _ = ecs_world.despawn(monster);
+        }
+    }
+}
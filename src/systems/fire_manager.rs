@@ -0,0 +1,298 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+use hecs::{Entity, World};
+
+use crate::{
+    components::{
+        combat::{CombatStats, SufferingDamage},
+        common::{Burning, Fire, Flammable, Named, Position, Wet},
+        items::{InBackback, Item, ProduceLight, TurnedOn},
+        player::Player,
+    },
+    constants::{
+        FIRE_DAMAGE_DICE, FIRE_LIGHT_RADIUS, FIRE_SPREAD_CHANCE, MUSHROOM_FIELD_BURNING_COUNTER,
+        OIL_BURNING_COUNTER, STARTING_BURNING_COUNTER,
+    },
+    engine::state::GameState,
+    maps::zone::{DecalType, TileType, Zone},
+    spawning::spawner::Spawn,
+    utils::roll::Roll,
+};
+
+/// Handles burning entities and tiles, and how fire spreads around
+pub struct FireManager {}
+
+impl FireManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+
+        let mut entities_to_extinguish: Vec<Entity> = Vec::new();
+        let mut entities_burnt_out: Vec<(Entity, usize)> = Vec::new();
+        let mut entities_to_ignite: HashSet<Entity> = HashSet::new();
+        let mut tiles_to_ignite: HashMap<usize, i32> = HashMap::new();
+        let mut damage_list: Vec<(Entity, i32)> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            // Tiles that are already on fire
+            let burning_tiles: HashSet<usize> = ecs_world
+                .query::<&Position>()
+                .with::<&Fire>()
+                .iter()
+                .map(|(_, position)| zone.get_index_from_xy(&position.x, &position.y))
+                .collect();
+
+            // Items stored in a backpack are smothered
+            entities_to_extinguish.extend(
+                ecs_world
+                    .query::<&Burning>()
+                    .with::<&InBackback>()
+                    .iter()
+                    .map(|(smothered_entity, _)| smothered_entity),
+            );
+
+            let mut burning_entities = ecs_world.query::<(
+                &mut Burning,
+                &Position,
+                &Named,
+                Option<&Wet>,
+                Option<&Fire>,
+                Option<&Item>,
+            )>();
+
+            for (burning_entity, (burning, position, named, wet_opt, fire_opt, item_opt)) in
+                &mut burning_entities
+            {
+                let pos_idx = zone.get_index_from_xy(&position.x, &position.y);
+
+                // Water puts out any fire
+                if zone.water_tiles[pos_idx] || wet_opt.is_some() {
+                    entities_to_extinguish.push(burning_entity);
+                    if burning_entity.id() == player_id {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("The flames on you are put out"));
+                    } else if zone.visible_tiles[pos_idx] && fire_opt.is_none() {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The flames on the {} are put out",
+                            named.name
+                        )));
+                    }
+                    continue;
+                }
+
+                burning.tick_counter -= 1;
+
+                if fire_opt.is_some() {
+                    // Everything standing in the flames could catch fire
+                    for &other in &zone.tile_content[pos_idx] {
+                        if other.id() == burning_entity.id()
+                            || ecs_world.satisfies::<&Burning>(other).unwrap_or(false)
+                        {
+                            continue;
+                        }
+
+                        if let Ok(other_stats) = ecs_world.get::<&CombatStats>(other) {
+                            // Dexterity save to avoid catching fire
+                            if Roll::d20() > other_stats.current_dexterity {
+                                entities_to_ignite.insert(other);
+                            }
+                        } else if ecs_world.satisfies::<&Flammable>(other).unwrap_or(false) {
+                            entities_to_ignite.insert(other);
+                        }
+                    }
+                } else if item_opt.is_none() {
+                    // Burning creatures suffer damage each tick
+                    let damage_roll = Roll::dice(1, FIRE_DAMAGE_DICE);
+                    damage_list.push((burning_entity, damage_roll));
+
+                    if burning_entity.id() == player_id {
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("You burn for {} damage!", damage_roll)));
+                    } else if zone.visible_tiles[pos_idx] {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} burns for {} damage",
+                            named.name, damage_roll
+                        )));
+                    }
+                }
+
+                // Spread fire to adjacent flammable things
+                for x in position.x - 1..=position.x + 1 {
+                    for y in position.y - 1..=position.y + 1 {
+                        if !(0..zone.width).contains(&x) || !(0..zone.height).contains(&y) {
+                            continue;
+                        }
+
+                        let adj_idx = zone.get_index_from_xy(&x, &y);
+                        if zone.water_tiles[adj_idx] {
+                            continue;
+                        }
+
+                        if !burning_tiles.contains(&adj_idx) {
+                            if let Some(DecalType::Oil) = zone.decals_tiles.get(&adj_idx) {
+                                tiles_to_ignite.insert(adj_idx, OIL_BURNING_COUNTER);
+                            } else if zone.tiles[adj_idx] == TileType::MushroomField
+                                && Roll::d6() <= FIRE_SPREAD_CHANCE
+                            {
+                                tiles_to_ignite.insert(adj_idx, MUSHROOM_FIELD_BURNING_COUNTER);
+                            }
+                        }
+
+                        for &other in &zone.tile_content[adj_idx] {
+                            if other.id() != burning_entity.id()
+                                && ecs_world.satisfies::<&Flammable>(other).unwrap_or(false)
+                                && !ecs_world.satisfies::<&Burning>(other).unwrap_or(false)
+                                && Roll::d6() <= FIRE_SPREAD_CHANCE
+                            {
+                                entities_to_ignite.insert(other);
+                            }
+                        }
+                    }
+                }
+
+                // Fire dies out in time. Flammable items are consumed by it
+                if burning.tick_counter <= 0 {
+                    if fire_opt.is_some() || item_opt.is_some() {
+                        entities_burnt_out.push((burning_entity, pos_idx));
+                        if item_opt.is_some() && zone.visible_tiles[pos_idx] {
+                            game_state.game_log.add_entry(Cow::Owned(format!(
+                                "The {} burns to ashes",
+                                named.name
+                            )));
+                        }
+                    } else {
+                        entities_to_extinguish.push(burning_entity);
+                        if burning_entity.id() == player_id {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("You are no longer burning"));
+                        }
+                    }
+                }
+            }
+
+            // Log all the entities that caught fire
+            for &entity in &entities_to_ignite {
+                if entity.id() == player_id {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("You catch fire!"));
+                } else if let Ok(position) = ecs_world.get::<&Position>(entity)
+                    && zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)]
+                    && let Ok(named) = ecs_world.get::<&Named>(entity)
+                {
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("The {} catches fire!", named.name)));
+                }
+            }
+        }
+
+        let must_recalculate_view = !entities_to_extinguish.is_empty()
+            || !entities_burnt_out.is_empty()
+            || !entities_to_ignite.is_empty()
+            || !tiles_to_ignite.is_empty();
+
+        for (entity, damage) in damage_list {
+            if let Ok(mut suffering_damage) = ecs_world.get::<&mut SufferingDamage>(entity) {
+                suffering_damage.damage_received += damage;
+            }
+        }
+
+        for entity in entities_to_extinguish {
+            FireManager::extinguish(ecs_world, entity);
+        }
+
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+
+        // Burnt out fires and items leave only ashes behind
+        for (entity, pos_idx) in entities_burnt_out {
+            let _ = ecs_world.despawn(entity);
+            zone.decals_tiles.insert(pos_idx, DecalType::Ash);
+            if zone.tiles[pos_idx] == TileType::MushroomField {
+                zone.tiles[pos_idx] = TileType::Floor;
+            }
+        }
+
+        for entity in entities_to_ignite {
+            FireManager::set_on_fire(ecs_world, entity);
+        }
+
+        for (pos_idx, tick_counter) in tiles_to_ignite {
+            let (x, y) = zone.get_xy_from_index(pos_idx);
+            Spawn::fire(ecs_world, x, y, tick_counter);
+        }
+
+        if must_recalculate_view {
+            Player::force_view_recalculation(game_state);
+        }
+    }
+
+    /// Set an entity on fire. Entities that do not produce light will start doing it while burning
+    pub fn set_on_fire(ecs_world: &mut World, entity: Entity) {
+        if ecs_world.satisfies::<&Burning>(entity).unwrap_or(false) {
+            return;
+        }
+
+        if ecs_world
+            .satisfies::<&ProduceLight>(entity)
+            .unwrap_or(false)
+        {
+            let _ = ecs_world.insert_one(
+                entity,
+                Burning {
+                    tick_counter: STARTING_BURNING_COUNTER,
+                    gives_light: false,
+                },
+            );
+        } else {
+            let _ = ecs_world.insert(
+                entity,
+                (
+                    Burning {
+                        tick_counter: STARTING_BURNING_COUNTER,
+                        gives_light: true,
+                    },
+                    ProduceLight {
+                        radius: FIRE_LIGHT_RADIUS,
+                    },
+                    TurnedOn {},
+                ),
+            );
+        }
+    }
+
+    /// Put out the fire on an entity. Fires burning on tiles simply vanish
+    fn extinguish(ecs_world: &mut World, entity: Entity) {
+        if ecs_world.satisfies::<&Fire>(entity).unwrap_or(false) {
+            let _ = ecs_world.despawn(entity);
+            return;
+        }
+
+        if let Ok(burning) = ecs_world.remove_one::<Burning>(entity)
+            && burning.gives_light
+        {
+            let _ = ecs_world.remove::<(ProduceLight, TurnedOn)>(entity);
+        }
+    }
+}
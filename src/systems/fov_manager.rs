@@ -1,10 +1,9 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cmp::max};
 
 use adam_fov_rs::{IVec2, compute_fov};
 
 use crate::{
     components::{common::*, health::Blind},
-    constants::{MAP_HEIGHT, MAP_WIDTH},
     engine::state::GameState,
     maps::zone::Zone,
     utils::common::Utils,
@@ -41,7 +40,7 @@ impl FieldOfViewManager {
                 // Do not calculate FOV for blind entities
                 if let Some(blind) = blind_opt {
                     // Can see only the tile were is standing
-                    let index = Zone::get_index_from_xy(&position.x, &position.y);
+                    let index = zone.get_index_from_xy(&position.x, &position.y);
                     viewshed.visible_tiles.clear();
                     viewshed.visible_tiles.push(index);
                     // After a while the blindness will be removed
@@ -72,7 +71,7 @@ impl FieldOfViewManager {
                     if is_player {
                         zone.visible_tiles.fill(false);
                         for &index in viewshed.visible_tiles.iter() {
-                            let (x, y) = Zone::get_xy_from_index(index);
+                            let (x, y) = zone.get_xy_from_index(index);
                             let distance = Utils::distance(&x, &position.x, &y, &position.y);
 
                             // if is lit, that we can show and reveal
@@ -86,7 +85,7 @@ impl FieldOfViewManager {
                         // Human vision: only lit or adjacent tiles are visible
                         // This emulates the player's field of view on NPCs
                         viewshed.visible_tiles.retain(|&index| {
-                            let (x, y) = Zone::get_xy_from_index(index);
+                            let (x, y) = zone.get_xy_from_index(index);
                             let distance = Utils::distance(&x, &position.x, &y, &position.y);
                             zone.lit_tiles[index] || distance < 2.0
                         });
@@ -109,22 +108,28 @@ impl FieldOfViewManager {
         let set_to_visible = |position: IVec2| {
             viewshed
                 .visible_tiles
-                .push(Zone::get_index_from_xy(&position[0], &position[1]));
+                .push(zone.get_index_from_xy(&position[0], &position[1]));
         };
 
+        // Gas lingering around the origin reduces the range
+        let mut range = viewshed.range;
+        if zone.gas_tiles.contains_key(&zone.get_index_from_xy(&x, &y)) {
+            range = max(1, range / 2);
+        }
+
         // Calculate Fov
         compute_fov(
             Point { x, y },
-            viewshed.range as usize,
-            [MAP_WIDTH, MAP_HEIGHT],
+            range as usize,
+            [zone.width, zone.height],
             is_opaque,
             set_to_visible,
         );
 
         // Sort visible tiles by distance from origin. Needed for better IA handling
         viewshed.visible_tiles.sort_by(|&a_index, &b_index| {
-            let (ax, ay) = Zone::get_xy_from_index(a_index);
-            let (bx, by) = Zone::get_xy_from_index(b_index);
+            let (ax, ay) = zone.get_xy_from_index(a_index);
+            let (bx, by) = zone.get_xy_from_index(b_index);
             Utils::distance(&x, &ax, &y, &ay).total_cmp(&Utils::distance(&x, &bx, &y, &by))
         });
     }
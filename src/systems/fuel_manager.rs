@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 
 use crate::{
-    components::{common::Position, items::TurnedOff},
-    constants::{FLAME_PARTICLE_TYPE, STANDARD_ACTION_MULTIPLIER},
+    components::{actions::WantsToThrow, combat::WantsToZap, common::Position, items::TurnedOff},
+    constants::{
+        FLAME_PARTICLE_TYPE, MAX_THROW_DISTANCE, OIL_BURNING_COUNTER, STANDARD_ACTION_MULTIPLIER,
+    },
     engine::state::GameState,
-    utils::{common::Utils, particle_animation::ParticleAnimation},
+    maps::zone::{DecalType, Zone},
+    spawning::spawner::Spawn,
+    utils::{common::Utils, effect_manager::EffectManager, particle_animation::ParticleAnimation},
 };
 use hecs::Entity;
 
@@ -208,4 +212,144 @@ impl FuelManager {
             let _ = ecs_world.spawn((true, particle));
         }
     }
+
+    /// Thrown oil flasks shatter on landing, spilling burning oil all around
+    pub fn do_throws(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+        let mut throwers_and_items_thrown: Vec<(Entity, Entity, i32)> = Vec::new();
+        let mut landing_points: Vec<(i32, i32)> = Vec::new();
+        let mut particle_animations: Vec<ParticleAnimation> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            // List of entities that want to throw stuff
+            let mut throwers =
+                ecs_world.query::<(&WantsToThrow, &WantsToZap, &Position, &CombatStats, &Named)>();
+
+            for (thrower, (wants_to_throw, wants_to_zap, position, stats, named_thrower)) in
+                &mut throwers
+            {
+                let named_item = ecs_world
+                    .get::<&Named>(wants_to_throw.item)
+                    .expect("Entity is not Named");
+
+                let mut landing_point = (position.x, position.y);
+
+                // Do not draw if thrower is throwing on himself
+                if position.x != wants_to_zap.target.0 || position.y != wants_to_zap.target.1 {
+                    // Item will be thrown in line
+                    let mut line_effect = EffectManager::new_line(
+                        (position.x, position.y),
+                        (wants_to_zap.target.0, wants_to_zap.target.1),
+                    );
+
+                    // The item stops on the first creature hit, before a solid obstacle
+                    // or when it flies too far
+                    let mut must_truncate_line_at = line_effect.len();
+                    for (i, &(x, y)) in line_effect.iter().skip(1).enumerate() {
+                        let index = zone.get_index_from_xy(&x, &y);
+
+                        if Utils::distance(&position.x, &x, &position.y, &y) > MAX_THROW_DISTANCE {
+                            must_truncate_line_at = i + 1;
+                            break;
+                        }
+
+                        if zone.blocked_tiles[index] {
+                            if zone.tile_content[index].iter().any(|&entity| {
+                                ecs_world
+                                    .satisfies::<&SufferingDamage>(entity)
+                                    .unwrap_or(false)
+                            }) {
+                                landing_point = (x, y);
+                                must_truncate_line_at = i + 2;
+                            } else {
+                                must_truncate_line_at = i + 1;
+                            }
+                            break;
+                        }
+
+                        landing_point = (x, y);
+                    }
+
+                    line_effect.truncate(must_truncate_line_at);
+
+                    particle_animations.push(ParticleAnimation::new_projectile(
+                        line_effect,
+                        FLAME_PARTICLE_TYPE,
+                    ));
+                }
+
+                if thrower.id() == player_id {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "You throw the {} and it shatters",
+                        named_item.name
+                    )));
+                } else if zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)] {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "{} throws a {}",
+                        named_thrower.name, named_item.name
+                    )));
+                }
+
+                landing_points.push(landing_point);
+                throwers_and_items_thrown.push((thrower, wants_to_throw.item, stats.speed));
+            }
+        }
+
+        //Cleanup
+        for (thrower, item_thrown, speed) in throwers_and_items_thrown {
+            let _ = ecs_world.remove::<(WantsToThrow, WantsToZap)>(thrower);
+            let _ = ecs_world.despawn(item_thrown);
+
+            Utils::wait_after_action(ecs_world, thrower, speed * STANDARD_ACTION_MULTIPLIER);
+        }
+
+        // Spill oil around the landing point and set it on fire. Water puts it out immediately
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+        for (x, y) in landing_points {
+            let landing_index = zone.get_index_from_xy(&x, &y);
+            if zone.water_tiles[landing_index] {
+                if zone.visible_tiles[landing_index] {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("The oil floats harmlessly on the water"));
+                }
+                continue;
+            }
+
+            for (spill_x, spill_y) in zone.get_adjacent_passable_tiles(&x, &y, true, false) {
+                let spill_index = zone.get_index_from_xy(&spill_x, &spill_y);
+                if !zone.water_tiles[spill_index] {
+                    zone.decals_tiles.insert(spill_index, DecalType::Oil);
+                }
+            }
+            zone.decals_tiles.insert(landing_index, DecalType::Oil);
+
+            Spawn::fire(ecs_world, x, y, OIL_BURNING_COUNTER);
+
+            if zone.visible_tiles[landing_index] {
+                game_state
+                    .game_log
+                    .entries
+                    .push(Cow::Borrowed("The oil bursts into flames!"));
+            }
+        }
+
+        for particle in particle_animations {
+            let _ = ecs_world.spawn((true, particle));
+        }
+    }
 }
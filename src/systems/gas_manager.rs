@@ -0,0 +1,256 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use hecs::Entity;
+
+use crate::{
+    components::{
+        combat::{CombatStats, SufferingDamage},
+        common::{
+            EmitsGas, Fire, Immunity, ImmunityTypeEnum, Named, Position, Species, SpeciesEnum,
+        },
+        health::{DiseaseType, Diseased, Stunned},
+        player::Player,
+    },
+    constants::{
+        FIRE_SMOKE_DENSITY, GAS_DECAY, GAS_POISON_DAMAGE_DICE, GAS_STUN_TICKS, LAVA_STEAM_DENSITY,
+        MAX_DISEASE_TICK_COUNTER, STEAM_DAMAGE_DICE,
+    },
+    engine::state::GameState,
+    maps::zone::{GasType, Zone},
+    utils::roll::Roll,
+};
+
+/// Handles gas clouds: how they are released, spread, thin out and affect who breathes them
+pub struct GasManager {}
+
+impl GasManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+
+        let had_gas = !zone.gas_tiles.is_empty();
+        let mut stunned_list: Vec<Entity> = Vec::new();
+        let mut infected_list: Vec<Entity> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            // Gas emitters release their gas on the tile they stand on
+            let mut emitters = ecs_world.query::<(&Position, &EmitsGas)>();
+            for (_, (position, emits_gas)) in &mut emitters {
+                if Roll::d20() <= emits_gas.chance {
+                    zone.add_gas(
+                        zone.get_index_from_xy(&position.x, &position.y),
+                        emits_gas.of,
+                        emits_gas.density,
+                    );
+                }
+            }
+
+            // Fires produce smoke
+            let mut fires = ecs_world.query::<&Position>().with::<&Fire>();
+            for (_, position) in &mut fires {
+                zone.add_gas(
+                    zone.get_index_from_xy(&position.x, &position.y),
+                    GasType::Smoke,
+                    FIRE_SMOKE_DENSITY,
+                );
+            }
+
+            // Lava boils the water next to it into steam
+            let lava_tiles: Vec<usize> = (0..zone.tiles.len())
+                .filter(|&index| zone.is_lava(index))
+                .collect();
+            for index in lava_tiles {
+                let (x, y) = zone.get_xy_from_index(index);
+                for (adj_x, adj_y) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                    if !(0..zone.width).contains(&adj_x) || !(0..zone.height).contains(&adj_y) {
+                        continue;
+                    }
+
+                    let adj_idx = zone.get_index_from_xy(&adj_x, &adj_y);
+                    if zone.water_tiles[adj_idx] {
+                        zone.add_gas(adj_idx, GasType::Steam, LAVA_STEAM_DENSITY);
+                    }
+                }
+            }
+        }
+
+        GasManager::spread_and_decay(zone);
+
+        // Scope for keeping borrow checker quiet
+        {
+            // Everyone that breathes is affected by the gas on its tile
+            let mut breathers = ecs_world.query::<(
+                &Position,
+                &CombatStats,
+                &mut SufferingDamage,
+                &Named,
+                &Species,
+                &Immunity,
+                Option<&EmitsGas>,
+            )>();
+
+            for (
+                breather,
+                (position, stats, suffering_damage, named, species, immunity, emits_gas_opt),
+            ) in &mut breathers
+            {
+                let pos_idx = zone.get_index_from_xy(&position.x, &position.y);
+                let Some(gas) = zone.gas_tiles.get(&pos_idx) else {
+                    continue;
+                };
+
+                // Undead and slimes do not breathe, and nobody suffers its own gas
+                if species.value == SpeciesEnum::Undead
+                    || species.value == SpeciesEnum::Slime
+                    || emits_gas_opt.is_some_and(|emits_gas| emits_gas.of == gas.gas_type)
+                {
+                    continue;
+                }
+
+                let is_player = breather.id() == player_id;
+                let failed_saving_throw = Roll::d20() > stats.current_toughness;
+
+                match gas.gas_type {
+                    GasType::Poison => {
+                        if failed_saving_throw {
+                            let damage_roll = Roll::dice(1, GAS_POISON_DAMAGE_DICE);
+                            suffering_damage.damage_received += damage_roll;
+                            if is_player {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "You choke on the poisonous gas for {} damage!",
+                                    damage_roll
+                                )));
+                            } else if zone.visible_tiles[pos_idx] {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The {} chokes on the poisonous gas",
+                                    named.name
+                                )));
+                            }
+                        }
+                    }
+                    GasType::Spores => {
+                        let is_immune = species.value == SpeciesEnum::Myconid
+                            || immunity
+                                .to
+                                .contains_key(&ImmunityTypeEnum::Disease(DiseaseType::Fever));
+                        if failed_saving_throw && !is_immune {
+                            infected_list.push(breather);
+                            if is_player {
+                                game_state
+                                    .game_log
+                                    .entries
+                                    .push(Cow::Borrowed("You breathe in the spores"));
+                            }
+                        }
+                    }
+                    GasType::Sulfur => {
+                        if failed_saving_throw {
+                            stunned_list.push(breather);
+                            if is_player {
+                                game_state
+                                    .game_log
+                                    .entries
+                                    .push(Cow::Borrowed("The sulphuric fumes make you retch!"));
+                            } else if zone.visible_tiles[pos_idx] {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The {} retches in the sulphuric fumes",
+                                    named.name
+                                )));
+                            }
+                        }
+                    }
+                    GasType::Smoke => {
+                        if is_player && failed_saving_throw {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("You cough in the smoke"));
+                        }
+                    }
+                    GasType::Steam => {
+                        if failed_saving_throw && !immunity.to.contains_key(&ImmunityTypeEnum::Heat)
+                        {
+                            let damage_roll = Roll::dice(1, STEAM_DAMAGE_DICE);
+                            suffering_damage.damage_received += damage_roll;
+                            if is_player {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The scalding steam burns you for {} damage!",
+                                    damage_roll
+                                )));
+                            } else if zone.visible_tiles[pos_idx] {
+                                game_state.game_log.add_entry(Cow::Owned(format!(
+                                    "The {} is scalded by the steam",
+                                    named.name
+                                )));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for entity in stunned_list {
+            let _ = ecs_world.insert_one(
+                entity,
+                Stunned {
+                    tick_counter: GAS_STUN_TICKS,
+                },
+            );
+        }
+
+        // Spores infect healthy breathers only
+        for infected in infected_list {
+            if !ecs_world.satisfies::<&Diseased>(infected).unwrap_or(false) {
+                let mut tick_counters = HashMap::new();
+                tick_counters.insert(
+                    DiseaseType::Fever,
+                    (MAX_DISEASE_TICK_COUNTER + Roll::d20(), false),
+                );
+                let _ = ecs_world.insert_one(infected, Diseased { tick_counters });
+            }
+        }
+
+        // Gas clouds change what can be seen
+        if had_gas || !zone.gas_tiles.is_empty() {
+            Player::force_view_recalculation(game_state);
+        }
+    }
+
+    /// Gas spreads to adjacent open tiles, thinning out over time until it vanishes
+    fn spread_and_decay(zone: &mut Zone) {
+        let old_gas_tiles = std::mem::take(&mut zone.gas_tiles);
+
+        for (index, gas) in old_gas_tiles {
+            if gas.density > GAS_DECAY {
+                zone.add_gas(index, gas.gas_type, gas.density - GAS_DECAY);
+            }
+
+            let spread_density = gas.density / 2;
+            if spread_density == 0 {
+                continue;
+            }
+
+            let (x, y) = zone.get_xy_from_index(index);
+            for (adj_x, adj_y) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                if (0..zone.width).contains(&adj_x)
+                    && (0..zone.height).contains(&adj_y)
+                    && !zone.is_tile_solid(&adj_x, &adj_y)
+                {
+                    zone.add_gas(
+                        zone.get_index_from_xy(&adj_x, &adj_y),
+                        gas.gas_type,
+                        spread_density,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -40,7 +40,7 @@ impl GazeAttacksManager {
             //For each Entity with Components Viewshed and Position
             for (gazer, (wants_to_gaze, gaze_attack, position, named)) in &mut gazers {
                 end_gazing.push(gazer);
-                let index = Zone::get_index_from_xy(&position.x, &position.y);
+                let index = zone.get_index_from_xy(&position.x, &position.y);
                 // if target can see the gazer
                 if let Ok(target_view) = ecs_world.get::<&Viewshed>(wants_to_gaze.target)
                     && target_view.visible_tiles.contains(&index)
@@ -95,10 +95,9 @@ impl GazeAttacksManager {
                                     "The {} {} you with its gaze!",
                                     named.name, effect
                                 )));
-                            } else if zone.visible_tiles
-                                [Zone::get_index_from_xy(&t_pos.x, &t_pos.y)]
+                            } else if zone.visible_tiles[zone.get_index_from_xy(&t_pos.x, &t_pos.y)]
                                 && zone.visible_tiles
-                                    [Zone::get_index_from_xy(&position.x, &position.y)]
+                                    [zone.get_index_from_xy(&position.x, &position.y)]
                             {
                                 game_state.game_log.add_entry(Cow::Owned(format!(
                                     "The {} {} the {} with its gaze!",
@@ -124,7 +124,7 @@ impl HealthManager {
                                                     .push(Cow::Borrowed("Your skin peels away!"));
                                             }
                                         } else if zone.visible_tiles
-                                            [Zone::get_index_from_xy(&position.x, &position.y)]
+                                            [zone.get_index_from_xy(&position.x, &position.y)]
                                         {
                                             if Roll::d6() > 3 {
                                                 game_log.add_entry(Cow::Owned(format!(
@@ -140,7 +140,7 @@ impl HealthManager {
                                         }
 
                                         zone.decals_tiles.insert(
-                                            Zone::get_index_from_xy(&position.x, &position.y),
+                                            zone.get_index_from_xy(&position.x, &position.y),
                                             DecalType::Blood,
                                         );
                                     } else {
@@ -159,7 +159,7 @@ impl HealthManager {
                                         }
 
                                         zone.decals_tiles.insert(
-                                            Zone::get_index_from_xy(&position.x, &position.y),
+                                            zone.get_index_from_xy(&position.x, &position.y),
                                             DecalType::Vomit,
                                         );
 
@@ -168,7 +168,7 @@ impl HealthManager {
                                                 .entries
                                                 .push(Cow::Borrowed("You vomit badly!"));
                                         } else if zone.visible_tiles
-                                            [Zone::get_index_from_xy(&position.x, &position.y)]
+                                            [zone.get_index_from_xy(&position.x, &position.y)]
                                         {
                                             game_log.add_entry(Cow::Owned(format!(
                                                 "{} vomits badly!",
@@ -198,7 +198,7 @@ impl HealthManager {
                                                 "The fever makes you feel dizzy for a moment!",
                                             ));
                                         } else if zone.visible_tiles
-                                            [Zone::get_index_from_xy(&position.x, &position.y)]
+                                            [zone.get_index_from_xy(&position.x, &position.y)]
                                         {
                                             game_log.add_entry(Cow::Owned(format!(
                                                 "The {} seems dizzy",
@@ -215,7 +215,7 @@ impl HealthManager {
                                                 .entries
                                                 .push(Cow::Borrowed("Your muscles stiffens!"));
                                         } else if zone.visible_tiles
-                                            [Zone::get_index_from_xy(&position.x, &position.y)]
+                                            [zone.get_index_from_xy(&position.x, &position.y)]
                                         {
                                             game_log.add_entry(Cow::Owned(format!(
                                                 "{}'s body stiffens!",
@@ -228,7 +228,7 @@ impl HealthManager {
                                                 "A calcified patch appears on your skin!",
                                             ));
                                         } else if zone.visible_tiles
-                                            [Zone::get_index_from_xy(&position.x, &position.y)]
+                                            [zone.get_index_from_xy(&position.x, &position.y)]
                                         {
                                             game_log.add_entry(Cow::Owned(format!(
                                                 "A calcified patch appears on {}'s skin!",
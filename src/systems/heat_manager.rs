@@ -0,0 +1,197 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use hecs::Entity;
+
+use crate::{
+    components::{
+        combat::{CombatStats, SufferingDamage},
+        common::{Burning, Flammable, Immunity, ImmunityTypeEnum, Named, Position, Wet},
+        items::{Armor, BodyLocation, Equipped, InBackback},
+        player::Player,
+    },
+    constants::{FIRE_SPREAD_CHANCE, HOT_ROCK_DAMAGE_DICE},
+    engine::state::GameState,
+    maps::zone::{TileType, Zone},
+    systems::fire_manager::FireManager,
+    utils::roll::Roll,
+};
+
+/// Handles hot terrain: lava burning everything, cooling crust cracking and hot rock scorching feet
+pub struct HeatManager {}
+
+impl HeatManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+
+        let mut entities_to_ignite: HashSet<Entity> = HashSet::new();
+        let mut entities_to_dry: Vec<Entity> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let mut walkers = ecs_world.query::<(
+                &Position,
+                &CombatStats,
+                &mut SufferingDamage,
+                &Named,
+                Option<&Immunity>,
+            )>();
+
+            for (walker, (position, stats, suffering_damage, named, immunity_opt)) in &mut walkers {
+                let pos_idx = zone.get_index_from_xy(&position.x, &position.y);
+                let is_player = walker.id() == player_id;
+                let is_immune = immunity_opt
+                    .is_some_and(|immunity| immunity.to.contains_key(&ImmunityTypeEnum::Heat));
+
+                // The crust gives way after a few steps, revealing the lava below
+                if zone.tiles[pos_idx] == TileType::LavaCrust {
+                    zone.special_tile_counter[pos_idx] =
+                        zone.special_tile_counter[pos_idx].saturating_sub(1);
+
+                    if zone.special_tile_counter[pos_idx] == 0 {
+                        zone.tiles[pos_idx] = TileType::Lava;
+                        zone.must_recalculate_lava_light = true;
+                        if is_player {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("The crust cracks under your feet!"));
+                        } else if zone.visible_tiles[pos_idx] {
+                            game_state.game_log.add_entry(Cow::Owned(format!(
+                                "The crust cracks under the {}'s feet!",
+                                named.name
+                            )));
+                        }
+                    } else if is_player {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("The crust creaks under your feet"));
+                    }
+                }
+
+                if zone.is_lava(pos_idx) && !is_immune {
+                    suffering_damage.damage_received =
+                        stats.current_stamina + stats.current_toughness;
+                    if is_player {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("You are engulfed by the lava!"));
+                    } else if zone.visible_tiles[pos_idx] {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} is engulfed by the lava!",
+                            named.name
+                        )));
+                    }
+                } else if zone.tiles[pos_idx] == TileType::HotRock {
+                    if ecs_world.satisfies::<&Wet>(walker).unwrap_or(false) {
+                        entities_to_dry.push(walker);
+                        if is_player {
+                            game_state
+                                .game_log
+                                .entries
+                                .push(Cow::Borrowed("The heat of the rock dries you up"));
+                        }
+                    }
+
+                    let has_protected_feet =
+                        ecs_world.query::<&Equipped>().with::<&Armor>().iter().any(
+                            |(_, equipped)| {
+                                equipped.owner.id() == walker.id()
+                                    && equipped.body_location == BodyLocation::Feet
+                            },
+                        );
+                    let is_floor_immune = immunity_opt.is_some_and(|immunity| {
+                        immunity.to.contains_key(&ImmunityTypeEnum::DamagingFloor)
+                    });
+
+                    if !is_immune && !is_floor_immune && !has_protected_feet {
+                        let damage_roll = Roll::dice(1, HOT_ROCK_DAMAGE_DICE);
+                        suffering_damage.damage_received += damage_roll;
+                        if is_player {
+                            game_state.game_log.add_entry(Cow::Owned(format!(
+                                "The hot rock burns your bare feet for {} damage!",
+                                damage_roll
+                            )));
+                        } else if zone.visible_tiles[pos_idx] {
+                            game_state.game_log.add_entry(Cow::Owned(format!(
+                                "The hot rock burns the {}'s feet",
+                                named.name
+                            )));
+                        }
+                    }
+                }
+            }
+
+            // Whatever is carried by someone on hot rock dries up too
+            let drying_owners: HashSet<u32> =
+                entities_to_dry.iter().map(|entity| entity.id()).collect();
+            entities_to_dry.extend(
+                ecs_world
+                    .query::<&InBackback>()
+                    .with::<&Wet>()
+                    .iter()
+                    .filter(|(_, in_backpack)| drying_owners.contains(&in_backpack.owner.id()))
+                    .map(|(item, _)| item),
+            );
+
+            // Lava sets on fire anything flammable lying in it or next to it
+            for index in 0..zone.tiles.len() {
+                if !zone.is_lava(index) {
+                    continue;
+                }
+
+                let (x, y) = zone.get_xy_from_index(index);
+                for adj_x in x - 1..=x + 1 {
+                    for adj_y in y - 1..=y + 1 {
+                        if !(0..zone.width).contains(&adj_x) || !(0..zone.height).contains(&adj_y) {
+                            continue;
+                        }
+
+                        let adj_idx = zone.get_index_from_xy(&adj_x, &adj_y);
+                        for &other in &zone.tile_content[adj_idx] {
+                            if ecs_world.satisfies::<&Flammable>(other).unwrap_or(false)
+                                && !ecs_world.satisfies::<&Burning>(other).unwrap_or(false)
+                                && (adj_idx == index || Roll::d6() <= FIRE_SPREAD_CHANCE)
+                            {
+                                entities_to_ignite.insert(other);
+                            }
+                        }
+                    }
+                }
+            }
+
+            for &entity in &entities_to_ignite {
+                if let Ok(position) = ecs_world.get::<&Position>(entity)
+                    && zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)]
+                    && let Ok(named) = ecs_world.get::<&Named>(entity)
+                {
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("The {} catches fire!", named.name)));
+                }
+            }
+        }
+
+        for entity in entities_to_dry {
+            let _ = ecs_world.remove_one::<Wet>(entity);
+        }
+
+        let must_recalculate_view = !entities_to_ignite.is_empty();
+        for entity in entities_to_ignite {
+            FireManager::set_on_fire(ecs_world, entity);
+        }
+
+        if must_recalculate_view {
+            Player::force_view_recalculation(game_state);
+        }
+    }
+}
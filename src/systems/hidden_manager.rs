@@ -4,8 +4,9 @@ use hecs::Entity;
 
 use crate::{
     components::{
-        combat::{CanHide, CombatStats, IsHidden},
+        combat::{CanHide, CombatStats, IsHidden, Sneaking},
         common::{MyTurn, Named, Position},
+        player::Player,
     },
     constants::MAX_HIDDEN_TURNS,
     engine::state::GameState,
@@ -32,6 +33,8 @@ impl HiddenManager {
                     &Position,
                     &Named,
                     Option<&mut IsHidden>,
+                    Option<&Player>,
+                    Option<&Sneaking>,
                 )>()
                 .with::<&MyTurn>();
 
@@ -42,7 +45,14 @@ impl HiddenManager {
                 .as_ref()
                 .expect("must have Some Zone");
 
-            for (entity, (can_hide, stats, position, named, hidden)) in &mut stealthers {
+            for (entity, (can_hide, stats, position, named, hidden, player_opt, sneaking_opt)) in
+                &mut stealthers
+            {
+                // The player can hide only while sneaking
+                if player_opt.is_some() && sneaking_opt.is_none() {
+                    continue;
+                }
+
                 let have_made_dex_saving_throw = Roll::d20() <= stats.current_dexterity;
 
                 if can_hide.cooldown > 0 {
@@ -67,8 +77,13 @@ impl HiddenManager {
                                     (stats.current_dexterity / 3) * stats.speed;
                             } else {
                                 // Log if within players view
-                                if zone.visible_tiles
-                                    [Zone::get_index_from_xy(&position.x, &position.y)]
+                                if player_opt.is_some() {
+                                    game_state
+                                        .game_log
+                                        .entries
+                                        .push(Cow::Borrowed("You are exposed!"));
+                                } else if zone.visible_tiles
+                                    [zone.get_index_from_xy(&position.x, &position.y)]
                                 {
                                     game_state.game_log.add_entry(Cow::Owned(format!(
                                         "A {} suddenly appears!",
@@ -90,7 +105,13 @@ impl HiddenManager {
                             hidden_entities
                                 .push((entity, (stats.current_dexterity / 3) * stats.speed));
 
-                            if zone.visible_tiles[Zone::get_index_from_xy(&position.x, &position.y)]
+                            if player_opt.is_some() {
+                                game_state
+                                    .game_log
+                                    .entries
+                                    .push(Cow::Borrowed("You hide in the shadows"));
+                            } else if zone.visible_tiles
+                                [zone.get_index_from_xy(&position.x, &position.y)]
                             {
                                 game_state.game_log.add_entry(Cow::Owned(format!(
                                     "The {} suddenly disappears!",
@@ -0,0 +1,391 @@
+use std::borrow::Cow;
+
+use hecs::{Entity, World};
+
+use crate::{
+    components::{
+        combat::Grappled,
+        common::{DigProductEnum, GameLog, Hates, MyTurn, Named, Position, Viewshed},
+        health::Hunger,
+        items::{Edible, Equipped, InBackback, Item},
+        monster::{
+            Awareness, AwarenessState, Fleeing, Hireable, Hireling, HirelingCommand, Memory, Prey,
+            Surrendered, WantsToApproach,
+        },
+    },
+    constants::{
+        HIRE_FOOD_LOYALTY, HIRE_GOLD_LOYALTY, HUNGRY_LOYALTY_PENALTY, MAX_LOYALTY,
+        NEXT_TO_DISTANCE, REPUTATION_HELP_BONUS, STARVED_LOYALTY_PENALTY,
+    },
+    engine::state::{GameState, RunState},
+    maps::zone::Zone,
+    systems::{faction_manager::FactionManager, hunger_check::HungerStatus},
+    utils::{common::Utils, dialog::DialogAction},
+};
+
+/// Hirer, item offered and NPC to be hired
+pub type HireDtt = (Entity, Entity, Entity);
+
+/// Handles NPCs working for the player: their loyalty, their orders and what they carry
+pub struct HirelingManager {}
+
+impl HirelingManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let mut leaving_list: Vec<Entity> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let mut hirelings = ecs_world
+                .query::<(&mut Hireling, &Hunger, &Hates, &Named, &Position)>()
+                .with::<&MyTurn>();
+
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            for (hireling_entity, (hireling, hunger, hates, named, position)) in &mut hirelings {
+                // Hungry hirelings lose their loyalty faster
+                hireling.loyalty -= match hunger.current_status {
+                    HungerStatus::Hungry => HUNGRY_LOYALTY_PENALTY,
+                    HungerStatus::Starved => STARVED_LOYALTY_PENALTY,
+                    _ => 1,
+                };
+
+                // Orders to attack something that is gone are forgotten
+                if let HirelingCommand::Attack(target) = hireling.command
+                    && !ecs_world.contains(target)
+                {
+                    hireling.command = HirelingCommand::Follow;
+                }
+
+                let is_visible =
+                    zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)];
+
+                // An attacked hireling will not work anymore for its attacker
+                if hates.list.contains(&hireling.owner.id()) {
+                    leaving_list.push(hireling_entity);
+                    if is_visible {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} turns against you!",
+                            named.name
+                        )));
+                    }
+                } else if hireling.loyalty <= 0 {
+                    leaving_list.push(hireling_entity);
+                    if is_visible {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} leaves your service",
+                            named.name
+                        )));
+                    }
+                }
+            }
+        }
+
+        // Former hirelings could be hired again
+        for hireling in leaving_list {
+            let _ = ecs_world.remove_one::<Hireling>(hireling);
+            let _ = ecs_world.insert_one(hireling, Hireable {});
+        }
+    }
+
+    /// Check if the item offered is accepted by the NPC. If so, open the hire dialog
+    pub fn check_offer(
+        ecs_world: &World,
+        game_log: &mut GameLog,
+        hire_info: HireDtt,
+    ) -> Option<RunState> {
+        let (hirer, item, target) = hire_info;
+
+        // Hirelings will carry anything for their owner
+        let is_own_hireling = ecs_world
+            .get::<&Hireling>(target)
+            .is_ok_and(|hireling| hireling.owner.id() == hirer.id());
+
+        if is_own_hireling || HirelingManager::get_loyalty_value(ecs_world, item) > 0 {
+            Some(RunState::ShowDialog(DialogAction::Hire(hire_info)))
+        } else {
+            let named = ecs_world.get::<&Named>(target).expect("Must be Named");
+            game_log.add_entry(Cow::Owned(format!("{} is not interested", named.name)));
+            None
+        }
+    }
+
+    /// Pay an NPC with gold or food, or give an item to an own hireling to carry
+    pub fn end_hire(game_state: &mut GameState, hire_info: HireDtt) {
+        let ecs_world = &mut game_state.ecs_world;
+        let (hirer, item, target) = hire_info;
+        let loyalty_gain = HirelingManager::get_loyalty_value(ecs_world, item);
+
+        let is_own_hireling = if let Ok(mut hireling) = ecs_world.get::<&mut Hireling>(target) {
+            hireling.loyalty = (hireling.loyalty + loyalty_gain).min(MAX_LOYALTY);
+            true
+        } else {
+            false
+        };
+
+        let (target_name, item_name) = {
+            let target_named = ecs_world.get::<&Named>(target).expect("Must be Named");
+            let item_named = ecs_world.get::<&Named>(item).expect("Must be Named");
+            (target_named.name, item_named.name)
+        };
+
+        // Payments are spent and make the whole faction of the NPC like the hirer more,
+        // anything else is carried for the hirer
+        if loyalty_gain > 0 {
+            FactionManager::change_reputation(
+                ecs_world,
+                &mut game_state.game_log,
+                hirer,
+                target,
+                REPUTATION_HELP_BONUS,
+            );
+            let _ = ecs_world.despawn(item);
+        } else {
+            let _ = ecs_world.remove_one::<Equipped>(item);
+            if let Ok(mut in_backpack) = ecs_world.get::<&mut InBackback>(item) {
+                in_backpack.owner = target;
+            }
+        }
+
+        if is_own_hireling {
+            game_state.game_log.add_entry(Cow::Owned(format!(
+                "The {} takes your {}",
+                target_name, item_name
+            )));
+            return;
+        }
+
+        HirelingManager::enlist(ecs_world, hirer, target, loyalty_gain);
+
+        game_state.game_log.add_entry(Cow::Owned(format!(
+            "The {} agrees to work for you",
+            target_name
+        )));
+    }
+
+    /// Make an NPC work for the hirer. It stops being shy or scared and forgives the hirer
+    pub fn enlist(ecs_world: &mut World, hirer: Entity, target: Entity, loyalty: i32) {
+        let _ = ecs_world.remove_one::<Hireable>(target);
+        let _ = ecs_world.remove_one::<Prey>(target);
+        let _ = ecs_world.remove_one::<Fleeing>(target);
+        let _ = ecs_world.remove_one::<Surrendered>(target);
+        let _ = ecs_world.remove_one::<WantsToApproach>(target);
+        if let Ok(mut hates) = ecs_world.get::<&mut Hates>(target) {
+            hates.list.remove(&hirer.id());
+        }
+        if let Ok(mut awareness) = ecs_world.get::<&mut Awareness>(target) {
+            awareness.state = AwarenessState::Alert;
+        }
+
+        let _ = ecs_world.insert_one(
+            target,
+            Hireling {
+                owner: hirer,
+                loyalty: loyalty.min(MAX_LOYALTY),
+                command: HirelingCommand::Follow,
+            },
+        );
+    }
+
+    /// Give the same order to all the hirelings of the player
+    pub fn give_command(game_state: &mut GameState, command: HirelingCommand) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        let commanded_list: Vec<Entity> = ecs_world
+            .query_mut::<&mut Hireling>()
+            .into_iter()
+            .filter_map(|(entity, hireling)| {
+                if hireling.owner.id() == player.id() {
+                    hireling.command = command;
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if commanded_list.is_empty() {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("You have no one to command"));
+            return;
+        }
+
+        // Staying hirelings stop whatever they were approaching
+        if command == HirelingCommand::Stay {
+            for hireling in commanded_list {
+                let _ = ecs_world.remove_one::<WantsToApproach>(hireling);
+            }
+        }
+
+        game_state.game_log.entries.push(match command {
+            HirelingCommand::Follow => Cow::Borrowed("You order your hirelings to follow you"),
+            HirelingCommand::Stay => Cow::Borrowed("You order your hirelings to stay here"),
+            HirelingCommand::Attack(_) => Cow::Borrowed("You order your hirelings to attack"),
+        });
+    }
+
+    /// Hirelings next to the player drop what they are carrying, except their equipment
+    pub fn take_back_items(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        let (player_x, player_y) = {
+            let position = ecs_world
+                .get::<&Position>(player)
+                .expect("Player must have Position");
+            (position.x, position.y)
+        };
+
+        let carriers: Vec<(Entity, i32, i32)> = ecs_world
+            .query::<(&Hireling, &Position)>()
+            .iter()
+            .filter_map(|(entity, (hireling, position))| {
+                if hireling.owner.id() == player.id()
+                    && Utils::distance(&player_x, &position.x, &player_y, &position.y)
+                        <= NEXT_TO_DISTANCE
+                {
+                    Some((entity, position.x, position.y))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if carriers.is_empty() {
+            game_state
+                .game_log
+                .entries
+                .push(Cow::Borrowed("There is no hireling next to you"));
+            return;
+        }
+
+        for (carrier, x, y) in carriers {
+            let items_to_drop: Vec<Entity> = ecs_world
+                .query::<&InBackback>()
+                .with::<&Item>()
+                .without::<&Equipped>()
+                .iter()
+                .filter_map(|(item, in_backpack)| {
+                    if in_backpack.owner.id() == carrier.id() {
+                        Some(item)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            for item in items_to_drop {
+                let _ = ecs_world.exchange_one::<InBackback, Position>(item, Position { x, y });
+            }
+        }
+
+        game_state
+            .game_log
+            .entries
+            .push(Cow::Borrowed("Your hirelings drop what they are carrying"));
+    }
+
+    /// Place the hirelings that followed the player next to the spawn point of the new zone
+    /// Those that cannot fit are left behind, with all they carry
+    pub fn place_followers(
+        ecs_world: &mut World,
+        zone: &Zone,
+        player: Entity,
+        game_log: &mut GameLog,
+    ) {
+        let (player_x, player_y) = zone.get_xy_from_index(zone.player_spawn_point);
+        let mut occupied_tiles = vec![(player_x, player_y)];
+
+        let followers: Vec<Entity> = ecs_world
+            .query::<&Hireling>()
+            .iter()
+            .filter_map(|(entity, hireling)| {
+                if hireling.owner.id() == player.id() {
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let mut lost_followers: Vec<Entity> = Vec::new();
+        for follower in followers {
+            // Search for a free tile next to the player or the followers already placed
+            let free_tile = occupied_tiles.iter().find_map(|(x, y)| {
+                zone.get_adjacent_passable_tiles(x, y, false, false)
+                    .into_iter()
+                    .find(|tile| {
+                        !occupied_tiles.contains(tile)
+                            && !zone.water_tiles[zone.get_index_from_xy(&tile.0, &tile.1)]
+                    })
+            });
+
+            let Some((x, y)) = free_tile else {
+                lost_followers.push(follower);
+                continue;
+            };
+
+            if let Ok(mut position) = ecs_world.get::<&mut Position>(follower) {
+                position.x = x;
+                position.y = y;
+            }
+            if let Ok(mut viewshed) = ecs_world.get::<&mut Viewshed>(follower) {
+                viewshed.visible_tiles.clear();
+                viewshed.must_recalculate = true;
+            }
+
+            // Whatever happened in the old zone is left behind
+            let _ = ecs_world.remove_one::<Grappled>(follower);
+            let _ = ecs_world.remove_one::<WantsToApproach>(follower);
+            let _ = ecs_world.remove_one::<Memory>(follower);
+            occupied_tiles.push((x, y));
+        }
+
+        if lost_followers.is_empty() {
+            return;
+        }
+
+        let lost_items: Vec<Entity> = ecs_world
+            .query::<&InBackback>()
+            .iter()
+            .filter_map(|(item, in_backpack)| {
+                if lost_followers.contains(&in_backpack.owner) {
+                    Some(item)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        for entity in lost_items.into_iter().chain(lost_followers) {
+            let _ = ecs_world.despawn(entity);
+        }
+
+        game_log
+            .entries
+            .push(Cow::Borrowed("Some of your hirelings are left behind"));
+    }
+
+    /// How much loyalty an item is worth when given as payment
+    fn get_loyalty_value(ecs_world: &World, item: Entity) -> i32 {
+        if ecs_world
+            .get::<&DigProductEnum>(item)
+            .is_ok_and(|dig_product| dig_product.eq(&DigProductEnum::Gold))
+        {
+            HIRE_GOLD_LOYALTY
+        } else if ecs_world.satisfies::<&Edible>(item).unwrap_or(false) {
+            HIRE_FOOD_LOYALTY
+        } else {
+            0
+        }
+    }
+}
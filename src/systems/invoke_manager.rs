@@ -80,7 +80,7 @@ impl InvokeManager {
 
                     // Zap all entities in line (Excluding first)!
                     for (x, y) in line_effect.iter().skip(1) {
-                        let index = Zone::get_index_from_xy(x, y);
+                        let index = zone.get_index_from_xy(x, y);
                         target_list.push(&zone.tile_content[index]);
                     }
 
@@ -91,7 +91,7 @@ impl InvokeManager {
                     ));
                 } else {
                     // Only one if zapping himself
-                    let index = Zone::get_index_from_xy(&wants_zap.target.0, &wants_zap.target.1);
+                    let index = zone.get_index_from_xy(&wants_zap.target.0, &wants_zap.target.1);
                     target_list.push(&zone.tile_content[index]);
                 }
 
@@ -133,10 +133,9 @@ impl InvokeManager {
                                         .game_log
                                         .entries
                                         .push(Cow::Borrowed("You duck some of the blow!"));
-                                } else if zone.visible_tiles[Zone::get_index_from_xy(
-                                    &wants_zap.target.0,
-                                    &wants_zap.target.1,
-                                )] {
+                                } else if zone.visible_tiles[zone
+                                    .get_index_from_xy(&wants_zap.target.0, &wants_zap.target.1)]
+                                {
                                     game_state.game_log.add_entry(Cow::Owned(format!(
                                         "{} ducks some of the blow!",
                                         named_target.name
@@ -151,10 +150,9 @@ impl InvokeManager {
                                         "You zap yourself for {} damage",
                                         damage_roll
                                     )));
-                                } else if zone.visible_tiles[Zone::get_index_from_xy(
-                                    &wants_zap.target.0,
-                                    &wants_zap.target.1,
-                                )] {
+                                } else if zone.visible_tiles[zone
+                                    .get_index_from_xy(&wants_zap.target.0, &wants_zap.target.1)]
+                                {
                                     game_state.game_log.add_entry(Cow::Owned(format!(
                                         "You zap the {} for {} damage",
                                         named_target.name, damage_roll
@@ -166,7 +164,7 @@ impl InvokeManager {
                                     named_attacker.name, damage_roll
                                 )));
                             } else if zone.visible_tiles
-                                [Zone::get_index_from_xy(&wants_zap.target.0, &wants_zap.target.1)]
+                                [zone.get_index_from_xy(&wants_zap.target.0, &wants_zap.target.1)]
                             {
                                 game_state.game_log.add_entry(Cow::Owned(format!(
                                     "{} zaps the {} for {} damage",
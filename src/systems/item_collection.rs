@@ -7,7 +7,7 @@ use crate::{
         actions::WantsItem,
         combat::CombatStats,
         common::{Hates, MyTurn, Named, Position},
-        items::{Corpse, InBackback, Item, Perishable, ToBeHarvested},
+        items::{Corpse, InBackback, Item, Perishable, ShopOwner, ToBeHarvested},
         monster::Small,
     },
     constants::{
@@ -106,7 +106,7 @@ impl ItemCollection {
                                 named_item.name, corpse_text
                             )));
                         } else if zone.visible_tiles
-                            [Zone::get_index_from_xy(&position.x, &position.y)]
+                            [zone.get_index_from_xy(&position.x, &position.y)]
                         {
                             // Log NPC  only if visible
                             game_state.game_log.add_entry(Cow::Owned(format!(
@@ -127,21 +127,22 @@ impl ItemCollection {
                         if !wants_item.was_bought
                             && let Some(owner) = Utils::get_item_owner_by_position(
                                 ecs_world,
+                                zone,
                                 &position.x,
                                 &position.y,
                             )
                         {
                             let mut shop_owner_query = ecs_world
-                                .query_one::<(&mut Hates, &Named)>(owner)
+                                .query_one::<(&mut Hates, &Named, &mut ShopOwner)>(owner)
                                 .expect("owner must be named and hate");
-                            if let Some((hates, named_owner)) = shop_owner_query.get() {
+                            if let Some((hates, named_owner, shop_owner)) = shop_owner_query.get() {
                                 if collector.id() == player_id {
                                     game_state.game_log.add_entry(Cow::Owned(format!(
                                         "You stole the {}{}! The {} gets angry!",
                                         named_item.name, corpse_text, named_owner.name
                                     )));
                                 } else if zone.visible_tiles
-                                    [Zone::get_index_from_xy(&position.x, &position.y)]
+                                    [zone.get_index_from_xy(&position.x, &position.y)]
                                 {
                                     game_state.game_log.add_entry(Cow::Owned(format!(
                                         "The {} stoles the {}{}! The {} gets angry!",
@@ -153,6 +154,7 @@ impl ItemCollection {
                                 }
 
                                 hates.list.insert(collector.id());
+                                shop_owner.times_wronged += 1;
                             }
                         }
                     }
@@ -138,7 +138,7 @@ impl ItemEquipping {
                                     .entries
                                     .push(Cow::Owned(format!("You equip the {}", named_item.name)));
                             } else if zone.visible_tiles
-                                [Zone::get_index_from_xy(&position.x, &position.y)]
+                                [zone.get_index_from_xy(&position.x, &position.y)]
                             {
                                 game_state.game_log.add_entry(Cow::Owned(format!(
                                     "{} equips the {}",
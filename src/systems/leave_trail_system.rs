@@ -28,7 +28,7 @@ impl LeaveTrailSystem {
                 .with::<&MyTurn>();
 
             for (_, (leave, position)) in &mut trailers {
-                let trail_pos_idx = Zone::get_index_from_xy(&position.x, &position.y);
+                let trail_pos_idx = zone.get_index_from_xy(&position.x, &position.y);
                 if !zone.water_tiles[trail_pos_idx] {
                     // Insert the trail tile at the entity's position
                     let _ = zone.decals_tiles.insert(trail_pos_idx, leave.of.clone());
@@ -55,7 +55,7 @@ impl LeaveTrailSystem {
                     intensity: SmellIntensity::None,
                 },
             };
-            let (x, y) = Zone::get_xy_from_index(trail_pos_idx);
+            let (x, y) = zone.get_xy_from_index(trail_pos_idx);
             ecs_world.spawn((smell, TrailCounter { trail_counter }, Position { x, y }));
         }
     }
@@ -78,7 +78,7 @@ impl LeaveTrailSystem {
             for (entity, (trail, position)) in &mut trails_spawned {
                 if trail.trail_counter == 0 {
                     zone.decals_tiles
-                        .remove(&Zone::get_index_from_xy(&position.x, &position.y));
+                        .remove(&zone.get_index_from_xy(&position.x, &position.y));
                     to_despawn.push(entity);
                 } else {
                     trail.trail_counter -= 1;
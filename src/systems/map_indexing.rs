@@ -5,6 +5,7 @@ use crate::{
         common::*,
         items::{InBackback, MustBeFueled, ProduceLight, TurnedOn},
     },
+    constants::LAVA_LIGHT_RADIUS,
     engine::state::GameState,
     maps::zone::Zone,
     systems::fov_manager::FieldOfViewManager,
@@ -37,7 +38,7 @@ impl MapIndexing {
                 },
             )
             .for_each(|position| {
-                let index = Zone::get_index_from_xy(&position.x, &position.y);
+                let index = zone.get_index_from_xy(&position.x, &position.y);
                 zone.blocked_tiles[index] = true;
             });
 
@@ -50,7 +51,8 @@ impl MapIndexing {
         zone.clear_content_index();
         //index all the things in the zone based on their position
         for (entity, (position, ..)) in &mut entities_with_pos {
-            zone.tile_content[Zone::get_index_from_xy(&position.x, &position.y)].push(entity);
+            let index = zone.get_index_from_xy(&position.x, &position.y);
+            zone.tile_content[index].push(entity);
         }
     }
 
@@ -111,7 +113,35 @@ impl MapIndexing {
                 for index in viewshed.visible_tiles {
                     zone.lit_tiles[index] = true;
                 }
-            })
+            });
+
+        // Lava glows on its own. It seldom changes, so its light is computed again only when needed
+        if zone.must_recalculate_lava_light {
+            zone.must_recalculate_lava_light = false;
+            zone.lava_lit_tiles.fill(false);
+
+            let lava_tiles: Vec<usize> = (0..zone.tiles.len())
+                .filter(|&index| zone.is_lava(index))
+                .collect();
+            for index in lava_tiles {
+                let (x, y) = zone.get_xy_from_index(index);
+                let mut viewshed = Viewshed {
+                    visible_tiles: Vec::new(),
+                    range: LAVA_LIGHT_RADIUS,
+                    must_recalculate: true,
+                };
+
+                FieldOfViewManager::compute(zone, &mut viewshed, x, y);
+
+                for index in viewshed.visible_tiles {
+                    zone.lava_lit_tiles[index] = true;
+                }
+            }
+        }
+
+        for (lit, &lava_lit) in zone.lit_tiles.iter_mut().zip(zone.lava_lit_tiles.iter()) {
+            *lit |= lava_lit;
+        }
     }
 }
 
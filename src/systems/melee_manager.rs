@@ -3,7 +3,7 @@ use crate::{
         combat::{Grappled, InflictsDamage},
         common::{Immunity, ImmunityTypeEnum},
         health::{Blind, DiseaseType, Diseased},
-        monster::{DiseaseBearer, Grappler, LeaveTrail},
+        monster::{Awareness, AwarenessState, DiseaseBearer, Grappler, LeaveTrail},
     },
     constants::{ACID_DECAL_DAMAGE_DICE, MAX_DISEASE_TICK_COUNTER},
     engine::state::GameState,
@@ -26,7 +26,7 @@ use crate::{
         monster::Venomous,
     },
     constants::MAX_HIDDEN_TURNS,
-    maps::zone::Zone,
+    maps::zone::{NoiseType, Zone},
     utils::roll::Roll,
 };
 
@@ -43,6 +43,7 @@ impl MeleeManager {
         let mut hidden_list: Vec<Entity> = Vec::new();
         let mut infected_list: Vec<(Entity, DiseaseType)> = Vec::new();
         let mut grappled_entities: Vec<(Entity, Entity)> = Vec::new();
+        let mut attacked_list: Vec<Entity> = Vec::new();
 
         // Scope for keeping borrow checker quiet
         {
@@ -69,7 +70,7 @@ impl MeleeManager {
 
             let zone = game_state
                 .current_zone
-                .as_ref()
+                .as_mut()
                 .expect("must have Some Zone");
 
             for (
@@ -89,6 +90,14 @@ impl MeleeManager {
                 let attacker_is_player = attacker.id() == player_id;
                 let target_is_player = wants_melee.target.id() == player_id;
 
+                if attacker_is_player {
+                    zone.add_noise(
+                        attacker_position.x,
+                        attacker_position.y,
+                        NoiseType::Fighting,
+                    );
+                }
+
                 //Sum damage, keeping in mind that could not have SufferingDamage component
                 if let Ok(mut target_damage) =
                     ecs_world.get::<&mut SufferingDamage>(wants_melee.target)
@@ -105,6 +114,7 @@ impl MeleeManager {
                             Option<&Grappled>,
                             Option<&Immunity>,
                             Option<&LeaveTrail>,
+                            Option<&Awareness>,
                         )>(wants_melee.target)
                         .unwrap_or_else(|_| {
                             panic!(
@@ -121,6 +131,7 @@ impl MeleeManager {
                         target_grappled_opt,
                         target_immunity_opt,
                         target_trail_opt,
+                        target_awareness_opt,
                     )) = target_query.get()
                     {
                         let (attacker_dice_number, attacker_dice, erosion) =
@@ -141,9 +152,13 @@ impl MeleeManager {
                             &mut equipped_armors,
                         );
                         // Sneak attack doubles damage
-                        // Can sneak attack if hidden or target is blind or grappled
+                        // Can sneak attack if hidden or target is blind, grappled or unaware
                         let is_grappled_by_attacker = target_grappled_opt.is_some()
                             && target_grappled_opt.unwrap().by.id() == attacker.id();
+                        let target_is_unaware = target_awareness_opt.is_some_and(|awareness| {
+                            awareness.state == AwarenessState::Asleep
+                                || awareness.state == AwarenessState::Unaware
+                        });
 
                         //Venomous damage targets toughness ignoring armor
                         match venomous_opt {
@@ -177,7 +192,7 @@ impl MeleeManager {
                                         )));
                                     } else {
                                         // Log NPC infighting only if visible
-                                        if zone.visible_tiles[Zone::get_index_from_xy(
+                                        if zone.visible_tiles[zone.get_index_from_xy(
                                             &attacker_position.x,
                                             &attacker_position.y,
                                         )] {
@@ -202,6 +217,7 @@ impl MeleeManager {
                                 if is_grappled_by_attacker
                                     || hidden_opt.is_some()
                                     || target_blind_opt.is_some()
+                                    || target_is_unaware
                                 {
                                     damage_roll = max(
                                         0,
@@ -222,7 +238,7 @@ impl MeleeManager {
                                         )));
                                     } else {
                                         // Log NPC infighting only if visible
-                                        if zone.visible_tiles[Zone::get_index_from_xy(
+                                        if zone.visible_tiles[zone.get_index_from_xy(
                                             &attacker_position.x,
                                             &attacker_position.y,
                                         )] {
@@ -268,7 +284,7 @@ impl MeleeManager {
                                         )));
                                     } else {
                                         // Log NPC infighting only if visible
-                                        if zone.visible_tiles[Zone::get_index_from_xy(
+                                        if zone.visible_tiles[zone.get_index_from_xy(
                                             &attacker_position.x,
                                             &attacker_position.y,
                                         )] {
@@ -347,10 +363,9 @@ impl MeleeManager {
                                         "The {} grabs on you!",
                                         named_attacker.name
                                     )));
-                                } else if zone.visible_tiles[Zone::get_index_from_xy(
-                                    &attacker_position.x,
-                                    &attacker_position.y,
-                                )] {
+                                } else if zone.visible_tiles[zone
+                                    .get_index_from_xy(&attacker_position.x, &attacker_position.y)]
+                                {
                                     game_state.game_log.add_entry(Cow::Owned(format!(
                                         "The {} grabs on the {}!",
                                         named_attacker.name, named_target.name
@@ -373,6 +388,7 @@ impl MeleeManager {
                         }
 
                         wants_to_melee_list.push((attacker, attacker_stats.speed));
+                        attacked_list.push(wants_melee.target);
                     } else if ecs_world.contains(wants_melee.target) {
                         println!(
                             "---- wants_melee.target {:?} has no CombatStats or Named, wat? ----",
@@ -398,6 +414,13 @@ impl MeleeManager {
         for attacker in hidden_list {
             let _ = ecs_world.remove_one::<IsHidden>(attacker);
         }
+        // Being attacked makes anyone fully aware of the attacker
+        for target in attacked_list {
+            if let Ok(mut awareness) = ecs_world.get::<&mut Awareness>(target) {
+                awareness.state = AwarenessState::Hunting;
+            }
+        }
+
         // Grappled entities
         for (by, grappled_ent) in grappled_entities {
             let _ = ecs_world.insert_one(grappled_ent, Grappled { by });
@@ -1,16 +1,25 @@
 pub mod advancement_system;
 pub mod apply_system;
 pub mod automatic_healing;
+pub mod awareness_manager;
 pub mod damage_manager;
 pub mod decay_manager;
+pub mod dialogue_manager;
 pub mod dig_manager;
+pub mod door_manager;
 pub mod drinking_quaffables;
 pub mod eating_edibles;
+pub mod faction_manager;
+pub mod fall_manager;
+pub mod fire_manager;
 pub mod fov_manager;
 pub mod fuel_manager;
+pub mod gas_manager;
 pub mod gaze_attacks_manager;
 pub mod health_manager;
+pub mod heat_manager;
 pub mod hidden_manager;
+pub mod hireling_manager;
 pub mod hunger_check;
 pub mod invoke_manager;
 pub mod item_collection;
@@ -21,14 +30,19 @@ pub mod map_indexing;
 pub mod melee_manager;
 pub mod monster_approach;
 pub mod monster_think;
+pub mod morale_manager;
+pub mod noise_manager;
 pub mod particle_manager;
+pub mod quest_manager;
 pub mod ranged_manager;
 pub mod smell_manager;
 pub mod sound_system;
 pub mod special_tiles_system;
 pub mod spell_manager;
+pub mod swim_manager;
 pub mod thirst_check;
 pub mod trade_system;
+pub mod trap_manager;
 pub mod turn_checker;
 pub mod wet_manager;
 
@@ -4,9 +4,10 @@ use hecs::Entity;
 
 use crate::{
     components::{
+        actions::WantsToOpenDoor,
         combat::{CombatStats, Grappled, SufferingDamage},
         common::*,
-        monster::{Aquatic, LeaveTrail, Monster, SnakeBody, SnakeHead, WantsToApproach},
+        monster::{Aquatic, Fleeing, LeaveTrail, Monster, SnakeBody, SnakeHead, WantsToApproach},
     },
     constants::ACID_DECAL_DAMAGE_DICE,
     engine::state::GameState,
@@ -24,6 +25,7 @@ impl MonsterApproach {
 
         let mut waiter_speed_list: Vec<(Entity, i32)> = Vec::new();
         let mut approacher_list: Vec<Entity> = Vec::new();
+        let mut door_openers_list: Vec<(Entity, (i32, i32))> = Vec::new();
         // Scope for keeping borrow checker quiet
         {
             let mut named_monsters = ecs_world
@@ -39,6 +41,7 @@ impl MonsterApproach {
                     Option<&Immobile>,
                     Option<&SnakeHead>,
                     Option<&Grappled>,
+                    Option<&Fleeing>,
                 )>()
                 .with::<(&Monster, &MyTurn)>()
                 .without::<&SnakeBody>();
@@ -63,10 +66,11 @@ impl MonsterApproach {
                     immobile_opt,
                     snake_head_opt,
                     grappled_opt,
+                    fleeing_opt,
                 ),
             ) in &mut named_monsters
             {
-                let current_pos_index = Zone::get_index_from_xy(&position.x, &position.y);
+                let current_pos_index = zone.get_index_from_xy(&position.x, &position.y);
 
                 // Checking if could slip on slime before moving away
                 if leave_trail_opt.is_none() && snake_head_opt.is_none() // Snake monsters cannot slip (TODO really?)
@@ -76,7 +80,7 @@ impl MonsterApproach {
                     // Do DEX saving or slip on slime!
                     if stats.current_dexterity < Roll::d20() {
                         waiter_speed_list.push((monster_entity, stats.speed));
-                        if zone.visible_tiles[Zone::get_index_from_xy(&position.x, &position.y)] {
+                        if zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)] {
                             // Log NPC infighting only if visible
                             game_state.game_log.add_entry(Cow::Owned(format!(
                                 "The {} slips on the slime!",
@@ -103,7 +107,7 @@ impl MonsterApproach {
                             g_query.get().expect("g_query must have result");
 
                         // Grappler lose turn
-                        if zone.visible_tiles[Zone::get_index_from_xy(&position.x, &position.y)] {
+                        if zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)] {
                             game_state.game_log.add_entry(Cow::Owned(format!(
                                 "The {} escapes the {}'s grasp!",
                                 named.name, grappler_name.name
@@ -117,6 +121,19 @@ impl MonsterApproach {
                     }
                 }
 
+                // Fleeing monsters do not look where they run, and may stumble into a chasm nearby
+                if fleeing_opt.is_some()
+                    && snake_head_opt.is_none()
+                    && let Some(chasm_index) = MonsterApproach::get_adjacent_chasm(zone, position)
+                    && stats.current_dexterity < Roll::d20()
+                {
+                    viewshed.must_recalculate = true;
+                    zone.blocked_tiles[current_pos_index] = false;
+                    (position.x, position.y) = zone.get_xy_from_index(chasm_index);
+                    approacher_list.push(monster_entity);
+                    continue;
+                }
+
                 let pathfinding_result = Pathfinding::dijkstra_wrapper(
                     position.x,
                     position.y,
@@ -128,14 +145,21 @@ impl MonsterApproach {
                 );
 
                 //If can actually reach the new position, do it or else stay still
-                if let Some((path, _)) = pathfinding_result
+                if let Some((path, _)) = &pathfinding_result
+                    && path.len() > 1
+                    && zone.is_door_closed(zone.get_index_from_xy(&path[1].0, &path[1].1))
+                {
+                    // A closed door is in the way, must open or bash it before going on
+                    door_openers_list.push((monster_entity, path[1]));
+                } else if let Some((path, _)) = pathfinding_result
                     && path.len() > 1
                 {
                     // Update view
                     viewshed.must_recalculate = true;
 
                     // Avoid overlap with other monsters and player
-                    zone.blocked_tiles[Zone::get_index_from_xy(&position.x, &position.y)] = false;
+                    let tile_index = zone.get_index_from_xy(&position.x, &position.y);
+                    zone.blocked_tiles[tile_index] = false;
 
                     // Shift snake body parts to previous part position
                     // .......    .......
@@ -164,7 +188,8 @@ impl MonsterApproach {
                     //Move monster (or head)
                     position.x = path[1].0;
                     position.y = path[1].1;
-                    zone.blocked_tiles[Zone::get_index_from_xy(&position.x, &position.y)] = true;
+                    let tile_index = zone.get_index_from_xy(&position.x, &position.y);
+                    zone.blocked_tiles[tile_index] = true;
 
                     // Checking if could step on acid after moving
                     if leave_trail_opt.is_none()
@@ -175,7 +200,7 @@ impl MonsterApproach {
                         if stats.current_dexterity < Roll::d20() {
                             suffering_damage.damage_received +=
                                 Roll::dice(1, ACID_DECAL_DAMAGE_DICE);
-                            if zone.visible_tiles[Zone::get_index_from_xy(&position.x, &position.y)]
+                            if zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)]
                             {
                                 // Log only if visible
                                 game_state.game_log.add_entry(Cow::Owned(format!(
@@ -199,6 +224,12 @@ impl MonsterApproach {
             }
         }
 
+        // Door openers will wait after dealing with the door
+        for &(opener, target) in &door_openers_list {
+            waiter_speed_list.retain(|&(waiter, _)| waiter.id() != opener.id());
+            let _ = ecs_world.insert_one(opener, WantsToOpenDoor { target });
+        }
+
         // TODO account speed penalties
         for (waiter, speed) in waiter_speed_list {
             Utils::wait_after_action(ecs_world, waiter, speed);
@@ -208,4 +239,16 @@ impl MonsterApproach {
             let _ = ecs_world.remove_one::<WantsToApproach>(approacher);
         }
     }
+
+    /// Return the index of a chasm or pit next to the position, if any
+    fn get_adjacent_chasm(zone: &Zone, position: &Position) -> Option<usize> {
+        for y in position.y - 1..=position.y + 1 {
+            for x in position.x - 1..=position.x + 1 {
+                if zone.is_inside(&x, &y) && zone.is_chasm(zone.get_index_from_xy(&x, &y)) {
+                    return Some(zone.get_index_from_xy(&x, &y));
+                }
+            }
+        }
+        None
+    }
 }
@@ -8,10 +8,13 @@ use crate::components::items::Equippable;
 use crate::components::items::Equipped;
 use crate::components::monster::Prey;
 use crate::components::monster::StoneEater;
-use crate::constants::MAP_HEIGHT;
-use crate::constants::MAP_WIDTH;
 use crate::constants::MAX_PRIORITIES_NUMBER;
+use crate::constants::MONSTER_SEARCH_RADIUS;
+use crate::constants::MONSTER_SEARCH_TURNS;
+use crate::constants::STONE_EATER_TUNNEL_CHANCE;
 use crate::engine::state::GameState;
+use crate::maps::zone::TileType;
+use crate::spawning::spawner::Spawn;
 use crate::utils::common::EdibleInBackpack;
 use crate::utils::roll::Roll;
 use std::collections::HashSet;
@@ -21,18 +24,22 @@ use hecs::{Entity, World};
 use crate::{
     components::{
         actions::{WantsItem, WantsToEat, WantsToEquip, WantsToInvoke},
-        combat::{WantsToMelee, WantsToZap},
+        combat::{CombatStats, WantsToMelee, WantsToZap},
         common::*,
         health::Hunger,
         items::{Bulky, Deadly, Edible, Item},
-        monster::{Aquatic, Monster, Small, Smart, WantsToApproach},
+        monster::{
+            Aquatic, Awareness, AwarenessState, Fleeing, Hireling, HirelingCommand, Memory,
+            Monster, PackMember, Small, Smart, Surrendered, WantsToApproach,
+        },
         player::Player,
     },
     constants::{
-        MAX_ITEMS_IN_BACKPACK, MAX_ITEMS_IN_BACKPACK_FOR_SMALL, NEXT_TO_DISTANCE, ON_TOP_DISTANCE,
+        HIRELING_FOLLOW_DISTANCE, MAX_ITEMS_IN_BACKPACK, MAX_ITEMS_IN_BACKPACK_FOR_SMALL,
+        NEXT_TO_DISTANCE, ON_TOP_DISTANCE,
     },
     maps::zone::Zone,
-    systems::hunger_check::HungerStatus,
+    systems::{faction_manager::FactionManager, hunger_check::HungerStatus},
     utils::{
         common::{ItemsInBackpack, Utils},
         pathfinding::Pathfinding,
@@ -57,18 +64,28 @@ struct MonsterThinkData<'a> {
     viewshed: &'a Viewshed,
     hunger: &'a Hunger,
     self_id: &'a u32,
-    _player_id: &'a u32,
+    level: u32,
+    player_id: &'a u32,
+    is_unaware: bool,
     is_smart: bool,
     is_small: bool,
     can_invoke: bool,
     can_shoot: bool,
     species: &'a SpeciesEnum,
     hates: &'a HashSet<u32>,
+    leader_hates: Option<&'a HashSet<u32>>,
+    leader_position: Option<(i32, i32)>,
+    owner_id: Option<u32>,
+    owner_species: Option<SpeciesEnum>,
+    command_target: Option<u32>,
+    is_player_friend: bool,
     backpack_is_not_full: bool,
     is_prey: bool,
     can_cast: bool,
     can_eat_stone: bool,
     has_dig_tool: bool,
+    can_smell: Option<&'a CanSmell>,
+    listen_radius: Option<f32>,
 }
 
 type MonsterTargetPick = (MonsterAction, Option<Entity>, i32, i32);
@@ -80,10 +97,10 @@ impl MonsterThink {
     /// Monster acting function
     pub fn run(game_state: &mut GameState) {
         let ecs_world = &mut game_state.ecs_world;
-        let player_id = game_state
+        let player = game_state
             .current_player_entity
-            .expect("Player id should be set")
-            .id();
+            .expect("Player id should be set");
+        let player_id = player.id();
 
         let mut approacher_list: Vec<(Entity, i32, i32, u32)> = Vec::new();
         let mut pickup_list: Vec<(Entity, Entity)> = Vec::new();
@@ -95,6 +112,9 @@ impl MonsterThink {
         let mut equipper_item_list: Vec<(Entity, Entity)> = Vec::new();
         let mut gaze_at_target_list: Vec<(Entity, Entity)> = Vec::new();
         let mut dig_target_list: Vec<(Entity, Entity, Option<Entity>)> = Vec::new();
+        let mut tunnel_list: Vec<(Entity, i32, i32)> = Vec::new();
+        let mut remember_list: Vec<(Entity, Vec<(Entity, i32, i32)>)> = Vec::new();
+        let mut search_list: Vec<(Entity, u32, bool)> = Vec::new();
 
         // Scope for keeping borrow checker quiet
         {
@@ -105,6 +125,7 @@ impl MonsterThink {
                     &Species,
                     &Hates,
                     &Hunger,
+                    &CombatStats,
                     Option<&Small>,
                     Option<&Smart>,
                     Option<&Aquatic>,
@@ -112,8 +133,18 @@ impl MonsterThink {
                     Option<&Prey>,
                     Option<&GazeAttack>,
                     Option<&StoneEater>,
+                    (
+                        Option<&CanSmell>,
+                        Option<&CanListen>,
+                        Option<&Memory>,
+                        Option<&Awareness>,
+                        Option<&Fleeing>,
+                        Option<&PackMember>,
+                        Option<&Hireling>,
+                    ),
                 )>()
-                .with::<(&Monster, &MyTurn)>();
+                .with::<(&Monster, &MyTurn)>()
+                .without::<&Surrendered>();
 
             let zone = game_state
                 .current_zone
@@ -129,6 +160,7 @@ impl MonsterThink {
                     species,
                     hates,
                     hunger,
+                    stats,
                     small,
                     smart,
                     aquatic,
@@ -136,9 +168,50 @@ impl MonsterThink {
                     is_prey,
                     gaze_attack_opt,
                     stone_eater_opt,
+                    (
+                        can_smell_opt,
+                        can_listen_opt,
+                        memory_opt,
+                        awareness_opt,
+                        fleeing_opt,
+                        pack_member_opt,
+                        hireling_opt,
+                    ),
                 ),
             ) in &mut all_monsters
             {
+                // Sleeping monsters do nothing until woken up
+                if awareness_opt.is_some_and(|a| a.state == AwarenessState::Asleep) {
+                    continue;
+                }
+
+                // Fleeing monsters behave like prey
+                let acts_like_prey = is_prey.is_some() || fleeing_opt.is_some();
+
+                // Pack members follow their leader, hate its enemies and share what they sense with it
+                let leader_opt = pack_member_opt
+                    .map(|pack_member| pack_member.leader)
+                    .filter(|&leader| ecs_world.contains(leader));
+                // Hirelings keep close to their owner and protect it, unless told to stay
+                let owner_position = hireling_opt
+                    .filter(|hireling| hireling.command != HirelingCommand::Stay)
+                    .and_then(|hireling| {
+                        ecs_world
+                            .get::<&Position>(hireling.owner)
+                            .ok()
+                            .map(|owner_pos| (owner_pos.x, owner_pos.y))
+                    });
+                let leader_position = leader_opt
+                    .and_then(|leader| {
+                        ecs_world
+                            .get::<&Position>(leader)
+                            .ok()
+                            .map(|leader_pos| (leader_pos.x, leader_pos.y))
+                    })
+                    .or(owner_position);
+                let leader_hates =
+                    leader_opt.and_then(|leader| ecs_world.get::<&Hates>(leader).ok());
+
                 let mut items_in_backpacks_query = ecs_world.query::<ItemsInBackpack>();
                 let items_in_backpacks: Vec<(Entity, ItemsInBackpack)> =
                     items_in_backpacks_query.iter().collect();
@@ -193,7 +266,9 @@ impl MonsterThink {
                     let (can_shoot, equipped_ranged_weapon) =
                         MonsterThink::check_if_can_shoot(monster, &items_in_backpacks);
 
-                    // Look around for a target and decide what to do
+                    // Look around for a target and decide what to do.
+                    // Enemies seen, smelled or heard will be remembered
+                    let mut sensed_enemies: Vec<(Entity, i32, i32)> = Vec::new();
                     let target_picked = MonsterThink::choose_target_and_action(
                         ecs_world,
                         MonsterThinkData {
@@ -202,26 +277,75 @@ impl MonsterThink {
                             viewshed,
                             hunger,
                             self_id: &monster.id(),
-                            _player_id: &player_id,
+                            level: stats.level,
+                            player_id: &player_id,
+                            is_unaware: awareness_opt
+                                .is_some_and(|a| a.state == AwarenessState::Unaware),
                             is_smart: smart.is_some(),
                             is_small: small.is_some(),
                             can_invoke: !invokables.is_empty(),
                             can_shoot,
                             species: &species.value,
                             hates: &hates.list,
+                            leader_hates: leader_hates.as_ref().map(|h| &h.list),
+                            leader_position,
+                            owner_id: hireling_opt.map(|hireling| hireling.owner.id()),
+                            owner_species: hireling_opt.and_then(|hireling| {
+                                ecs_world
+                                    .get::<&Species>(hireling.owner)
+                                    .ok()
+                                    .map(|owner_species| owner_species.value)
+                            }),
+                            command_target: hireling_opt.and_then(|hireling| {
+                                if let HirelingCommand::Attack(target) = hireling.command {
+                                    Some(target.id())
+                                } else {
+                                    None
+                                }
+                            }),
+                            is_player_friend: FactionManager::is_friendly(
+                                ecs_world, player, monster,
+                            ),
                             backpack_is_not_full: (small.is_none()
                                 && total_items < MAX_ITEMS_IN_BACKPACK)
                                 || (small.is_some()
                                     && total_items < MAX_ITEMS_IN_BACKPACK_FOR_SMALL),
-                            is_prey: is_prey.is_some(),
+                            is_prey: acts_like_prey,
                             can_cast: !castable_spells_list.is_empty(),
                             can_eat_stone: stone_eater_opt.is_some(),
                             has_dig_tool: !dig_tool.is_empty(),
+                            can_smell: can_smell_opt,
+                            listen_radius: can_listen_opt.map(|can_listen| can_listen.radius),
                         },
+                        &mut sensed_enemies,
                     );
 
+                    if !sensed_enemies.is_empty() {
+                        if let Some(leader) = leader_opt {
+                            remember_list.push((leader, sensed_enemies.clone()));
+                        }
+                        remember_list.push((monster, sensed_enemies));
+                    }
+
                     // If enemy can see target, do action relative to it
-                    let (action, target, target_x, target_y) = target_picked;
+                    let (action, target, mut target_x, mut target_y) = target_picked;
+
+                    // Pack members and hirelings surround their enemies instead of queuing behind each other
+                    if (leader_opt.is_some() || hireling_opt.is_some())
+                        && !acts_like_prey
+                        && action == MonsterAction::Move
+                        && target.is_some_and(|t| {
+                            ecs_world.satisfies::<&CombatStats>(t).unwrap_or(false)
+                        })
+                    {
+                        (target_x, target_y) = MonsterThink::get_flanking_position(
+                            zone,
+                            position,
+                            target_x,
+                            target_y,
+                            aquatic.is_some(),
+                        );
+                    }
 
                     // Gaze attacks are directed to the target the monster is looking at
                     // These attacks are nasty because they are additional attacks in that turn
@@ -245,19 +369,80 @@ impl MonsterThink {
                             );
 
                             //If can actually reach the position
-                            if let Some((path, _)) = pathfinding_result
+                            if hireling_opt
+                                .is_some_and(|hireling| hireling.command == HirelingCommand::Stay)
+                            {
+                                // Hirelings told to stay hold their position
+                            } else if let Some((path, _)) = pathfinding_result
                                 && path.len() > 1
                                 && target.is_some()
                             {
                                 // Approach something of its interest. x,y are passed to avoid unique borrow issues later on
                                 approacher_list.push((monster, target_x, target_y, 0));
+                            } else if let Some((owner_x, owner_y)) = owner_position {
+                                // Hirelings with nothing better to do follow their owner
+                                if Utils::distance(&position.x, &owner_x, &position.y, &owner_y)
+                                    > HIRELING_FOLLOW_DISTANCE
+                                {
+                                    approacher_list.push((monster, owner_x, owner_y, 0));
+                                }
+                            } else if fleeing_opt.is_none()
+                                && let Some(memory) = memory_opt
+                                && let Some((&enemy_id, &(last_x, last_y))) =
+                                    memory.last_seen.iter().min_by(|(_, a), (_, b)| {
+                                        Utils::distance(&position.x, &a.0, &position.y, &a.1)
+                                            .total_cmp(&Utils::distance(
+                                                &position.x,
+                                                &b.0,
+                                                &position.y,
+                                                &b.1,
+                                            ))
+                                    })
+                            {
+                                // No target in sight, but the monster remembers where an enemy was
+                                if Utils::distance(&position.x, &last_x, &position.y, &last_y)
+                                    <= MONSTER_SEARCH_RADIUS
+                                {
+                                    // Look around the last known position for a while
+                                    let search_x = (Roll::dice(1, 3) - Roll::dice(1, 3) + last_x)
+                                        .clamp(1, zone.width - 1);
+                                    let search_y = (Roll::dice(1, 3) - Roll::dice(1, 3) + last_y)
+                                        .clamp(1, zone.height - 1);
+                                    approacher_list.push((monster, search_x, search_y, 0));
+                                    search_list.push((monster, enemy_id, false));
+                                } else if Pathfinding::dijkstra_wrapper(
+                                    position.x,
+                                    position.y,
+                                    last_x,
+                                    last_y,
+                                    zone,
+                                    true,
+                                    aquatic.is_some(),
+                                )
+                                .is_some()
+                                {
+                                    approacher_list.push((monster, last_x, last_y, 0));
+                                } else {
+                                    // Cannot get there, better forget about it
+                                    search_list.push((monster, enemy_id, true));
+                                }
+                            } else if stone_eater_opt.is_some()
+                                && hunger.current_status != HungerStatus::Satiated
+                                && Roll::d6() <= STONE_EATER_TUNNEL_CHANCE
+                                && let Some((wall_x, wall_y)) =
+                                    MonsterThink::pick_wall_to_tunnel(ecs_world, zone, position)
+                            {
+                                // Hungry StoneEaters with nothing better to do eat their way through the walls
+                                tunnel_list.push((monster, wall_x, wall_y));
                             } else if wants_to_approach.is_none() {
                                 // No target in sight, wander around for a while (if not already doing so)
-                                // clamped inside map
+                                // clamped inside map. Pack members stay close to their leader
+                                let (center_x, center_y) =
+                                    leader_position.unwrap_or((position.x, position.y));
                                 let random_dest_x =
-                                    (Roll::d6() - Roll::d6() + position.x).clamp(1, MAP_WIDTH - 1);
+                                    (Roll::d6() - Roll::d6() + center_x).clamp(1, zone.width - 1);
                                 let random_dest_y =
-                                    (Roll::d6() - Roll::d6() + position.y).clamp(1, MAP_HEIGHT - 1);
+                                    (Roll::d6() - Roll::d6() + center_y).clamp(1, zone.height - 1);
                                 approacher_list.push((monster, random_dest_x, random_dest_y, 3));
                             }
                         }
@@ -314,6 +499,39 @@ impl MonsterThink {
             }
         }
 
+        // Remember where the enemies were
+        for (monster, sensed_enemies) in remember_list {
+            if let Ok(mut memory) = ecs_world.get::<&mut Memory>(monster) {
+                for (enemy, x, y) in sensed_enemies {
+                    memory.last_seen.insert(enemy.id(), (x, y));
+                }
+                memory.search_counter = MONSTER_SEARCH_TURNS;
+            } else {
+                let _ = ecs_world.insert_one(
+                    monster,
+                    Memory {
+                        last_seen: sensed_enemies
+                            .into_iter()
+                            .map(|(enemy, x, y)| (enemy.id(), (x, y)))
+                            .collect(),
+                        search_counter: MONSTER_SEARCH_TURNS,
+                    },
+                );
+            }
+        }
+
+        // Searching takes time, after a while the monster gives up
+        for (searcher, enemy_id, must_forget) in search_list {
+            if let Ok(mut memory) = ecs_world.get::<&mut Memory>(searcher) {
+                if must_forget || memory.search_counter == 0 {
+                    memory.last_seen.remove(&enemy_id);
+                    memory.search_counter = MONSTER_SEARCH_TURNS;
+                } else {
+                    memory.search_counter -= 1;
+                }
+            }
+        }
+
         // Approach if needed
         for (approacher, target_x, target_y, counter) in approacher_list {
             let _ = ecs_world.insert_one(
@@ -362,6 +580,23 @@ impl MonsterThink {
             }
         }
 
+        // start tunnelling if needed, StoneEaters use their own attack
+        for (digger, x, y) in tunnel_list {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+            if let Some(target) = Spawn::tunnel_entity(ecs_world, zone, x, y) {
+                let _ = ecs_world.insert_one(
+                    digger,
+                    WantsToDig {
+                        target,
+                        tool: digger,
+                    },
+                );
+            }
+        }
+
         // pick up item
         for (pickupper, item) in pickup_list {
             let _ = ecs_world.insert_one(
@@ -415,6 +650,14 @@ impl MonsterThink {
                 },
             );
         }
+
+        // Every monster that could hear the last noises has already thought about them
+        game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone")
+            .last_noises
+            .clear();
     }
 
     /// Check if the monster has nothing that can equip and do it if is the case.
@@ -474,13 +717,14 @@ impl MonsterThink {
     fn choose_target_and_action(
         ecs_world: &World,
         monster_dto: MonsterThinkData,
+        sensed_enemies: &mut Vec<(Entity, i32, i32)>,
     ) -> MonsterTargetPick {
         // Array to put the targets found in order of priority (0 = top priority, 4 = least priority)
         let mut targets_vec: Vec<Option<MonsterTargetPick>> = vec![None; MAX_PRIORITIES_NUMBER];
 
         // Search in range of view possible targets
         for &index in monster_dto.viewshed.visible_tiles.iter() {
-            let (x, y) = Zone::get_xy_from_index(index);
+            let (x, y) = monster_dto.zone.get_xy_from_index(index);
 
             let distance: f32 =
                 Utils::distance(&monster_dto.position.x, &x, &monster_dto.position.y, &y);
@@ -492,8 +736,15 @@ impl MonsterThink {
                     let is_creature = ecs_world.satisfies::<&Player>(entity).unwrap_or(false)
                         || ecs_world.satisfies::<&Monster>(entity).unwrap_or(false);
 
+                    // Unaware monsters have not noticed the player yet
+                    let is_unnoticed_player =
+                        monster_dto.is_unaware && entity.id() == *monster_dto.player_id;
+
                     // If looking at a creature that is not hidden
-                    if is_creature && !ecs_world.satisfies::<&IsHidden>(entity).unwrap_or(false) {
+                    if is_creature
+                        && !ecs_world.satisfies::<&IsHidden>(entity).unwrap_or(false)
+                        && !is_unnoticed_player
+                    {
                         // Attack if next to it and is not prey
                         if distance < NEXT_TO_DISTANCE && !monster_dto.is_prey {
                             action = MonsterAction::Attack;
@@ -510,11 +761,18 @@ impl MonsterThink {
                             action = MonsterAction::Move;
                         }
 
+                        // Hirelings attack the target chosen by their owner before anything else.
                         // Starvation makes the monster behave more aggressively
-                        // Should be a cannibal in this state
-                        // TODO do not make it suicidial, do level check on target
-                        if monster_dto.hunger.current_status == HungerStatus::Starved
+                        // Should be a cannibal in this state, but not suicidal:
+                        // creatures of higher level are left alone, and so is the owner
+                        if monster_dto.command_target == Some(entity.id()) {
+                            targets_vec[0] = Some((action, Some(entity), x, y));
+                        } else if monster_dto.hunger.current_status == HungerStatus::Starved
                             && targets_vec[0].is_none()
+                            && monster_dto.owner_id != Some(entity.id())
+                            && ecs_world
+                                .get::<&CombatStats>(entity)
+                                .is_ok_and(|target_stats| target_stats.level <= monster_dto.level)
                         {
                             targets_vec[0] = Some((action, Some(entity), x, y));
                         } else {
@@ -522,11 +780,19 @@ impl MonsterThink {
                                 .get::<&Species>(entity)
                                 .expect("must have Species");
 
-                            let is_enemy = Utils::what_hates(monster_dto.species)
-                                .contains(&target_species.value)
-                                || monster_dto.hates.contains(&entity.id());
+                            let is_enemy = MonsterThink::is_enemy(
+                                ecs_world,
+                                &monster_dto,
+                                entity,
+                                &target_species.value,
+                            );
 
                             if is_enemy {
+                                // Prey do not look for their predators
+                                if !monster_dto.is_prey {
+                                    sensed_enemies.push((entity, x, y));
+                                }
+
                                 //Enemy target is far away, try to approach it. Unless it's prey, than it should escape
                                 if monster_dto.is_prey && targets_vec[0].is_none() {
                                     // If prey but can somehow do a ranged attack, just attack
@@ -542,10 +808,22 @@ impl MonsterThink {
                                                 &x,
                                                 &y,
                                                 monster_dto.viewshed,
+                                                monster_dto.zone,
                                             );
                                         targets_vec[0] =
                                             Some((action, Some(entity), target_x, target_y));
                                     }
+                                } else if !monster_dto.is_prey
+                                    && targets_vec[0].is_none()
+                                    && monster_dto.leader_position.is_some_and(
+                                        |(leader_x, leader_y)| {
+                                            Utils::distance(&leader_x, &x, &leader_y, &y)
+                                                < NEXT_TO_DISTANCE
+                                        },
+                                    )
+                                {
+                                    // Protect the leader before anything else
+                                    targets_vec[0] = Some((action, Some(entity), x, y));
                                 } else if targets_vec[1].is_none() {
                                     targets_vec[1] = Some((action, Some(entity), x, y));
                                 }
@@ -622,6 +900,8 @@ impl MonsterThink {
             }
         }
 
+        MonsterThink::sense_unseen_enemies(ecs_world, &monster_dto, sensed_enemies);
+
         // return the first valid target by priority
         // .flatten() gets all the Some(_) values, .next() gets the first element
         if let Some(monster_action) = targets_vec.into_iter().flatten().next() {
@@ -632,6 +912,129 @@ impl MonsterThink {
         (MonsterAction::Move, None, -1, -1)
     }
 
+    /// Enemies out of sight could still be smelled or heard
+    fn sense_unseen_enemies(
+        ecs_world: &World,
+        monster_dto: &MonsterThinkData,
+        sensed_enemies: &mut Vec<(Entity, i32, i32)>,
+    ) {
+        if monster_dto.is_prey
+            || (monster_dto.can_smell.is_none() && monster_dto.listen_radius.is_none())
+        {
+            return;
+        }
+
+        let mut creatures = ecs_world
+            .query::<(&Position, &Species, Option<&Smellable>)>()
+            .with::<&CombatStats>();
+
+        for (creature, (position, species, smellable_opt)) in &mut creatures {
+            let index = monster_dto.zone.get_index_from_xy(&position.x, &position.y);
+            if creature.id() == *monster_dto.self_id
+                || (monster_dto.is_unaware && creature.id() == *monster_dto.player_id)
+                || monster_dto.viewshed.visible_tiles.contains(&index)
+                || !MonsterThink::is_enemy(ecs_world, monster_dto, creature, &species.value)
+            {
+                continue;
+            }
+
+            let distance = Utils::distance(
+                &monster_dto.position.x,
+                &position.x,
+                &monster_dto.position.y,
+                &position.y,
+            );
+
+            // Nothing can be smelled in water
+            let is_smelled = !monster_dto.zone.water_tiles[index]
+                && monster_dto
+                    .can_smell
+                    .zip(smellable_opt)
+                    .is_some_and(|(can_smell, smellable)| can_smell.can_smell(smellable, distance));
+
+            // A noise made right where the creature stands gives it away
+            let is_heard = monster_dto.listen_radius.is_some_and(|listen_radius| {
+                monster_dto.zone.last_noises.iter().any(|noise| {
+                    let noise_distance = Utils::distance(
+                        &monster_dto.position.x,
+                        &noise.x,
+                        &monster_dto.position.y,
+                        &noise.y,
+                    );
+                    Utils::distance(&position.x, &noise.x, &position.y, &noise.y) < 1.5
+                        && noise_distance < listen_radius
+                        && noise_distance <= noise.noise_type.radius()
+                })
+            });
+
+            if is_smelled || is_heard {
+                sensed_enemies.push((creature, position.x, position.y));
+            }
+        }
+    }
+
+    /// Check if a creature is hated by the monster, by species or for personal reasons
+    fn is_enemy(
+        ecs_world: &World,
+        monster_dto: &MonsterThinkData,
+        creature: Entity,
+        creature_species: &SpeciesEnum,
+    ) -> bool {
+        // Hirelings never turn on their owner or its other hirelings,
+        // but fight anyone hostile to their owner
+        if let Some(owner_id) = monster_dto.owner_id {
+            if creature.id() == owner_id
+                || ecs_world
+                    .get::<&Hireling>(creature)
+                    .is_ok_and(|hireling| hireling.owner.id() == owner_id)
+            {
+                return false;
+            }
+
+            if monster_dto.owner_species.is_some_and(|owner_species| {
+                Utils::what_hates(creature_species).contains(&owner_species)
+            }) || ecs_world
+                .get::<&Hates>(creature)
+                .is_ok_and(|creature_hates| creature_hates.list.contains(&owner_id))
+            {
+                return true;
+            }
+        }
+
+        // Factions friendly to the player ignore it, whatever its species
+        let is_befriended_player =
+            monster_dto.is_player_friend && creature.id() == *monster_dto.player_id;
+
+        (!is_befriended_player && Utils::what_hates(monster_dto.species).contains(creature_species))
+            || monster_dto.hates.contains(&creature.id())
+            || monster_dto
+                .leader_hates
+                .is_some_and(|leader_hates| leader_hates.contains(&creature.id()))
+    }
+
+    /// Pick the free tile next to the target closest to the monster, so that
+    /// many monsters approaching the same target will surround it
+    fn get_flanking_position(
+        zone: &Zone,
+        position: &Position,
+        target_x: i32,
+        target_y: i32,
+        is_aquatic: bool,
+    ) -> (i32, i32) {
+        zone.get_adjacent_passable_tiles(&target_x, &target_y, false, is_aquatic)
+            .into_iter()
+            .filter(|&(x, y)| x != target_x || y != target_y)
+            .min_by(|a, b| {
+                Utils::distance(&position.x, &a.0, &position.y, &a.1).total_cmp(&Utils::distance(
+                    &position.x,
+                    &b.0,
+                    &position.y,
+                    &b.1,
+                ))
+            })
+            .unwrap_or((target_x, target_y))
+    }
+
     /// Check if the monster can shoot. This is done by checking if the monster has a ranged weapon equipped and has ammo for at least one of them
     fn check_if_can_shoot(
         monster: Entity,
@@ -685,6 +1088,43 @@ impl MonsterThink {
         castable_spells_list
     }
 
+    /// Pick a random wall next to the monster that could be tunnelled through.
+    /// A wall already being dug is always picked first, so the monster ends what it started
+    fn pick_wall_to_tunnel(
+        ecs_world: &World,
+        zone: &Zone,
+        position: &Position,
+    ) -> Option<(i32, i32)> {
+        let mut walls: Vec<(i32, i32)> = Vec::new();
+        for (x, y) in [
+            (position.x + 1, position.y),
+            (position.x - 1, position.y),
+            (position.x, position.y + 1),
+            (position.x, position.y - 1),
+        ] {
+            if x > 0
+                && y > 0
+                && x < zone.width - 1
+                && y < zone.height - 1
+                && zone.tiles[zone.get_index_from_xy(&x, &y)] == TileType::Wall
+            {
+                walls.push((x, y));
+            }
+        }
+
+        let started_wall = walls.iter().copied().find(|(x, y)| {
+            zone.tile_content[zone.get_index_from_xy(x, y)]
+                .iter()
+                .any(|&entity| ecs_world.satisfies::<&Diggable>(entity).unwrap_or(false))
+        });
+
+        if started_wall.is_some() || walls.is_empty() {
+            started_wall
+        } else {
+            Some(walls[Roll::dice(1, walls.len() as i32) as usize - 1])
+        }
+    }
+
     /// Handles edible items in the monster's backpack.
     /// If there is at least one edible item in the backpack of a monster, he will eat it when not satiated.
     fn handle_edibles_in_backpack(
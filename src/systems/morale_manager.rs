@@ -0,0 +1,244 @@
+use std::borrow::Cow;
+
+use hecs::{Entity, World};
+
+use crate::{
+    components::{
+        combat::{CombatStats, IsHidden},
+        common::{Hates, MyTurn, Named, Position, Species, SpeciesEnum, Viewshed},
+        monster::{
+            Awareness, AwarenessState, Fleeing, Hireling, Monster, Morale, Smart, Surrendered,
+            WantsToApproach,
+        },
+    },
+    constants::{
+        ALLY_DEATH_MORALE_PENALTY, CALL_FOR_HELP_APPROACH_COUNTER, CALL_FOR_HELP_RADIUS,
+        FEARLESS_SPECIES, FLEE_TURNS, MORALE_BASE, WOUNDED_MORALE_PENALTY,
+    },
+    engine::state::GameState,
+    maps::zone::Zone,
+    utils::{common::Utils, roll::Roll},
+};
+
+/// Handles the courage of monsters: those who lose it will flee, surrender or call for help
+pub struct MoraleManager {}
+
+impl MoraleManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        let mut fleeing_list: Vec<Entity> = Vec::new();
+        let mut brave_again_list: Vec<Entity> = Vec::new();
+        let mut surrender_list: Vec<Entity> = Vec::new();
+        let mut callers_list: Vec<(Entity, i32, i32, SpeciesEnum)> = Vec::new();
+
+        // Surrendered monsters attacked by the player will fight back
+        let betrayed_list: Vec<Entity> = ecs_world
+            .query::<&Hates>()
+            .with::<&Surrendered>()
+            .iter()
+            .filter_map(|(monster, hates)| {
+                if hates.list.contains(&player.id()) {
+                    Some(monster)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        for monster in betrayed_list {
+            let _ = ecs_world.remove_one::<Surrendered>(monster);
+        }
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+            let player_level = ecs_world
+                .get::<&CombatStats>(player)
+                .expect("Player must have CombatStats")
+                .level as i32;
+            let player_index = {
+                let position = ecs_world
+                    .get::<&Position>(player)
+                    .expect("Player must have Position");
+                zone.get_index_from_xy(&position.x, &position.y)
+            };
+            let player_is_hidden = ecs_world.satisfies::<&IsHidden>(player).unwrap_or(false);
+
+            // Hirelings are kept in line by their loyalty instead
+            let mut monsters = ecs_world
+                .query::<(
+                    &mut Morale,
+                    &CombatStats,
+                    &Species,
+                    &Position,
+                    &Viewshed,
+                    &Named,
+                    Option<&mut Fleeing>,
+                    Option<&Smart>,
+                )>()
+                .with::<(&Monster, &MyTurn)>()
+                .without::<&Surrendered>()
+                .without::<&Hireling>();
+
+            for (
+                monster,
+                (morale, stats, species, position, viewshed, named, fleeing_opt, smart_opt),
+            ) in &mut monsters
+            {
+                // Fleeing monsters will find their courage again after a while
+                if let Some(fleeing) = fleeing_opt {
+                    fleeing.counter -= 1;
+                    if fleeing.counter <= 0 {
+                        brave_again_list.push(monster);
+                    }
+                    continue;
+                }
+
+                // Mindless creatures know no fear
+                if FEARLESS_SPECIES.contains(&species.value) {
+                    morale.allies_lost = 0;
+                    continue;
+                }
+
+                let is_badly_wounded = stats.current_stamina < stats.max_stamina / 2;
+                let must_test_wounds = is_badly_wounded && !morale.has_tested_wounds;
+                if !must_test_wounds && morale.allies_lost == 0 {
+                    continue;
+                }
+
+                let mut morale_target =
+                    MORALE_BASE - morale.allies_lost * ALLY_DEATH_MORALE_PENALTY;
+                if is_badly_wounded {
+                    morale_target -= WOUNDED_MORALE_PENALTY;
+                }
+
+                // Facing a stronger player is scarier, a weaker one less so
+                let sees_player =
+                    !player_is_hidden && viewshed.visible_tiles.contains(&player_index);
+                if sees_player {
+                    morale_target += stats.level as i32 - player_level;
+                }
+
+                morale.allies_lost = 0;
+                morale.has_tested_wounds |= is_badly_wounded;
+
+                if Roll::d20() <= morale_target {
+                    continue;
+                }
+
+                let is_visible =
+                    zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)];
+
+                // Smart humans know they can beg for mercy.
+                // Other smart monsters call their kin, then run away
+                if smart_opt.is_some() && species.value == SpeciesEnum::Human && sees_player {
+                    surrender_list.push(monster);
+                    if is_visible {
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("The {} surrenders!", named.name)));
+                    }
+                    continue;
+                } else if smart_opt.is_some() {
+                    callers_list.push((monster, position.x, position.y, species.value));
+                    if is_visible {
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("The {} calls for help!", named.name)));
+                    }
+                }
+
+                fleeing_list.push(monster);
+                if is_visible {
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("The {} flees in terror!", named.name)));
+                }
+            }
+        }
+
+        for monster in fleeing_list {
+            let _ = ecs_world.insert_one(
+                monster,
+                Fleeing {
+                    counter: FLEE_TURNS,
+                },
+            );
+        }
+
+        for monster in brave_again_list {
+            let _ = ecs_world.remove_one::<Fleeing>(monster);
+        }
+
+        // Surrendered monsters stop fighting the player
+        for monster in surrender_list {
+            let _ = ecs_world.remove_one::<WantsToApproach>(monster);
+            let _ = ecs_world.insert_one(monster, Surrendered {});
+            if let Ok(mut hates) = ecs_world.get::<&mut Hates>(monster) {
+                hates.list.remove(&player.id());
+            }
+        }
+
+        // Kin nearby will come to help
+        for (caller, caller_x, caller_y, caller_species) in callers_list {
+            let helpers: Vec<Entity> = ecs_world
+                .query::<(&Position, &Species)>()
+                .with::<&Monster>()
+                .without::<&Surrendered>()
+                .iter()
+                .filter_map(|(helper, (position, species))| {
+                    if helper.id() != caller.id()
+                        && species.value == caller_species
+                        && Utils::distance(&caller_x, &position.x, &caller_y, &position.y)
+                            <= CALL_FOR_HELP_RADIUS
+                    {
+                        Some(helper)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            for helper in helpers {
+                if let Ok(mut awareness) = ecs_world.get::<&mut Awareness>(helper) {
+                    awareness.state = AwarenessState::Alert;
+                }
+                let _ = ecs_world.insert_one(
+                    helper,
+                    WantsToApproach {
+                        target_x: caller_x,
+                        target_y: caller_y,
+                        counter: CALL_FOR_HELP_APPROACH_COUNTER,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Register the death of a creature: monsters of the same species that saw it die lose courage
+    pub fn register_death(
+        ecs_world: &World,
+        zone: &Zone,
+        x: i32,
+        y: i32,
+        dead_species: &SpeciesEnum,
+    ) {
+        let death_index = zone.get_index_from_xy(&x, &y);
+
+        for (_, (morale, species, viewshed)) in &mut ecs_world
+            .query::<(&mut Morale, &Species, &Viewshed)>()
+            .with::<&Monster>()
+        {
+            if species.value == *dead_species && viewshed.visible_tiles.contains(&death_index) {
+                morale.allies_lost += 1;
+            }
+        }
+    }
+}
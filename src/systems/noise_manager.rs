@@ -0,0 +1,95 @@
+use hecs::Entity;
+
+use crate::{
+    components::{
+        common::{CanListen, Position, Viewshed},
+        monster::{Awareness, AwarenessState, Monster, Surrendered, WantsToApproach},
+    },
+    constants::NOISE_APPROACH_COUNTER,
+    engine::state::GameState,
+    maps::zone::Zone,
+    utils::{common::Utils, roll::Roll},
+};
+
+/// Handles the noises made in the zone: monsters that can hear them will come to investigate
+pub struct NoiseManager {}
+
+impl NoiseManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+
+        if zone.noises.is_empty() {
+            return;
+        }
+
+        let mut investigators_list: Vec<(Entity, i32, i32)> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            // Loud noises could wake up sleeping or unaware monsters nearby
+            let mut sleepers = ecs_world
+                .query::<(&mut Awareness, &Position)>()
+                .with::<&Monster>();
+
+            for (_, (awareness, position)) in &mut sleepers {
+                if (awareness.state == AwarenessState::Asleep
+                    || awareness.state == AwarenessState::Unaware)
+                    && zone.noises.iter().any(|noise| {
+                        Utils::distance(&position.x, &noise.x, &position.y, &noise.y)
+                            <= noise.noise_type.radius()
+                            && Roll::d20() <= noise.noise_type.loudness()
+                    })
+                {
+                    awareness.state = AwarenessState::Alert;
+                }
+            }
+
+            let mut listeners = ecs_world
+                .query::<(&CanListen, &Position, &Viewshed, Option<&Awareness>)>()
+                .with::<&Monster>()
+                .without::<&Surrendered>();
+
+            for (listener, (can_listen, position, viewshed, awareness_opt)) in &mut listeners {
+                // Sleeping monsters do not investigate anything
+                if awareness_opt.is_some_and(|a| a.state == AwarenessState::Asleep) {
+                    continue;
+                }
+
+                // Only the first noise heard will be investigated
+                let heard_noise = zone.noises.iter().find(|noise| {
+                    let distance = Utils::distance(&position.x, &noise.x, &position.y, &noise.y);
+
+                    // No need to investigate something already in sight
+                    distance <= noise.noise_type.radius()
+                        && distance < can_listen.radius
+                        && !viewshed
+                            .visible_tiles
+                            .contains(&zone.get_index_from_xy(&noise.x, &noise.y))
+                        && Roll::d20() <= noise.noise_type.loudness()
+                });
+
+                if let Some(noise) = heard_noise {
+                    investigators_list.push((listener, noise.x, noise.y));
+                }
+            }
+        }
+
+        for (investigator, target_x, target_y) in investigators_list {
+            let _ = ecs_world.insert_one(
+                investigator,
+                WantsToApproach {
+                    target_x,
+                    target_y,
+                    counter: NOISE_APPROACH_COUNTER,
+                },
+            );
+        }
+
+        // Noises last only one tick, then the monsters thinking next can still tell where they came from
+        zone.last_noises.append(&mut zone.noises);
+    }
+}
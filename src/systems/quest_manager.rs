@@ -0,0 +1,450 @@
+use std::borrow::Cow;
+
+use hecs::{Entity, World};
+
+use crate::{
+    components::{
+        combat::CombatStats,
+        common::{Experience, Faction, Immunity, ImmunityTypeEnum, Named, Position, WillChat},
+        health::DiseaseType,
+        items::{Corpse, Equipped, InBackback, Item},
+        monster::{Hireling, Monster},
+        quest::{Quest, QuestId, QuestJournal, QuestKind, QuestReward, QuestState},
+    },
+    constants::{
+        MAX_LOYALTY, NEXT_TO_DISTANCE, QUEST_ESCORT_GOLD, QUEST_GOLD_AMOUNT, QUEST_HUNT_EXPERIENCE,
+    },
+    engine::state::GameState,
+    maps::zone::{TileType, Zone},
+    spawning::spawner::Spawn,
+    systems::{faction_manager::FactionManager, hireling_manager::HirelingManager},
+    utils::common::Utils,
+};
+
+/// Handles the quests given to the player by NPCs: their progress and their rewards
+pub struct QuestManager {}
+
+impl QuestManager {
+    /// Check the progress of the quests that must not be reported to their giver
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let mut completed_list: Vec<usize> = Vec::new();
+        let mut failed_list: Vec<usize> = Vec::new();
+        let mut arrived_list: Vec<(Entity, i32, i32)> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let Ok(journal) = ecs_world.get::<&QuestJournal>(player) else {
+                return;
+            };
+
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            let passages: Vec<(i32, i32)> = zone
+                .tiles
+                .iter()
+                .enumerate()
+                .filter(|(_, tile)| **tile == TileType::DownPassage)
+                .map(|(index, _)| zone.get_xy_from_index(index))
+                .collect();
+
+            for (index, quest) in journal.quests.iter().enumerate() {
+                if quest.state != QuestState::Active {
+                    continue;
+                }
+
+                match quest.kind {
+                    // Whoever killed it, the target is dead
+                    QuestKind::Kill { target } => {
+                        if !ecs_world.contains(target) {
+                            completed_list.push(index);
+                        }
+                    }
+                    QuestKind::Escort { escorted } => {
+                        let is_following = ecs_world
+                            .get::<&Hireling>(escorted)
+                            .is_ok_and(|hireling| hireling.owner.id() == player.id());
+
+                        if !is_following {
+                            failed_list.push(index);
+                        } else if let Ok(position) = ecs_world.get::<&Position>(escorted)
+                            && passages.iter().any(|(x, y)| {
+                                Utils::distance(&position.x, x, &position.y, y) <= NEXT_TO_DISTANCE
+                            })
+                        {
+                            completed_list.push(index);
+                            arrived_list.push((escorted, position.x, position.y));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Escorted creatures go down on their own, leaving behind what they carry
+        for (escorted, x, y) in arrived_list {
+            let carried_items: Vec<Entity> = ecs_world
+                .query::<&InBackback>()
+                .with::<&Item>()
+                .iter()
+                .filter_map(|(item, in_backpack)| {
+                    if in_backpack.owner.id() == escorted.id() {
+                        Some(item)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            for item in carried_items {
+                let _ = ecs_world.remove_one::<Equipped>(item);
+                let _ = ecs_world.exchange_one::<InBackback, Position>(item, Position { x, y });
+            }
+
+            let _ = ecs_world.despawn(escorted);
+        }
+
+        for index in failed_list {
+            QuestManager::fail_quest(game_state, index);
+        }
+        for index in completed_list {
+            QuestManager::complete_quest(game_state, index);
+        }
+    }
+
+    /// Add a new quest to the journal of the player
+    pub fn start_quest(game_state: &mut GameState, giver: Entity, id: QuestId) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let giver_name = ecs_world
+            .get::<&Named>(giver)
+            .expect("Quest giver must be Named")
+            .name;
+        let giver_faction = ecs_world
+            .get::<&Faction>(giver)
+            .ok()
+            .map(|faction| faction.value);
+
+        let quest = match id {
+            QuestId::AbbotGold => Quest {
+                id,
+                description: format!("Bring {} raw gold to the {}", QUEST_GOLD_AMOUNT, giver_name),
+                giver_name,
+                giver_faction,
+                kind: QuestKind::BringItems {
+                    item_name: "raw gold",
+                    amount: QUEST_GOLD_AMOUNT,
+                },
+                state: QuestState::Active,
+                reward: QuestReward::Immunity(ImmunityTypeEnum::Disease(
+                    DiseaseType::Calcification,
+                )),
+            },
+            QuestId::MolemanHunt => {
+                let Some((target, target_name)) = QuestManager::find_prey(ecs_world, giver) else {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "The {} knows of no beast to hunt here",
+                        giver_name
+                    )));
+                    return;
+                };
+                Quest {
+                    id,
+                    description: format!("Kill the {} for the {}", target_name, giver_name),
+                    giver_name,
+                    giver_faction,
+                    kind: QuestKind::Kill { target },
+                    state: QuestState::Active,
+                    reward: QuestReward::Experience(QUEST_HUNT_EXPERIENCE),
+                }
+            }
+            QuestId::RefugeeEscort => {
+                // The escorted creature follows the player like a hireling
+                HirelingManager::enlist(ecs_world, player, giver, MAX_LOYALTY);
+                Quest {
+                    id,
+                    description: format!("Escort the {} to the way down", giver_name),
+                    giver_name,
+                    giver_faction,
+                    kind: QuestKind::Escort { escorted: giver },
+                    state: QuestState::Active,
+                    reward: QuestReward::RawGold(QUEST_ESCORT_GOLD),
+                }
+            }
+            QuestId::FarmerCorpse => Quest {
+                id,
+                description: "Trade a corpse to a shop owner".to_string(),
+                giver_name,
+                giver_faction,
+                kind: QuestKind::DeliverCorpse,
+                state: QuestState::Active,
+                reward: QuestReward::Item(Spawn::helmet),
+            },
+        };
+
+        game_state
+            .game_log
+            .add_entry(Cow::Owned(format!("New quest: {}", quest.description)));
+
+        if let Ok(mut journal) = ecs_world.get::<&mut QuestJournal>(player) {
+            journal.quests.push(quest);
+        }
+    }
+
+    /// True if the player is still working on the quest
+    pub fn is_active(ecs_world: &World, player: Entity, id: QuestId) -> bool {
+        QuestManager::get_active_index(ecs_world, player, id).is_some()
+    }
+
+    /// True if the player has everything that must be brought to the giver
+    pub fn is_ready(ecs_world: &World, player: Entity, id: QuestId) -> bool {
+        let Some(index) = QuestManager::get_active_index(ecs_world, player, id) else {
+            return false;
+        };
+        let Ok(journal) = ecs_world.get::<&QuestJournal>(player) else {
+            return false;
+        };
+
+        match journal.quests[index].kind {
+            QuestKind::BringItems { item_name, amount } => {
+                QuestManager::find_items(ecs_world, player, item_name).len() >= amount
+            }
+            _ => false,
+        }
+    }
+
+    /// Give to the quest giver what was requested and get the reward
+    pub fn turn_in(game_state: &mut GameState, giver: Entity, id: QuestId) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let Some(index) = QuestManager::get_active_index(ecs_world, player, id) else {
+            return;
+        };
+
+        let requested_opt = ecs_world
+            .get::<&QuestJournal>(player)
+            .ok()
+            .and_then(|journal| match journal.quests[index].kind {
+                QuestKind::BringItems { item_name, amount } => Some((item_name, amount)),
+                _ => None,
+            });
+
+        if let Some((item_name, amount)) = requested_opt {
+            let items = QuestManager::find_items(ecs_world, player, item_name);
+            if items.len() < amount {
+                return;
+            }
+            for &item in items.iter().take(amount) {
+                let _ = ecs_world.remove_one::<Equipped>(item);
+                if let Ok(mut in_backpack) = ecs_world.get::<&mut InBackback>(item) {
+                    in_backpack.owner = giver;
+                }
+            }
+        }
+
+        QuestManager::complete_quest(game_state, index);
+    }
+
+    /// Trading a corpse to any shop owner completes the corpse delivery
+    pub fn register_trade(game_state: &mut GameState, traded_item: Entity) {
+        let ecs_world = &game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        if !ecs_world.satisfies::<&Corpse>(traded_item).unwrap_or(false) {
+            return;
+        }
+
+        if let Some(index) =
+            QuestManager::get_active_index(ecs_world, player, QuestId::FarmerCorpse)
+        {
+            QuestManager::complete_quest(game_state, index);
+        }
+    }
+
+    /// Quests about creatures that are left behind on zone change can't be completed anymore
+    pub fn fail_lost_quests(game_state: &mut GameState, lost_entities: &[Entity]) {
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        let failed_list: Vec<usize> = match game_state.ecs_world.get::<&QuestJournal>(player) {
+            Ok(journal) => journal
+                .quests
+                .iter()
+                .enumerate()
+                .filter_map(|(index, quest)| {
+                    let involved_opt = match quest.kind {
+                        QuestKind::Kill { target } => Some(target),
+                        QuestKind::Escort { escorted } => Some(escorted),
+                        _ => None,
+                    };
+                    if quest.state == QuestState::Active
+                        && involved_opt.is_some_and(|involved| lost_entities.contains(&involved))
+                    {
+                        Some(index)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        for index in failed_list {
+            QuestManager::fail_quest(game_state, index);
+        }
+    }
+
+    fn complete_quest(game_state: &mut GameState, index: usize) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        let (description, giver_name, giver_faction, reward) = {
+            let mut journal = ecs_world
+                .get::<&mut QuestJournal>(player)
+                .expect("Player must have QuestJournal");
+            let quest = &mut journal.quests[index];
+            quest.state = QuestState::Completed;
+            (
+                quest.description.clone(),
+                quest.giver_name,
+                quest.giver_faction,
+                quest.reward,
+            )
+        };
+
+        game_state
+            .game_log
+            .add_entry(Cow::Owned(format!("Quest completed: {}", description)));
+
+        // Helping a creature is appreciated by its whole faction
+        if let Some(faction) = giver_faction {
+            FactionManager::change_faction_reputation(
+                ecs_world,
+                &mut game_state.game_log,
+                player,
+                faction,
+                REPUTATION_QUEST_BONUS,
+            );
+        }
+
+        let (x, y) = {
+            let position = ecs_world
+                .get::<&Position>(player)
+                .expect("Player must have Position");
+            (position.x, position.y)
+        };
+
+        match reward {
+            // Items are left at the feet of the player
+            QuestReward::Item(spawn_item) => {
+                let item = spawn_item(ecs_world, x, y);
+                let item_name = ecs_world
+                    .get::<&Named>(item)
+                    .expect("Item must be Named")
+                    .name;
+                game_state.game_log.add_entry(Cow::Owned(format!(
+                    "The {} rewards you with a {}",
+                    giver_name, item_name
+                )));
+            }
+            QuestReward::RawGold(amount) => {
+                for _ in 0..amount {
+                    let _ = Spawn::raw_gold(ecs_world, x, y);
+                }
+                game_state.game_log.add_entry(Cow::Owned(format!(
+                    "The {} rewards you with {} raw gold",
+                    giver_name, amount
+                )));
+            }
+            QuestReward::Experience(value) => {
+                if let Ok(mut experience) = ecs_world.get::<&mut Experience>(player) {
+                    experience.value += value;
+                }
+                game_state
+                    .game_log
+                    .entries
+                    .push(Cow::Borrowed("You feel more experienced"));
+            }
+            QuestReward::Immunity(immunity_type) => {
+                if let Ok(mut immunity) = ecs_world.get::<&mut Immunity>(player) {
+                    immunity
+                        .to
+                        .entry(immunity_type)
+                        .and_modify(|v| *v += 1)
+                        .or_insert(1);
+                }
+                game_state.game_log.add_entry(Cow::Owned(format!(
+                    "The {} blesses you, you feel protected",
+                    giver_name
+                )));
+            }
+        }
+    }
+
+    fn fail_quest(game_state: &mut GameState, index: usize) {
+        let player = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+
+        if let Ok(mut journal) = game_state.ecs_world.get::<&mut QuestJournal>(player) {
+            let quest = &mut journal.quests[index];
+            quest.state = QuestState::Failed;
+            game_state
+                .game_log
+                .add_entry(Cow::Owned(format!("Quest failed: {}", quest.description)));
+        }
+    }
+
+    fn get_active_index(ecs_world: &World, player: Entity, id: QuestId) -> Option<usize> {
+        ecs_world
+            .get::<&QuestJournal>(player)
+            .ok()?
+            .quests
+            .iter()
+            .position(|quest| quest.id == id && quest.state == QuestState::Active)
+    }
+
+    /// The strongest monster around that is not a talker or someone's hireling
+    fn find_prey(ecs_world: &World, giver: Entity) -> Option<(Entity, &'static str)> {
+        ecs_world
+            .query::<(&CombatStats, &Named)>()
+            .with::<&Monster>()
+            .without::<&WillChat>()
+            .without::<&Hireling>()
+            .iter()
+            .filter(|(entity, _)| entity.id() != giver.id())
+            .max_by_key(|(_, (stats, _))| stats.level)
+            .map(|(entity, (_, named))| (entity, named.name))
+    }
+
+    /// All the items with the given name carried by the owner
+    fn find_items(ecs_world: &World, owner: Entity, name: &str) -> Vec<Entity> {
+        ecs_world
+            .query::<(&InBackback, &Named)>()
+            .with::<&Item>()
+            .iter()
+            .filter_map(|(item, (in_backpack, named))| {
+                if in_backpack.owner.id() == owner.id() && named.name == name {
+                    Some(item)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
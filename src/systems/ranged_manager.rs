@@ -13,7 +13,7 @@ use crate::{
         common::{Hates, Named, Position},
         items::{Armor, Equipped, Eroded, RangedWeapon},
     },
-    maps::zone::Zone,
+    maps::zone::{NoiseType, Zone},
     utils::{
         common::Utils, effect_manager::EffectManager, particle_animation::ParticleAnimation,
         roll::Roll,
@@ -45,7 +45,7 @@ impl RangedManager {
 
             let zone = game_state
                 .current_zone
-                .as_ref()
+                .as_mut()
                 .expect("must have Some Zone");
             let mut equipped_armors = ecs_world.query::<(&Armor, &Equipped, Option<&Eroded>)>();
 
@@ -78,6 +78,10 @@ impl RangedManager {
                     }
                 }
 
+                if shooter.id() == player_id {
+                    zone.add_noise(shooter_position.x, shooter_position.y, NoiseType::Shooting);
+                }
+
                 let mut target_opt: Option<Entity> = None;
 
                 // Do not draw if shooter is himself
@@ -94,7 +98,7 @@ impl RangedManager {
                     // you cannot shoot something behind a barrier or another npc
                     let mut must_truncate_line_at = (false, 0);
                     for (i, &(x, y)) in line_effect.iter().skip(1).enumerate() {
-                        let index = Zone::get_index_from_xy(&x, &y);
+                        let index = zone.get_index_from_xy(&x, &y);
 
                         if !zone.tile_content[index].is_empty() {
                             //Get the first damageable entity
@@ -117,7 +121,7 @@ impl RangedManager {
                         // If no valid target is found, check for solid obstacle
                         if target_opt.is_none() && zone.blocked_tiles[index] {
                             // Log only if visible
-                            if zone.visible_tiles[Zone::get_index_from_xy(&x, &y)] {
+                            if zone.visible_tiles[zone.get_index_from_xy(&x, &y)] {
                                 game_state.game_log.add_entry(Cow::Borrowed(
                                     "The projectile bounces onto a solid obstacle",
                                 ));
@@ -133,7 +137,7 @@ impl RangedManager {
 
                     // Use particle type given by ranged weapon
                     if zone.visible_tiles
-                        [Zone::get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1)]
+                        [zone.get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1)]
                     {
                         particle_animations.push(ParticleAnimation::new_projectile(
                             line_effect,
@@ -143,7 +147,7 @@ impl RangedManager {
                 } else {
                     // Only one if shooting himself
                     let index =
-                        Zone::get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1);
+                        zone.get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1);
                     target_opt = Some(zone.tile_content[index][0]);
                 }
 
@@ -202,10 +206,9 @@ impl RangedManager {
                                 "{} shoot you for {} damage",
                                 named_attacker.name, damage_roll
                             )));
-                        } else if zone.visible_tiles[Zone::get_index_from_xy(
-                            &wants_to_zap.target.0,
-                            &wants_to_zap.target.1,
-                        )] {
+                        } else if zone.visible_tiles
+                            [zone.get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1)]
+                        {
                             game_state.game_log.add_entry(Cow::Owned(format!(
                                 "{} shoot the {} for {} damage",
                                 named_attacker.name, named_target.name, damage_roll
@@ -5,7 +5,7 @@ use hecs::Entity;
 use crate::{
     components::{
         actions::WantsToSmell,
-        common::{CanSmell, Position, SmellIntensity, Smellable},
+        common::{CanSmell, Position, Smellable},
         items::Rotten,
     },
     engine::state::GameState,
@@ -37,7 +37,7 @@ impl SmellManager {
                 &mut smellers
             {
                 let index =
-                    Zone::get_index_from_xy(&wants_to_smell.target.0, &wants_to_smell.target.1);
+                    zone.get_index_from_xy(&wants_to_smell.target.0, &wants_to_smell.target.1);
 
                 let mut have_smelled_something = false;
 
@@ -57,11 +57,7 @@ impl SmellManager {
                                 &smeller_position.y,
                             );
 
-                            let can_smell = smell_ability.intensity != SmellIntensity::None // the player cannot smell anything (common cold or other penalities)
-                                                && ((distance < smell_ability.radius / 2.0 && smells.intensity == SmellIntensity::Faint) // Faint odors can be smell from half normal distance
-                                                    || (distance < smell_ability.radius
-                                                        && (smells.intensity == SmellIntensity::Strong // Strong odors can be smelled at double distance.
-                                                            || smell_ability.intensity == SmellIntensity::Strong))); // Player have improved smell (can smell faint odors from far away)
+                            let can_smell = smell_ability.can_smell(&smells, distance);
 
                             if can_smell {
                                 have_smelled_something = true;
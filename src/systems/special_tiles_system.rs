@@ -43,7 +43,7 @@ impl SpecialTilesSystem {
                 (position, stepper_damage, stepper_stats, stepper_named, stepper_immunity),
             ) in &mut live_entities
             {
-                let pos_idx = Zone::get_index_from_xy(&position.x, &position.y);
+                let pos_idx = zone.get_index_from_xy(&position.x, &position.y);
 
                 if zone.tiles[pos_idx] == TileType::BigCrystal {
                     // if the Entity is stuck between BigCrystals, is crushed to death
@@ -87,7 +87,7 @@ impl SpecialTilesSystem {
                                 // Spread crystal growth to adjacent floor tiles
                                 for x_diff in -1..2 {
                                     for y_diff in -1..2 {
-                                        let pos_idx = Zone::get_index_from_xy(
+                                        let pos_idx = zone.get_index_from_xy(
                                             &(position.x + x_diff),
                                             &(position.y + y_diff),
                                         );
@@ -104,6 +104,7 @@ impl SpecialTilesSystem {
                             }
                             TileType::MediumCrystal => {
                                 zone.tiles[pos_idx] = TileType::BigCrystal;
+                                zone.must_recalculate_lava_light = true;
                                 zone.special_tile_counter[pos_idx] = 0;
                                 spawn_tile_entities.push((&TileType::BigCrystal, pos_idx));
                             }
@@ -150,7 +151,7 @@ impl SpecialTilesSystem {
 
         // Spawn tile entities if something changed
         for (tile_type, pos_idx) in spawn_tile_entities {
-            let (x, y) = Zone::get_xy_from_index(pos_idx);
+            let (x, y) = zone.get_xy_from_index(pos_idx);
             Spawn::tile_entity(ecs_world, x, y, tile_type);
         }
     }
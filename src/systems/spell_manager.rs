@@ -4,8 +4,9 @@ use crate::{
         common::{Immunity, ImmunityTypeEnum, MyTurn, Spell, SpellList, SpellType},
         health::Stunned,
     },
-    constants::STONE_FELL_PARTICLE_TYPE,
+    constants::{BOMBARDIER_SPRAY_DENSITY, STONE_FELL_PARTICLE_TYPE},
     engine::state::GameState,
+    systems::fire_manager::FireManager,
 };
 use std::{borrow::Cow, cmp::max};
 
@@ -16,7 +17,7 @@ use crate::{
         combat::{CombatStats, SufferingDamage, WantsToZap},
         common::{Hates, Named, Position},
     },
-    maps::zone::Zone,
+    maps::zone::{GasType, Zone},
     utils::{
         common::Utils, effect_manager::EffectManager, particle_animation::ParticleAnimation,
         roll::Roll,
@@ -35,6 +36,8 @@ impl SpellManager {
 
         let mut wants_to_cast_list: Vec<(Entity, i32)> = Vec::new();
         let mut stunned_list: Vec<(Entity, i32)> = Vec::new();
+        let mut set_on_fire_list: Vec<Entity> = Vec::new();
+        let mut spray_clouds: Vec<usize> = Vec::new();
         let mut particle_animations: Vec<ParticleAnimation> = Vec::new();
 
         // Scope for keeping borrow checker quiet
@@ -74,7 +77,7 @@ impl SpellManager {
                 {
                     // Use particle type given by ranged spell
                     if zone.visible_tiles
-                        [Zone::get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1)]
+                        [zone.get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1)]
                     {
                         // Select particle type based on spell type
                         match spell.spell_type {
@@ -85,7 +88,7 @@ impl SpellManager {
                                     wants_to_zap.target.1,
                                     STONE_FELL_PARTICLE_TYPE,
                                 ));
-                                let index = Zone::get_index_from_xy(
+                                let index = zone.get_index_from_xy(
                                     &wants_to_zap.target.0,
                                     &wants_to_zap.target.1,
                                 );
@@ -125,7 +128,7 @@ impl SpellManager {
                                 // you cannot shoot something behind a barrier or another npc
                                 let mut must_truncate_line_at = (false, 0);
                                 for (i, &(x, y)) in line_effect.iter().skip(1).enumerate() {
-                                    let index = Zone::get_index_from_xy(&x, &y);
+                                    let index = zone.get_index_from_xy(&x, &y);
 
                                     if !zone.tile_content[index].is_empty() {
                                         //Get the first damageable entity
@@ -148,7 +151,7 @@ impl SpellManager {
                                     // If no valid target is found, check for solid obstacle
                                     if target_opt.is_none() && zone.blocked_tiles[index] {
                                         // Log only if visible
-                                        if zone.visible_tiles[Zone::get_index_from_xy(&x, &y)] {
+                                        if zone.visible_tiles[zone.get_index_from_xy(&x, &y)] {
                                             game_state.game_log.add_entry(Cow::Borrowed(
                                                 "The spell bounces on something solid",
                                             ));
@@ -173,7 +176,7 @@ impl SpellManager {
                 } else {
                     // Only one if shooting himself
                     let index =
-                        Zone::get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1);
+                        zone.get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1);
                     target_opt = Some(zone.tile_content[index][0]);
                 }
 
@@ -195,10 +198,9 @@ impl SpellManager {
                                 "You avoid the spell cast by {}",
                                 named_attacker.name
                             )));
-                        } else if zone.visible_tiles[Zone::get_index_from_xy(
-                            &wants_to_zap.target.0,
-                            &wants_to_zap.target.1,
-                        )] {
+                        } else if zone.visible_tiles
+                            [zone.get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1)]
+                        {
                             game_state.game_log.add_entry(Cow::Owned(format!(
                                 "{} avoids the spell cast by {}",
                                 named_target.name, named_attacker.name,
@@ -242,7 +244,7 @@ impl SpellManager {
                                         named_spell.attack_verb.unwrap_or("zap"),
                                         damage_roll
                                     )));
-                                } else if zone.visible_tiles[Zone::get_index_from_xy(
+                                } else if zone.visible_tiles[zone.get_index_from_xy(
                                     &wants_to_zap.target.0,
                                     &wants_to_zap.target.1,
                                 )] {
@@ -256,6 +258,11 @@ impl SpellManager {
                                 }
                             }
 
+                            // Burning spray sets the target on fire
+                            if spell.spell_type == SpellType::BurningSpray {
+                                set_on_fire_list.push(target);
+                            }
+
                             //Stunning through spell
                             if let Some(stun) = stunned_opt {
                                 stunned_list.push((target, stun.tick_counter));
@@ -278,7 +285,7 @@ impl SpellManager {
                                         named_attacker.name,
                                         named_spell.attack_verb.unwrap_or("zap"),
                                     )));
-                                } else if zone.visible_tiles[Zone::get_index_from_xy(
+                                } else if zone.visible_tiles[zone.get_index_from_xy(
                                     &wants_to_zap.target.0,
                                     &wants_to_zap.target.1,
                                 )] {
@@ -294,6 +301,13 @@ impl SpellManager {
                     }
                 }
 
+                // Burning spray leaves a noxious cloud where it lands
+                if spell.spell_type == SpellType::BurningSpray {
+                    spray_clouds.push(
+                        zone.get_index_from_xy(&wants_to_zap.target.0, &wants_to_zap.target.1),
+                    );
+                }
+
                 // prepare lists for removal
                 wants_to_cast_list.push((caster, stats.speed));
             }
@@ -312,6 +326,18 @@ impl SpellManager {
         for (target, tick_counter) in stunned_list {
             let _ = ecs_world.insert_one(target, Stunned { tick_counter });
         }
+
+        for target in set_on_fire_list {
+            FireManager::set_on_fire(ecs_world, target);
+        }
+
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+        for index in spray_clouds {
+            zone.add_gas(index, GasType::Poison, BOMBARDIER_SPRAY_DENSITY);
+        }
     }
 
     /// On spellcaster turn, decrease casting cooldowns for all spells
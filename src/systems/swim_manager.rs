@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+
+use hecs::Entity;
+
+use crate::{
+    components::{
+        combat::{CanHide, CombatStats, IsHidden, SufferingDamage},
+        common::{MyTurn, Named, Position},
+        items::{Armor, Bulky, Equipped, InBackback, Metallic},
+        monster::Aquatic,
+    },
+    constants::{
+        BULKY_SWIM_PENALTY, DROWNING_DAMAGE_DICE, LOSE_ITEM_WHILE_SWIMMING_CHANCE,
+        METALLIC_SWIM_PENALTY,
+    },
+    engine::state::GameState,
+    maps::zone::Zone,
+    utils::roll::Roll,
+};
+
+/// Handles creatures in deep water: swimming, drowning and aquatic monsters submerging
+pub struct SwimManager {}
+
+impl SwimManager {
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+
+        let mut entities_that_submerge: Vec<Entity> = Vec::new();
+        let mut entities_that_surface: Vec<Entity> = Vec::new();
+        let mut items_lost: Vec<Entity> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            let mut swimmers = ecs_world
+                .query::<(
+                    &Position,
+                    &CombatStats,
+                    &mut SufferingDamage,
+                    &Named,
+                    Option<&Aquatic>,
+                    Option<&IsHidden>,
+                    Option<&CanHide>,
+                )>()
+                .with::<&MyTurn>();
+
+            for (swimmer, (position, stats, suffering_damage, named, aquatic, hidden, can_hide)) in
+                &mut swimmers
+            {
+                let pos_idx = zone.get_index_from_xy(&position.x, &position.y);
+                let is_player = swimmer.id() == player_id;
+
+                // Aquatic monsters dive in deep water to hide, and come up again in the shallows
+                if aquatic.is_some() {
+                    if zone.is_deep_water(pos_idx) && hidden.is_none() {
+                        entities_that_submerge.push(swimmer);
+                        if zone.visible_tiles[pos_idx] {
+                            game_state
+                                .game_log
+                                .add_entry(Cow::Owned(format!("The {} submerges!", named.name)));
+                        }
+                    } else if !zone.is_deep_water(pos_idx) && hidden.is_some() && can_hide.is_none()
+                    {
+                        entities_that_surface.push(swimmer);
+                        if zone.visible_tiles[pos_idx] {
+                            game_state
+                                .game_log
+                                .add_entry(Cow::Owned(format!("The {} surfaces!", named.name)));
+                        }
+                    }
+                    continue;
+                }
+
+                if !zone.is_deep_water(pos_idx) {
+                    continue;
+                }
+
+                // Heavy armor drags the swimmer down
+                let mut swim_penalty = 0;
+                for (_, (equipped, bulky, metallic)) in ecs_world
+                    .query::<(&Equipped, Option<&Bulky>, Option<&Metallic>)>()
+                    .with::<&Armor>()
+                    .iter()
+                {
+                    if equipped.owner.id() == swimmer.id() {
+                        if bulky.is_some() {
+                            swim_penalty += BULKY_SWIM_PENALTY;
+                        }
+                        if metallic.is_some() {
+                            swim_penalty += METALLIC_SWIM_PENALTY;
+                        }
+                    }
+                }
+
+                // Swimming check
+                if Roll::d20()
+                    <= (stats.current_toughness + stats.current_dexterity) / 2 - swim_penalty
+                {
+                    continue;
+                }
+
+                // Failed, the swimmer struggles to stay afloat. Without stamina left, drowning begins
+                let damage_roll = Roll::dice(1, DROWNING_DAMAGE_DICE);
+                suffering_damage.damage_received += damage_roll;
+                if is_player {
+                    if stats.current_stamina > damage_roll {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "You struggle to stay afloat and lose {} stamina!",
+                            damage_roll
+                        )));
+                    } else {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("You are drowning!"));
+                    }
+                } else if zone.visible_tiles[pos_idx] {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "The {} struggles to stay afloat",
+                        named.name
+                    )));
+                }
+
+                // Something could slip away from the backpack while thrashing around
+                if Roll::d6() <= LOSE_ITEM_WHILE_SWIMMING_CHANCE {
+                    let mut backpack_query = ecs_world
+                        .query::<(&InBackback, &Named)>()
+                        .without::<&Equipped>();
+                    let backpack: Vec<(Entity, &str)> = backpack_query
+                        .iter()
+                        .filter(|(_, (in_backpack, _))| in_backpack.owner.id() == swimmer.id())
+                        .map(|(item, (_, item_named))| (item, item_named.name))
+                        .collect();
+
+                    if !backpack.is_empty() {
+                        let (item, item_name) =
+                            backpack[Roll::dice(1, backpack.len() as i32) as usize - 1];
+                        items_lost.push(item);
+                        if is_player {
+                            game_state.game_log.add_entry(Cow::Owned(format!(
+                                "Your {} sinks into the deep water!",
+                                item_name
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+
+        for entity in entities_that_submerge {
+            let _ = ecs_world.insert_one(entity, IsHidden { hidden_counter: 0 });
+        }
+
+        for entity in entities_that_surface {
+            let _ = ecs_world.remove_one::<IsHidden>(entity);
+        }
+
+        // Lost items are gone for good
+        for item in items_lost {
+            let _ = ecs_world.despawn(item);
+        }
+    }
+}
@@ -1,17 +1,23 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cmp::max};
 
 use crate::{
     components::{
         actions::{WantsItem, WantsToTrade},
-        common::{DigProductEnum, Named},
-        items::{Corpse, Item, ShopOwner, Tradable},
+        common::{DigProductEnum, Named, Purse, Wet},
+        items::{Corpse, Eroded, Item, Rotten, ShopOwner, Tradable},
+    },
+    constants::{
+        ERODED_PRICE_PERCENT, PRICE_DEPTH_PERCENT, ROTTEN_PRICE_PERCENT, UNWANTED_SELL_PERCENT,
+        WET_PRICE_PERCENT, WRONGED_PRICE_PERCENT,
     },
     engine::state::{GameState, RunState},
+    systems::{faction_manager::FactionManager, hireling_manager::HirelingManager},
     utils::dialog::DialogAction,
 };
 use hecs::{Entity, World};
 
-pub type TradeDtt = (Entity, Entity, Entity, Vec<Entity>);
+/// Trader, traded item, shop owner and price in gold coins
+pub type TradeDtt = (Entity, Entity, Entity, u32);
 
 pub struct TradeSystem {}
 
@@ -29,75 +35,60 @@ impl TradeSystem {
             for (trader, wants_to_trade) in &mut want_to_trade {
                 // Only get traders who has something to trade
                 if let Some(traded_item) = wants_to_trade.item {
-                    let mut shop_owner_components = ecs_world
-                        .query_one::<(&ShopOwner, &Named)>(wants_to_trade.target)
-                        .expect("Must be a Named ShopOwner!");
-
-                    let (shop_owner, shop_owner_name) = shop_owner_components
-                        .get()
-                        .expect("Must be a Named ShopOwner!");
-
-                    // Calculate the selling cost of the item, based on the shop owner's wanted items
-                    let mut item_selling_cost = 0;
-                    for wanted in shop_owner.wanted_items.iter() {
-                        match wanted {
-                            Tradable::Corpse => {
-                                if ecs_world.satisfies::<&Corpse>(traded_item).unwrap_or(false) {
-                                    item_selling_cost += 1;
-                                }
-                            }
-                            Tradable::RawGold => {
-                                if let Ok(dig_product) =
-                                    ecs_world.get::<&DigProductEnum>(traded_item)
-                                    && dig_product.eq(&DigProductEnum::Gold)
-                                {
-                                    item_selling_cost += 1;
-                                }
-                            }
-                        }
+                    // NPCs for hire are paid rather than traded with
+                    if !ecs_world
+                        .satisfies::<&ShopOwner>(wants_to_trade.target)
+                        .unwrap_or(false)
+                    {
+                        new_run_state_opt = HirelingManager::check_offer(
+                            ecs_world,
+                            &mut game_state.game_log,
+                            (trader, traded_item, wants_to_trade.target),
+                        );
+                        traders.push(trader);
+                        continue;
                     }
 
-                    if item_selling_cost > 0 {
-                        let zone = game_state
-                            .current_zone
-                            .as_mut()
-                            .expect("must have Some Zone");
-
-                        let mut items_to_be_received: Vec<Entity> = Vec::new();
-                        for &index in &shop_owner.shop_tiles {
-                            for &content in &zone.tile_content[index] {
-                                if ecs_world.satisfies::<&Item>(content).unwrap_or(false) {
-                                    if items_to_be_received.len() < item_selling_cost {
-                                        items_to_be_received.push(content);
-                                    } else {
-                                        break;
-                                    }
-                                }
-                            }
-                            // Avoid unnecessary iterations
-                            if items_to_be_received.len() >= item_selling_cost {
-                                break;
-                            }
-                        }
-
-                        if items_to_be_received.is_empty() {
-                            game_state.game_log.add_entry(Cow::Owned(format!(
-                                "{} has no items to trade",
-                                shop_owner_name.name
-                            )));
-                        } else {
-                            // Open trade dialog
-                            new_run_state_opt = Some(RunState::ShowDialog(DialogAction::Trade((
-                                trader,
-                                traded_item,
-                                wants_to_trade.target,
-                                items_to_be_received,
-                            ))));
-                        }
+                    let shop_owner_name = ecs_world
+                        .get::<&Named>(wants_to_trade.target)
+                        .expect("Must be a Named ShopOwner!")
+                        .name;
+
+                    // Shop owners don't deal with those their faction dislikes
+                    if FactionManager::is_unfriendly(ecs_world, trader, wants_to_trade.target) {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "{} refuses to trade with you",
+                            shop_owner_name
+                        )));
+                        traders.push(trader);
+                        continue;
+                    }
+
+                    let depth = game_state
+                        .current_zone
+                        .as_ref()
+                        .expect("must have Some Zone")
+                        .depth;
+                    let price = TradeSystem::get_price(
+                        ecs_world,
+                        traded_item,
+                        wants_to_trade.target,
+                        depth,
+                        true,
+                    );
+
+                    if price > 0 {
+                        // Open trade dialog
+                        new_run_state_opt = Some(RunState::ShowDialog(DialogAction::Trade((
+                            trader,
+                            traded_item,
+                            wants_to_trade.target,
+                            price,
+                        ))));
                     } else {
                         game_state.game_log.add_entry(Cow::Owned(format!(
                             "{} is not interested",
-                            shop_owner_name.name
+                            shop_owner_name
                         )));
                     }
                     traders.push(trader);
@@ -117,19 +108,110 @@ impl TradeSystem {
         }
     }
 
-    /// End a trade between a trader and a shop owner
-    pub fn end_trade(ecs_world: &mut World, trade_info: TradeDtt) {
-        let (trader, traded_item, _, items_to_be_received) = trade_info;
-        // "picks up" all the items received
+    /// End a trade between a trader and a shop owner, paying the trader in gold
+    pub fn end_trade(game_state: &mut GameState, trade_info: TradeDtt) {
+        let ecs_world = &mut game_state.ecs_world;
+        let (trader, traded_item, shop_owner, price) = trade_info;
+
+        if let Ok(mut purse) = ecs_world.get::<&mut Purse>(trader) {
+            purse.gold += price;
+        }
+
+        let item_name = ecs_world
+            .get::<&Named>(traded_item)
+            .expect("traded item is not named")
+            .name;
+        let shop_owner_name = ecs_world
+            .get::<&Named>(shop_owner)
+            .expect("shop owner is not named")
+            .name;
+        game_state.game_log.add_entry(Cow::Owned(format!(
+            "You sell your {} to {} for {} gold",
+            item_name, shop_owner_name, price
+        )));
+
+        // TODO improve: must be placed in shop owner's inventory
+        let _ = ecs_world.despawn(traded_item);
+    }
+
+    /// End a purchase from a shop owner: the buyer pays and "picks up" the item
+    pub fn end_purchase(game_state: &mut GameState, trade_info: TradeDtt) {
+        let ecs_world = &mut game_state.ecs_world;
+        let (buyer, bought_item, shop_owner, price) = trade_info;
+
+        if let Ok(mut purse) = ecs_world.get::<&mut Purse>(buyer) {
+            purse.gold = purse.gold.saturating_sub(price);
+        }
+
+        let shop_owner_name = ecs_world
+            .get::<&Named>(shop_owner)
+            .expect("shop owner is not named")
+            .name;
+        game_state.game_log.add_entry(Cow::Owned(format!(
+            "You pay {} gold to {}",
+            price, shop_owner_name
+        )));
+
         let _ = ecs_world.insert_one(
-            trader,
+            buyer,
             WantsItem {
-                items: items_to_be_received,
+                items: vec![bought_item],
                 was_bought: true,
             },
         );
+    }
 
-        // TODO improve: must be placed in shop owner's inventory
-        let _ = ecs_world.despawn(traded_item);
+    /// Price in gold coins of an item in a shop, zero if the shop owner won't deal with it.
+    /// Goods are worth more deeper down, less when damaged, and wronged shop owners
+    /// ask more and pay less
+    pub fn get_price(
+        ecs_world: &World,
+        item: Entity,
+        shop_owner: Entity,
+        depth: u32,
+        is_selling: bool,
+    ) -> u32 {
+        let value = ecs_world.get::<&Item>(item).map_or(0, |item| item.value);
+        let Ok(shop_owner_data) = ecs_world.get::<&ShopOwner>(shop_owner) else {
+            return 0;
+        };
+        if value == 0 {
+            return 0;
+        }
+
+        let mut percent = 100 + depth * PRICE_DEPTH_PERCENT;
+
+        // Item condition
+        if ecs_world.satisfies::<&Rotten>(item).unwrap_or(false) {
+            percent = percent * ROTTEN_PRICE_PERCENT / 100;
+        }
+        if ecs_world.satisfies::<&Wet>(item).unwrap_or(false) {
+            percent = percent * WET_PRICE_PERCENT / 100;
+        }
+        if let Ok(eroded) = ecs_world.get::<&Eroded>(item) {
+            percent = percent.saturating_sub(percent * eroded.value * ERODED_PRICE_PERCENT / 100);
+        }
+
+        if is_selling {
+            // Only the wanted goods are paid in full
+            if !TradeSystem::is_wanted(ecs_world, item, &shop_owner_data.wanted_items) {
+                percent = percent * UNWANTED_SELL_PERCENT / 100;
+            }
+            percent /= 1 + shop_owner_data.times_wronged;
+        } else {
+            percent += percent * shop_owner_data.times_wronged * WRONGED_PRICE_PERCENT / 100;
+        }
+
+        max(1, value * percent / 100)
+    }
+
+    /// Check if an item is one of the goods a shop owner is looking for
+    fn is_wanted(ecs_world: &World, item: Entity, wanted_items: &[Tradable]) -> bool {
+        wanted_items.iter().any(|wanted| match wanted {
+            Tradable::Corpse => ecs_world.satisfies::<&Corpse>(item).unwrap_or(false),
+            Tradable::RawGold => ecs_world
+                .get::<&DigProductEnum>(item)
+                .is_ok_and(|dig_product| dig_product.eq(&DigProductEnum::Gold)),
+        })
     }
 }
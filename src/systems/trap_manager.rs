@@ -0,0 +1,358 @@
+use std::borrow::Cow;
+
+use hecs::Entity;
+
+use crate::{
+    components::{
+        actions::WantsToDisarm,
+        combat::{CombatStats, IsHidden, SufferingDamage},
+        common::{MyTurn, Named, Position, Trap, TrapType},
+        monster::{Awareness, AwarenessState, Monster, Surrendered, WantsToApproach},
+    },
+    constants::{
+        ALARM_APPROACH_COUNTER, ALARM_RADIUS, FALLING_ROCKS_DAMAGE_DICE, GAS_VENT_DENSITY,
+        PIT_DAMAGE_DICE, SPIKE_PLATE_DAMAGE_DICE, TRAP_SPOT_DISTANCE, TRAP_SPOT_PENALTY,
+    },
+    engine::state::GameState,
+    maps::zone::{GasType, Zone},
+    spawning::spawner::Spawn,
+    utils::{common::Utils, roll::Roll},
+};
+
+/// Handles traps: how they are spotted, triggered and disarmed
+pub struct TrapManager {}
+
+impl TrapManager {
+    /// Creatures stepping on armed traps trigger them.
+    /// Traps that are not destroyed when triggered are armed again once nobody is standing on them
+    pub fn run(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let mut sprung_traps: Vec<(Entity, Entity)> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            let mut traps = ecs_world.query::<(&mut Trap, &Position)>();
+            for (trap_entity, (trap, position)) in &mut traps {
+                let victim_opt = zone.tile_content
+                    [zone.get_index_from_xy(&position.x, &position.y)]
+                .iter()
+                .copied()
+                .find(|&entity| ecs_world.satisfies::<&CombatStats>(entity).unwrap_or(false));
+
+                if let Some(victim) = victim_opt {
+                    if trap.armed {
+                        trap.armed = false;
+                        sprung_traps.push((trap_entity, victim));
+                    }
+                } else {
+                    trap.armed = true;
+                }
+            }
+        }
+
+        for (trap, victim) in sprung_traps {
+            TrapManager::spring(game_state, trap, victim);
+        }
+    }
+
+    /// The player could spot hidden traps nearby with a dexterity check
+    pub fn spot_traps(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_entity = game_state
+            .current_player_entity
+            .expect("Player id should be set");
+        let mut spotted_traps: Vec<Entity> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let zone = game_state
+                .current_zone
+                .as_ref()
+                .expect("must have Some Zone");
+
+            let mut player_query = ecs_world
+                .query_one::<(&Position, &CombatStats)>(player_entity)
+                .expect("Player is not in hecs::World");
+            let (player_position, stats) =
+                player_query.get().expect("Player is not in hecs::World");
+
+            let mut hidden_traps = ecs_world
+                .query::<(&Position, &Named)>()
+                .with::<(&Trap, &IsHidden)>();
+            for (trap, (position, named)) in &mut hidden_traps {
+                if zone.visible_tiles[zone.get_index_from_xy(&position.x, &position.y)]
+                    && Utils::distance(
+                        &player_position.x,
+                        &position.x,
+                        &player_position.y,
+                        &position.y,
+                    ) <= TRAP_SPOT_DISTANCE
+                    && Roll::d20() + TRAP_SPOT_PENALTY <= stats.current_dexterity
+                {
+                    spotted_traps.push(trap);
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("You spot a {}!", named.name)));
+                }
+            }
+        }
+
+        for trap in spotted_traps {
+            let _ = ecs_world.remove_one::<IsHidden>(trap);
+        }
+    }
+
+    /// Try to disarm traps with a tool. A clumsy attempt could set the trap off
+    pub fn do_disarms(game_state: &mut GameState) {
+        let ecs_world = &mut game_state.ecs_world;
+        let mut disarmers_list: Vec<(Entity, i32)> = Vec::new();
+        let mut disarmed_traps: Vec<Entity> = Vec::new();
+        let mut sprung_traps: Vec<(Entity, Entity)> = Vec::new();
+
+        // Scope for keeping borrow checker quiet
+        {
+            let mut disarmers = ecs_world
+                .query::<(&WantsToDisarm, &CombatStats)>()
+                .with::<&MyTurn>();
+
+            for (disarmer, (wants_to_disarm, stats)) in &mut disarmers {
+                disarmers_list.push((disarmer, stats.speed));
+
+                // The trap could be already gone
+                let Ok(trap_named) = ecs_world.get::<&Named>(wants_to_disarm.target) else {
+                    continue;
+                };
+                let tool_named = ecs_world
+                    .get::<&Named>(wants_to_disarm.tool)
+                    .expect("Disarming tool must have Named component");
+
+                if Roll::d20() <= stats.current_dexterity {
+                    disarmed_traps.push(wants_to_disarm.target);
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "You disarm the {} with your {}",
+                        trap_named.name, tool_named.name
+                    )));
+                } else if Roll::d6() == 1 {
+                    sprung_traps.push((wants_to_disarm.target, disarmer));
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("You set off the {}!", trap_named.name)));
+                } else {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "You fail to disarm the {}",
+                        trap_named.name
+                    )));
+                }
+            }
+        }
+
+        for (disarmer, speed) in disarmers_list {
+            let _ = ecs_world.remove_one::<WantsToDisarm>(disarmer);
+            Utils::wait_after_action(ecs_world, disarmer, speed);
+        }
+
+        for trap in disarmed_traps {
+            let _ = ecs_world.despawn(trap);
+        }
+
+        for (trap, victim) in sprung_traps {
+            TrapManager::spring(game_state, trap, victim);
+        }
+    }
+
+    /// Apply the effects of a trap to its victim
+    fn spring(game_state: &mut GameState, trap: Entity, victim: Entity) {
+        let ecs_world = &mut game_state.ecs_world;
+        let player_id = game_state
+            .current_player_entity
+            .expect("Player id should be set")
+            .id();
+        let zone = game_state
+            .current_zone
+            .as_mut()
+            .expect("must have Some Zone");
+
+        let (trap_type, trap_x, trap_y) = {
+            let mut trap_query = ecs_world
+                .query_one::<(&Trap, &Position)>(trap)
+                .expect("Trap must have Trap and Position components");
+            let (trap_component, position) = trap_query.get().expect("Trap query failed");
+            (trap_component.trap_type, position.x, position.y)
+        };
+        let (victim_name, victim_dexterity) = {
+            let mut victim_query = ecs_world
+                .query_one::<(&Named, &CombatStats)>(victim)
+                .expect("Victim must have Named and CombatStats components");
+            let (named, stats) = victim_query.get().expect("Victim query failed");
+            (named.name, stats.current_dexterity)
+        };
+
+        let trap_index = zone.get_index_from_xy(&trap_x, &trap_y);
+        let is_player = victim.id() == player_id;
+        let is_visible = is_player || zone.visible_tiles[trap_index];
+        let mut damage = 0;
+        let mut is_destroyed = false;
+
+        match trap_type {
+            TrapType::Pit => {
+                damage = Roll::dice(1, PIT_DAMAGE_DICE);
+                if is_player {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "You fall into a pit for {} damage!",
+                        damage
+                    )));
+                } else if is_visible {
+                    game_state
+                        .game_log
+                        .add_entry(Cow::Owned(format!("The {} falls into a pit", victim_name)));
+                }
+            }
+            TrapType::FallingRocks => {
+                is_destroyed = true;
+                // Dexterity save to dodge the rocks
+                if Roll::d20() <= victim_dexterity {
+                    if is_player {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("You dodge the falling rocks!"));
+                    } else if is_visible {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} dodges the falling rocks",
+                            victim_name
+                        )));
+                    }
+                } else {
+                    damage = Roll::dice(2, FALLING_ROCKS_DAMAGE_DICE);
+                    if is_player {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "Rocks fall on you for {} damage!",
+                            damage
+                        )));
+                    } else if is_visible {
+                        game_state
+                            .game_log
+                            .add_entry(Cow::Owned(format!("Rocks fall on the {}", victim_name)));
+                    } else {
+                        game_state
+                            .game_log
+                            .entries
+                            .push(Cow::Borrowed("You hear falling rocks"));
+                    }
+                }
+                let _ = Spawn::slingshot_ammo(ecs_world, trap_x, trap_y);
+            }
+            TrapType::SpikePlate => {
+                // Dexterity save to jump away
+                if Roll::d20() <= victim_dexterity {
+                    if is_player {
+                        game_state.game_log.entries.push(Cow::Borrowed(
+                            "You jump away from the spikes springing from the floor!",
+                        ));
+                    } else if is_visible {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "The {} jumps away from the spikes",
+                            victim_name
+                        )));
+                    }
+                } else {
+                    damage = Roll::dice(1, SPIKE_PLATE_DAMAGE_DICE);
+                    if is_player {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "Spikes spring from the floor and pierce you for {} damage!",
+                            damage
+                        )));
+                    } else if is_visible {
+                        game_state.game_log.add_entry(Cow::Owned(format!(
+                            "Spikes spring from the floor and pierce the {}",
+                            victim_name
+                        )));
+                    }
+                }
+            }
+            TrapType::AlarmTripwire => {
+                is_destroyed = true;
+                if is_player {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("You trip over a wire, a bell rings loudly!"));
+                } else if is_visible {
+                    game_state.game_log.add_entry(Cow::Owned(format!(
+                        "The {} trips over a wire, a bell rings loudly!",
+                        victim_name
+                    )));
+                } else {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("You hear a bell ringing loudly"));
+                }
+
+                // Every monster nearby comes to see what happened
+                let alerted_monsters: Vec<Entity> = ecs_world
+                    .query::<&Position>()
+                    .with::<&Monster>()
+                    .without::<&Surrendered>()
+                    .iter()
+                    .filter_map(|(monster, position)| {
+                        if monster.id() != victim.id()
+                            && Utils::distance(&trap_x, &position.x, &trap_y, &position.y)
+                                <= ALARM_RADIUS
+                        {
+                            Some(monster)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                for monster in alerted_monsters {
+                    // The alarm is loud enough to wake up anyone
+                    if let Ok(mut awareness) = ecs_world.get::<&mut Awareness>(monster) {
+                        awareness.state = AwarenessState::Alert;
+                    }
+                    let _ = ecs_world.insert_one(
+                        monster,
+                        WantsToApproach {
+                            target_x: trap_x,
+                            target_y: trap_y,
+                            counter: ALARM_APPROACH_COUNTER,
+                        },
+                    );
+                }
+            }
+            TrapType::GasVent => {
+                zone.add_gas(trap_index, GasType::Poison, GAS_VENT_DENSITY);
+                if is_visible {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("A cloud of gas bursts from the floor!"));
+                } else {
+                    game_state
+                        .game_log
+                        .entries
+                        .push(Cow::Borrowed("You hear a hiss"));
+                }
+            }
+        }
+
+        if damage > 0
+            && let Ok(mut suffering_damage) = ecs_world.get::<&mut SufferingDamage>(victim)
+        {
+            suffering_damage.damage_received += damage;
+        }
+
+        // Traps that are seen going off are not hidden anymore
+        if is_destroyed {
+            let _ = ecs_world.despawn(trap);
+        } else if is_visible {
+            let _ = ecs_world.remove_one::<IsHidden>(trap);
+        }
+    }
+}
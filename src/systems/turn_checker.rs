@@ -5,6 +5,7 @@ use crate::{
         combat::CombatStats,
         common::{MyTurn, WaitingToAct},
         health::{Paralyzed, Stunned},
+        monster::{Awareness, AwarenessState, Surrendered},
     },
     engine::state::GameState,
     utils::common::Utils,
@@ -56,7 +57,7 @@ impl TurnCheck {
         }
     }
 
-    /// Check for turn reset (example: paralyzed entities)
+    /// Check for turn reset (example: paralyzed or sleeping entities)
     pub fn check_for_turn_reset(game_state: &mut GameState) {
         let ecs_world = &mut game_state.ecs_world;
         let mut entities_resetting_turn: Vec<(Entity, i32)> = Vec::new();
@@ -64,16 +65,28 @@ impl TurnCheck {
         // Scope for keeping borrow checker quiet
         {
             let mut actors = ecs_world
-                .query::<(&CombatStats, Option<&Paralyzed>, Option<&Stunned>)>()
+                .query::<(
+                    &CombatStats,
+                    Option<&Paralyzed>,
+                    Option<&Stunned>,
+                    Option<&Awareness>,
+                    Option<&Surrendered>,
+                )>()
                 .with::<&MyTurn>();
 
-            for (actor, (stats, paralyzed_opt, stunned_opt)) in &mut actors {
-                if paralyzed_opt.is_some() || stunned_opt.is_some() {
+            for (actor, (stats, paralyzed_opt, stunned_opt, awareness_opt, surrendered_opt)) in
+                &mut actors
+            {
+                if paralyzed_opt.is_some()
+                    || stunned_opt.is_some()
+                    || awareness_opt.is_some_and(|a| a.state == AwarenessState::Asleep)
+                    || surrendered_opt.is_some()
+                {
                     entities_resetting_turn.push((actor, stats.speed));
                 }
             }
         }
-        // Reset turn for entities that are paralyzed
+        // Reset turn for entities that are paralyzed, asleep or surrendered
         for (entity, speed) in entities_resetting_turn {
             Utils::wait_after_action(ecs_world, entity, speed);
         }
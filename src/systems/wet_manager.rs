@@ -45,7 +45,7 @@ impl WetManager {
             for (got_wet_entity, (position_opt, is_wet)) in &mut wettable_entities {
                 // Wet everyone walking in water
                 if let Some(position) = position_opt
-                    && zone.water_tiles[Zone::get_index_from_xy(&position.x, &position.y)]
+                    && zone.water_tiles[zone.get_index_from_xy(&position.x, &position.y)]
                 {
                     WetManager::wet_backpack(
                         ecs_world,
@@ -80,7 +80,7 @@ impl WetManager {
                     // This will make items in backpack dry faster.
                     if let Some(position) = position_opt {
                         for (index, tile) in zone.tiles.iter().enumerate() {
-                            let (tile_x, tile_y) = Zone::get_xy_from_index(index);
+                            let (tile_x, tile_y) = zone.get_xy_from_index(index);
                             if tile == &TileType::Brazier
                                 && Utils::distance(&position.x, &tile_x, &position.y, &tile_y)
                                     < (BRAZIER_RADIUS / 2) as f32
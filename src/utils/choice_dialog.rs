@@ -1,9 +1,9 @@
-use std::{collections::HashMap, slice::Iter};
+use std::collections::HashMap;
 
 use hecs::{Entity, World};
 use macroquad::{
     color::{BLACK, WHITE},
-    input::{clear_input_queue, get_char_pressed},
+    input::{KeyCode, clear_input_queue, get_char_pressed, is_key_pressed},
     shapes::draw_rectangle,
     text::{TextAlignment, TextParams, draw_multiline_text_ex, draw_text},
     texture::Texture2D,
@@ -14,11 +14,18 @@ use crate::{
         actions::{WantsItem, WantsToDrink, WantsToEat},
         common::Named,
         items::Corpse,
+        monster::Hireling,
     },
     constants::*,
     engine::state::{GameState, RunState},
     inventory::InventoryAction,
-    systems::trade_system::TradeSystem,
+    systems::{
+        dialogue_manager::{ChatDtt, DialogueManager},
+        faction_manager::FactionManager,
+        hireling_manager::HirelingManager,
+        quest_manager::QuestManager,
+        trade_system::TradeSystem,
+    },
     utils::{
         assets::TextureName,
         common::Utils,
@@ -31,6 +38,12 @@ pub struct ChoiceDialog {}
 impl Dialog for ChoiceDialog {
     /// Handle dialog input
     fn handle_input(game_state: &mut GameState, action: DialogAction) {
+        // Conversations are answered with the number of the chosen option
+        if let DialogAction::Chat(chat_info) = action {
+            ChoiceDialog::handle_chat_input(game_state, chat_info);
+            return;
+        }
+
         let ecs_world = &mut game_state.ecs_world;
         match get_char_pressed() {
             Some(letterkey) => match letterkey {
@@ -74,7 +87,28 @@ impl Dialog for ChoiceDialog {
                             return; // abort all other actions
                         }
                         DialogAction::Trade(trade_info) => {
-                            TradeSystem::end_trade(ecs_world, trade_info);
+                            QuestManager::register_trade(game_state, trade_info.1);
+                            FactionManager::change_reputation(
+                                &game_state.ecs_world,
+                                &mut game_state.game_log,
+                                player_entity,
+                                trade_info.2,
+                                REPUTATION_TRADE_BONUS,
+                            );
+                            TradeSystem::end_trade(game_state, trade_info);
+                        }
+                        DialogAction::Buy(trade_info) => {
+                            FactionManager::change_reputation(
+                                &game_state.ecs_world,
+                                &mut game_state.game_log,
+                                player_entity,
+                                trade_info.2,
+                                REPUTATION_TRADE_BONUS,
+                            );
+                            TradeSystem::end_purchase(game_state, trade_info);
+                        }
+                        DialogAction::Hire(hire_info) => {
+                            HirelingManager::end_hire(game_state, hire_info);
                         }
                         _ => {}
                     }
@@ -138,7 +172,7 @@ impl Dialog for ChoiceDialog {
                 )
             }
             DialogAction::Trade(trade_info) => {
-                let (_, traded_item, shop_owner, items_to_be_received) = trade_info;
+                let (_, traded_item, shop_owner, price) = trade_info;
 
                 let mut q = ecs_world
                     .query_one::<(&Named, Option<&Corpse>)>(*traded_item)
@@ -147,16 +181,66 @@ impl Dialog for ChoiceDialog {
                 let shop_owner_named = ecs_world
                     .get::<&Named>(*shop_owner)
                     .expect("shop_owner is not named");
-                // Build items string with "and" and "carriage return"
                 // Hack to determine if the collected item is a corpse (for logging purposes)
                 format!(
-                    "{}\noffers you\n{}\nfor your\n{}{}.\nAccept the offer?",
+                    "{}\noffers you\n{} gold\nfor your\n{}{}.\nAccept the offer?",
                     shop_owner_named.name,
-                    ChoiceDialog::build_offer_string(items_to_be_received.iter(), ecs_world),
+                    price,
                     traded_named.name,
                     Utils::get_corpse_string(corpse_opt.is_some()),
                 )
             }
+            DialogAction::Buy(trade_info) => {
+                let (_, bought_item, shop_owner, price) = trade_info;
+
+                let mut q = ecs_world
+                    .query_one::<(&Named, Option<&Corpse>)>(*bought_item)
+                    .unwrap_or_else(|_| panic!("Item with entity {:?} is not named", bought_item));
+                let (bought_named, corpse_opt) = q.get().expect("Item is not named!");
+                let shop_owner_named = ecs_world
+                    .get::<&Named>(*shop_owner)
+                    .expect("shop_owner is not named");
+                // Hack to determine if the collected item is a corpse (for logging purposes)
+                format!(
+                    "{}\nsells you the\n{}{}\nfor {} gold.\nBuy it?",
+                    shop_owner_named.name,
+                    bought_named.name,
+                    Utils::get_corpse_string(corpse_opt.is_some()),
+                    price,
+                )
+            }
+            DialogAction::Hire(hire_info) => {
+                let (_, paid_item, hireling) = hire_info;
+
+                let mut q = ecs_world
+                    .query_one::<(&Named, Option<&Corpse>)>(*paid_item)
+                    .unwrap_or_else(|_| panic!("Item with entity {:?} is not named", paid_item));
+                let (paid_named, corpse_opt) = q.get().expect("Item is not named!");
+                let hireling_named = ecs_world
+                    .get::<&Named>(*hireling)
+                    .expect("hireling is not named");
+                // Already hired NPCs will just carry the item
+                if ecs_world.satisfies::<&Hireling>(*hireling).unwrap_or(false) {
+                    format!(
+                        "Give your\n{}{}\nto the {}?",
+                        paid_named.name,
+                        Utils::get_corpse_string(corpse_opt.is_some()),
+                        hireling_named.name
+                    )
+                } else {
+                    format!(
+                        "The {}\nwill work for you\nfor your\n{}{}.\nHire it?",
+                        hireling_named.name,
+                        paid_named.name,
+                        Utils::get_corpse_string(corpse_opt.is_some()),
+                    )
+                }
+            }
+            DialogAction::Chat((chatter, node_index, _)) => {
+                let tree = DialogueManager::get_tree(ecs_world, *chatter)
+                    .expect("chatter has no dialogue tree");
+                tree[*node_index].text.to_string()
+            }
             _ => panic!("Cannot handle DialogAction {:?} in a ChoiceDialog", action),
         };
 
@@ -193,6 +277,12 @@ impl Dialog for ChoiceDialog {
 
         // ------- Choices -----------
 
+        // Conversations have numbered options instead of yes or no
+        if let DialogAction::Chat((chatter, node_index, available_options)) = action {
+            ChoiceDialog::draw_chat_options(ecs_world, *chatter, *node_index, available_options);
+            return;
+        }
+
         draw_text(
             "(Y)es",
             (DIALOG_X + DIALOG_LEFT_SPAN + HUD_BORDER) as f32,
@@ -212,25 +302,65 @@ impl Dialog for ChoiceDialog {
 
 // Implementations for ChoiceDialog
 impl ChoiceDialog {
-    /// Builds a string representation of the items to be received in a shop offer.
-    /// result example with 2 items: "a sword and a potion"
-    fn build_offer_string(items: Iter<'_, Entity>, ecs_world: &World) -> String {
-        let mut offer_string_arr = String::new();
-        let items_length = items.len();
-        for (index, item) in items.enumerate() {
-            let named = ecs_world
-                .get::<&Named>(*item)
-                .expect("offered item is not named");
-            offer_string_arr.push_str("a ");
-            offer_string_arr.push_str(named.name);
-            if items_length >= 2 {
-                if index < items_length - 2 {
-                    offer_string_arr.push_str(",\n");
-                } else if index == items_length - 2 {
-                    offer_string_arr.push_str("\nand a ");
-                }
-            }
+    /// Handle the choice of an option in a conversation. ESC leaves it
+    fn handle_chat_input(game_state: &mut GameState, chat_info: ChatDtt) {
+        if is_key_pressed(KeyCode::Escape) {
+            clear_input_queue();
+            DialogueManager::end_chat(game_state);
+            return;
+        }
+
+        let options_count = chat_info.2.len();
+        let choice_opt = get_char_pressed()
+            .and_then(|letterkey| letterkey.to_digit(10))
+            .map(|digit| digit as usize)
+            .filter(|&digit| digit >= 1 && digit <= options_count);
+
+        if let Some(choice) = choice_opt {
+            clear_input_queue();
+            DialogueManager::choose_option(game_state, chat_info, choice - 1);
+        } else {
+            game_state.run_state = RunState::ShowDialog(DialogAction::Chat(chat_info));
+        }
+    }
+
+    /// Draws the options of a conversation, one per line
+    fn draw_chat_options(
+        ecs_world: &World,
+        chatter: Entity,
+        node_index: usize,
+        available_options: &[usize],
+    ) {
+        let tree =
+            DialogueManager::get_tree(ecs_world, chatter).expect("chatter has no dialogue tree");
+        let options = tree[node_index].options;
+        let first_line_y = (DIALOG_Y + DIALOG_SIZE / 2 + DIALOG_TOP_SPAN / 2) as f32;
+
+        for (number, &option_index) in available_options.iter().enumerate() {
+            draw_text(
+                &format!("{}) {}", number + 1, options[option_index].text),
+                (DIALOG_X + INVENTORY_LEFT_SPAN + HUD_BORDER) as f32,
+                first_line_y + number as f32 * FONT_SIZE * 1.2,
+                FONT_SIZE,
+                WHITE,
+            );
         }
-        offer_string_arr
+
+        // ------- Footer -----------
+        let footer_text = "Number to answer, ESC to leave";
+        draw_rectangle(
+            (DIALOG_X + INVENTORY_LEFT_SPAN) as f32,
+            (DIALOG_Y + DIALOG_SIZE - UI_BORDER) as f32,
+            footer_text.len() as f32 * LETTER_SIZE - HUD_BORDER as f32 * 3.0,
+            HEADER_HEIGHT as f32,
+            BLACK,
+        );
+        draw_text(
+            footer_text,
+            (DIALOG_X + INVENTORY_LEFT_SPAN + HUD_BORDER) as f32,
+            (DIALOG_Y + DIALOG_SIZE + HUD_BORDER) as f32,
+            FONT_SIZE,
+            WHITE,
+        );
     }
 }
@@ -2,7 +2,9 @@ use crate::{
     components::{
         combat::CombatStats,
         common::{Position, Viewshed},
-        items::{Ammo, Armor, DiggingTool, Edible, Equippable, RangedWeapon, ShopOwner},
+        items::{
+            Ammo, Armor, DiggingTool, DisarmingTool, Edible, Equippable, RangedWeapon, ShopOwner,
+        },
         monster::{SingleSnakeCreature, SnakeBody},
     },
     constants::{
@@ -38,6 +40,8 @@ pub type ItemsInBackpack<'a> = (
 
 pub type EquippedDiggingTool<'a> = (&'a InBackback, &'a Equipped, &'a DiggingTool);
 
+pub type EquippedDisarmingTool<'a> = (&'a InBackback, &'a Equipped, &'a DisarmingTool);
+
 pub type AmmunitionInBackpack<'a> = (&'a InBackback, &'a mut Ammo);
 
 pub type EdibleInBackpack<'a> = (&'a InBackback, &'a Edible);
@@ -129,12 +133,13 @@ impl Utils {
         target_x: &i32,
         target_y: &i32,
         viewshed: &Viewshed,
+        zone: &Zone,
     ) -> (i32, i32) {
         let (mut new_x, mut new_y) = (-1, -1);
         let mut distance = 0.0;
 
         for &index in viewshed.visible_tiles.iter() {
-            let (x, y) = Zone::get_xy_from_index(index);
+            let (x, y) = zone.get_xy_from_index(index);
             let new_distance = Utils::distance(target_x, &x, target_y, &y);
             if new_distance > distance {
                 distance = new_distance;
@@ -147,18 +152,19 @@ impl Utils {
     }
 
     /// Check if a shop owner has ownership of an item and returns its Entity when found.
-    pub fn get_item_owner(ecs_world: &World, item: Entity) -> Option<Entity> {
+    pub fn get_item_owner(ecs_world: &World, zone: &Zone, item: Entity) -> Option<Entity> {
         let mut query_result = ecs_world
             .query_one::<&Position>(item)
             .expect("Item must have Position");
         let position = query_result.get().expect("Item must have Position");
 
-        Utils::get_item_owner_by_position(ecs_world, &position.x, &position.y)
+        Utils::get_item_owner_by_position(ecs_world, zone, &position.x, &position.y)
     }
 
     /// Check if a shop owner has ownership of an item and returns its Entity when found.
     pub fn get_item_owner_by_position(
         ecs_world: &World,
+        zone: &Zone,
         item_x: &i32,
         item_y: &i32,
     ) -> Option<Entity> {
@@ -170,7 +176,7 @@ impl Utils {
                 && shop_owner
                     .shop_tiles
                     .iter()
-                    .any(|&index| Zone::get_index_from_xy(item_x, item_y) == index)
+                    .any(|&index| zone.get_index_from_xy(item_x, item_y) == index)
             {
                 found = Some(owner);
 
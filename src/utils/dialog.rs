@@ -4,7 +4,9 @@ use hecs::{Entity, World};
 use macroquad::texture::Texture2D;
 
 use crate::{
-    engine::state::GameState, systems::trade_system::TradeDtt, utils::assets::TextureName,
+    engine::state::GameState,
+    systems::{dialogue_manager::ChatDtt, hireling_manager::HireDtt, trade_system::TradeDtt},
+    utils::assets::TextureName,
 };
 
 #[derive(PartialEq, Debug, Clone)]
@@ -12,6 +14,9 @@ pub enum DialogAction {
     Eat(Entity),
     Quaff(Entity),
     Trade(TradeDtt),
+    Buy(TradeDtt),
+    Hire(HireDtt),
+    Chat(ChatDtt),
     StealPick(Entity),
     StealEat(Entity),
     ShowMessage(&'static str),
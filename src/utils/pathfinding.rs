@@ -1,6 +1,10 @@
 use pathfinding::prelude::dijkstra;
 
-use crate::{constants::NEXT_TO_DISTANCE, maps::zone::Zone, utils::common::Utils};
+use crate::{
+    constants::{CLOSED_DOOR_PATH_COST, NEXT_TO_DISTANCE},
+    maps::zone::Zone,
+    utils::common::Utils,
+};
 
 pub struct Pathfinding {}
 
@@ -25,10 +29,21 @@ impl Pathfinding {
             // Must return all the passable adjacent squares form a x,y point.
             // .map(|p| (p, 1)) associate a pathfinding cost of 1 for each square
             // new not-passable tiles must be implemented inside "zone.get_adjacent_passable_tiles(x, y)"
+            // Creatures walking on land can open or bash closed doors, so those are passable at a higher cost
             |&(x, y)| {
-                zone.get_adjacent_passable_tiles(&x, &y, use_manhattan_distance, move_only_in_water)
+                let mut adjacent_tiles: Vec<((i32, i32), u32)> = zone
+                    .get_adjacent_passable_tiles(&x, &y, use_manhattan_distance, move_only_in_water)
                     .into_iter()
                     .map(|passable_tile| (passable_tile, 1))
+                    .collect();
+                if !move_only_in_water {
+                    adjacent_tiles.extend(
+                        zone.get_adjacent_closed_doors(&x, &y, use_manhattan_distance)
+                            .into_iter()
+                            .map(|door_tile| (door_tile, CLOSED_DOOR_PATH_COST)),
+                    );
+                }
+                adjacent_tiles
             },
             // Should tell when the goal is reached (being to te adjacent tile is sufficient)
             |&position: &(i32, i32)| {